@@ -77,6 +77,7 @@ impl Default for Engine {
         Self {
             parser: Arc::new(AsyncParserOptions {
                 include_loader: Box::new(resolver),
+                ..Default::default()
             }),
             render: Default::default(),
         }
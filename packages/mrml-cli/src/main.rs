@@ -8,6 +8,7 @@ use std::path::PathBuf;
 
 use clap::{Parser, Subcommand, ValueEnum};
 use mrml::mjml::Mjml;
+use mrml::prelude::format::{AttributeOrder, FormatOptions, SelfClosing, TextReflow};
 use mrml::prelude::parser::http_loader::{HttpIncludeLoader, UreqFetcher};
 use mrml::prelude::parser::loader::IncludeLoader;
 use mrml::prelude::parser::local_loader::LocalIncludeLoader;
@@ -47,6 +48,10 @@ struct Options {
     pub http_loader: Vec<String>,
     #[clap(long)]
     pub http_loader_mode: Option<HttpLoaderMode>,
+    /// Reject the attributes with an invalid value, like a `font-size` that
+    /// is not a pixel value
+    #[clap(long)]
+    pub strict_attributes: bool,
 }
 
 impl Options {
@@ -118,28 +123,39 @@ impl Options {
         log::debug!("parsing mjml input");
         let options = ParserOptions {
             include_loader: self.include_loader()?,
+            strict_attributes: self.strict_attributes,
+            ..Default::default()
         };
         Mjml::parse_with_options(input, &options).map_err(format_parser_error)
     }
 
-    fn parse_input(&self, input: String) -> Result<ParseOutput<Mjml>, String> {
+    fn parse_file_content(&self, filename: &str, input: &str) -> Result<ParseOutput<Mjml>, String> {
+        if filename.ends_with(".json") {
+            self.parse_json(input).map(|element| ParseOutput {
+                element,
+                warnings: Vec::new(),
+            })
+        } else if filename.ends_with(".mjml") {
+            self.parse_mjml(input)
+        } else {
+            Err(format!("unable to detect file type for {filename:?}"))
+        }
+    }
+
+    /// Parses the input, telling if it's written in mjml.
+    fn parse_input(&self, input: &str) -> Result<(ParseOutput<Mjml>, bool), String> {
         if let Some(ref filename) = self.input {
-            if filename.ends_with(".json") {
-                self.parse_json(&input).map(|element| ParseOutput {
-                    element,
-                    warnings: Vec::new(),
-                })
-            } else if filename.ends_with(".mjml") {
-                self.parse_mjml(&input)
-            } else {
-                Err(format!("unable to detect file type for {filename:?}"))
-            }
+            let root = self.parse_file_content(filename, input)?;
+            Ok((root, filename.ends_with(".mjml")))
+        } else if let Ok(root) = self.parse_mjml(input) {
+            Ok((root, true))
         } else {
-            self.parse_mjml(&input).or_else(|_| {
-                self.parse_json(&input).map(|element| ParseOutput {
+            self.parse_json(input).map(|element| {
+                let root = ParseOutput {
                     element,
                     warnings: Vec::new(),
-                })
+                };
+                (root, false)
             })
         }
     }
@@ -152,16 +168,20 @@ impl Options {
         }
     }
 
-    pub fn execute(self) -> Result<(), String> {
-        let root = self.read_input()?;
-        let root = self.parse_input(root)?;
-
-        self.subcmd.execute(root)
+    pub fn execute<W: Write>(self, output: &mut W) -> Result<(), String> {
+        let input = self.read_input()?;
+        let (root, is_mjml) = self.parse_input(&input)?;
+        let source = Some(input.as_str()).filter(|_| is_mjml);
+        self.subcmd.execute(&self, source, root, output)
     }
 }
 
 #[derive(Debug, Subcommand)]
 enum SubCommand {
+    /// Compare template with another one and list the changes
+    Diff(Diff),
+    /// Format template with a consistent style
+    Fmt(Fmt),
     /// Format template to JSON
     FormatJSON(Format),
     /// Format template to Mjml
@@ -172,36 +192,55 @@ enum SubCommand {
     Validate,
 }
 
+fn write_output<W: Write>(output: &mut W, value: &str) -> Result<(), String> {
+    writeln!(output, "{value}").map_err(|err| format!("couldn't write output: {err}"))
+}
+
 impl SubCommand {
-    pub fn execute(self, root: ParseOutput<Mjml>) -> Result<(), String> {
+    /// The source is given when the template is written in mjml.
+    pub fn execute<W: Write>(
+        &self,
+        options: &Options,
+        source: Option<&str>,
+        root: ParseOutput<Mjml>,
+        output: &mut W,
+    ) -> Result<(), String> {
         match self {
+            Self::Diff(diff) => {
+                let other = options.read_file(&diff.other)?;
+                let other = options.parse_file_content(&diff.other, &other)?;
+                diff.execute(&root.element, &other.element, output)?;
+            }
             Self::FormatJSON(opts) => {
                 log::debug!("format to json");
-                let output = if opts.pretty {
+                let result = if opts.pretty {
                     serde_json::to_string_pretty(&root.element).expect("couldn't format to JSON")
                 } else {
                     serde_json::to_string(&root.element).expect("couldn't format to JSON")
                 };
-                println!("{}", output);
+                write_output(output, &result)?;
             }
             Self::FormatMjml(opts) => {
                 log::debug!("format to mjml");
-                let output = if opts.pretty {
+                let result = if opts.pretty {
                     root.element.print_pretty()
                 } else {
                     root.element.print_dense()
                 }
                 .expect("couldn't format mjml");
-                println!("{}", output);
+                write_output(output, &result)?;
             }
             Self::Render(render) => {
                 log::debug!("render");
                 let render_opts = RenderOptions::from(render);
-                let output = root
+                let result = root
                     .element
                     .render(&render_opts)
                     .expect("couldn't render template");
-                println!("{}", output);
+                write_output(output, &result)?;
+            }
+            Self::Fmt(fmt) => {
+                fmt.execute(options.input.as_deref(), source, &root.element, output)?;
             }
             Self::Validate => {
                 log::debug!("validate");
@@ -221,6 +260,125 @@ struct Format {
     pub pretty: bool,
 }
 
+#[derive(Debug, Parser)]
+struct Diff {
+    /// Path to the mjml or json file to compare with
+    #[clap(index = 1)]
+    pub other: String,
+    /// Print the changes as JSON
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Diff {
+    /// Fails when the templates are different, like the `diff` command.
+    fn execute<W: Write>(&self, before: &Mjml, after: &Mjml, output: &mut W) -> Result<(), String> {
+        log::debug!("diff with {}", self.other);
+        let changes = before.diff(after);
+        if self.json {
+            let result = serde_json::to_string(&changes).expect("couldn't format to JSON");
+            write_output(output, &result)?;
+        } else {
+            for change in changes.iter() {
+                write_output(output, &change.to_string())?;
+            }
+        }
+        if changes.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("found {} change(s)", changes.len()))
+        }
+    }
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelfClosingStyle {
+    /// Like `<mj-spacer />`
+    Spaced,
+    /// Like `<mj-spacer/>`
+    Compact,
+    /// Like `<mj-spacer></mj-spacer>`
+    Expanded,
+}
+
+#[derive(Debug, Parser)]
+struct Fmt {
+    /// Fail when the template is not formatted, instead of printing it
+    #[clap(long)]
+    pub check: bool,
+    /// Number of spaces by level of indentation
+    #[clap(long, default_value_t = 2)]
+    pub indent_width: usize,
+    /// Indent with tabs instead of spaces
+    #[clap(long)]
+    pub use_tabs: bool,
+    /// Width above which the attributes are wrapped, 0 for no limit
+    #[clap(long, default_value_t = 80)]
+    pub max_width: usize,
+    /// Sort the attributes by name
+    #[clap(long)]
+    pub sort_attributes: bool,
+    #[clap(long, value_enum, default_value_t = SelfClosingStyle::Spaced)]
+    pub self_closing: SelfClosingStyle,
+    /// Collapse the whitespaces of the text of the mj-text elements and wrap
+    /// it at the max width
+    #[clap(long)]
+    pub reflow_text: bool,
+}
+
+impl From<&Fmt> for FormatOptions {
+    fn from(value: &Fmt) -> Self {
+        Self {
+            indent_width: value.indent_width,
+            use_tabs: value.use_tabs,
+            max_width: Some(value.max_width).filter(|width| *width > 0),
+            attribute_order: if value.sort_attributes {
+                AttributeOrder::Alphabetical
+            } else {
+                AttributeOrder::Preserve
+            },
+            self_closing: match value.self_closing {
+                SelfClosingStyle::Spaced => SelfClosing::Spaced,
+                SelfClosingStyle::Compact => SelfClosing::Compact,
+                SelfClosingStyle::Expanded => SelfClosing::Expanded,
+            },
+            text_reflow: if value.reflow_text {
+                TextReflow::Collapse
+            } else {
+                TextReflow::Preserve
+            },
+        }
+    }
+}
+
+impl Fmt {
+    /// Only the templates written in mjml can be checked, the source is
+    /// compared with the formatted template.
+    fn execute<W: Write>(
+        &self,
+        filename: Option<&str>,
+        source: Option<&str>,
+        root: &Mjml,
+        output: &mut W,
+    ) -> Result<(), String> {
+        log::debug!("format with {:?}", self);
+        let result = root
+            .print_formatted(&FormatOptions::from(self))
+            .expect("couldn't format mjml");
+        if !self.check {
+            write!(output, "{result}").map_err(|err| format!("couldn't write output: {err}"))?;
+        } else if source.is_none() {
+            return Err(String::from("only the mjml templates can be checked"));
+        } else if source != Some(result.as_str()) {
+            return Err(format!(
+                "{} is not formatted",
+                filename.unwrap_or("the template")
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Parser)]
 struct Render {
     /// Remove comments from html output
@@ -231,11 +389,11 @@ struct Render {
     pub social_icon_origin: Option<String>,
 }
 
-impl From<Render> for RenderOptions {
-    fn from(value: Render) -> Self {
+impl From<&Render> for RenderOptions {
+    fn from(value: &Render) -> Self {
         Self {
             disable_comments: value.disable_comments,
-            social_icon_origin: value.social_icon_origin.map(Cow::Owned),
+            social_icon_origin: value.social_icon_origin.clone().map(Cow::Owned),
             ..Default::default()
         }
     }
@@ -243,7 +401,7 @@ impl From<Render> for RenderOptions {
 
 fn main() {
     env_logger::init();
-    if let Err(error) = Options::parse().execute() {
+    if let Err(error) = Options::parse().execute(&mut std::io::stdout().lock()) {
         eprintln!("{error}");
         std::process::exit(1);
     }
@@ -256,6 +414,7 @@ mod tests {
     use crate::format_parser_error;
 
     use super::Options;
+    use mrml::prelude::attributes::AttributeError;
     use mrml::prelude::parser::{loader::IncludeLoaderError, Error as ParserError, Origin, Span};
 
     fn origin_include() -> Origin {
@@ -354,6 +513,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_parser_error_invalid_attribute_value_in_root() {
+        assert_eq!(
+            format_parser_error(ParserError::InvalidAttributeValue {
+                origin: Origin::Root,
+                position: any_span(),
+                source: Box::new(AttributeError {
+                    name: String::from("font-size"),
+                    value: String::from("big"),
+                    reason: String::from("value should end with $px"),
+                }),
+            }),
+            "invalid value for attribute \"font-size\" in root template at position 10:20: invalid value \"big\" for attribute \"font-size\": value should end with $px"
+        );
+    }
+
     #[test]
     fn format_parser_error_include_loader_error_in_root() {
         assert_eq!(
@@ -432,14 +607,28 @@ mod tests {
         );
     }
 
-    fn execute<const N: usize>(args: [&str; N]) {
-        Options::parse_from(args).execute().unwrap()
+    fn try_execute<const N: usize>(args: [&str; N]) -> (Result<(), String>, String) {
+        let mut output = Vec::new();
+        let result = Options::parse_from(args).execute(&mut output);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    fn execute<const N: usize>(args: [&str; N]) -> String {
+        let (result, output) = try_execute(args);
+        result.unwrap();
+        output
     }
 
-    fn execute_stdin<const N: usize, I: Into<String>>(args: [&str; N], input: I) {
+    fn execute_stdin<const N: usize, I: Into<String>>(args: [&str; N], input: I) -> String {
         let opts = Options::parse_from(args);
-        let root = opts.parse_input(input.into()).unwrap();
-        opts.subcmd.execute(root).unwrap()
+        let input = input.into();
+        let (root, is_mjml) = opts.parse_input(&input).unwrap();
+        let source = Some(input.as_str()).filter(|_| is_mjml);
+        let mut output = Vec::new();
+        opts.subcmd
+            .execute(&opts, source, root, &mut output)
+            .unwrap();
+        String::from_utf8(output).unwrap()
     }
 
     #[test]
@@ -496,6 +685,113 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn diff_amario() {
+        let output = execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "diff",
+            "./resources/amario.json",
+        ]);
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn diff_json_amario() {
+        let output = execute([
+            "mrml-cli",
+            "./resources/amario.json",
+            "diff",
+            "./resources/amario.mjml",
+            "--json",
+        ]);
+        assert_eq!(output, "[]\n");
+    }
+
+    #[test]
+    fn diff_changed() {
+        let (result, output) = try_execute([
+            "mrml-cli",
+            "./resources/formatted.mjml",
+            "diff",
+            "./resources/formatted-changed.mjml",
+        ]);
+        assert_eq!(result.unwrap_err(), "found 1 change(s)");
+        assert_eq!(
+            output,
+            "mj-body > mj-section > mj-column > mj-image: attribute \"width\" changed from \"100px\" to \"120px\"\n"
+        );
+    }
+
+    #[test]
+    fn diff_json_changed() {
+        let (result, output) = try_execute([
+            "mrml-cli",
+            "./resources/formatted.mjml",
+            "diff",
+            "./resources/formatted-changed.mjml",
+            "--json",
+        ]);
+        assert!(result.is_err());
+        let changes: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(changes.as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn fmt_amario() {
+        let output = execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "fmt",
+            "--use-tabs",
+            "--sort-attributes",
+        ]);
+        assert!(output.starts_with("<mjml>\n\t<mj-body background-color=\"#d6dde5\">\n"));
+    }
+
+    #[test]
+    fn fmt_reflow_text_amario() {
+        let output = execute(["mrml-cli", "./resources/amario.mjml", "fmt"]);
+        let reflowed = execute([
+            "mrml-cli",
+            "./resources/amario.mjml",
+            "fmt",
+            "--reflow-text",
+        ]);
+        assert_ne!(output, reflowed);
+        assert!(reflowed.starts_with("<mjml>\n  <mj-body background-color=\"#d6dde5\">\n"));
+    }
+
+    #[test]
+    fn fmt_check_formatted() {
+        let output = execute(["mrml-cli", "./resources/formatted.mjml", "fmt", "--check"]);
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn fmt_check_amario() {
+        let (result, _) = try_execute(["mrml-cli", "./resources/amario.mjml", "fmt", "--check"]);
+        assert_eq!(
+            result.unwrap_err(),
+            "./resources/amario.mjml is not formatted"
+        );
+    }
+
+    #[test]
+    fn fmt_json_amario() {
+        let output = execute(["mrml-cli", "./resources/amario.json", "fmt"]);
+        assert!(output.starts_with("<mjml>\n  <mj-body background-color=\"#d6dde5\">\n"));
+    }
+
+    #[test]
+    fn fmt_check_json_amario() {
+        let (result, _) = try_execute(["mrml-cli", "./resources/amario.json", "fmt", "--check"]);
+        assert_eq!(
+            result.unwrap_err(),
+            "only the mjml templates can be checked"
+        );
+    }
+
     #[test]
     fn render_amario() {
         execute(["mrml-cli", "./resources/amario.mjml", "render"]);
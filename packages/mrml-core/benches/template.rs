@@ -10,6 +10,13 @@ fn render(input: &str) {
 fn criterion_benchmark(c: &mut Criterion) {
     let data = include_str!("../resources/template/amario.mjml");
     c.bench_function("amario", |b| b.iter(|| render(black_box(data))));
+
+    let opts = RenderOptions::default();
+    let root = mrml::mjml::Mjml::parse(data).unwrap();
+    let compiled = root.element.compile();
+    c.bench_function("amario compiled", |b| {
+        b.iter(|| black_box(&compiled).render(&opts).unwrap())
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -9,7 +9,7 @@ impl<'root> Render<'root> for Renderer<'root, Comment, ()> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if !self.context.options.disable_comments {
             cursor.buffer.push_str("<!--");
-            cursor.buffer.push_str(self.element.children.as_str());
+            cursor.buffer.push_verbatim(self.element.children.as_str());
             cursor.buffer.push_str("-->");
         }
         Ok(())
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use crate::helper::size::Percent;
+use crate::prelude::render::*;
+
+fn is_horizontal_position(value: &str) -> bool {
+    value == "left" || value == "right" || value == "center"
+}
+
+fn is_vertical_position(value: &str) -> bool {
+    value == "top" || value == "bottom" || value == "center"
+}
+
+/// Background of the elements supporting a `background-url`: the css
+/// properties of the element and the VML shapes rendering it in Outlook.
+pub trait WithBackground<'root>: Render<'root> {
+    fn has_background(&self) -> bool {
+        self.attribute_exists("background-url")
+    }
+
+    fn parse_background_position<'a>(&'a self) -> (&'a str, &'a str)
+    where
+        'root: 'a,
+    {
+        // can be unwraped because has default value
+        let position = self.attribute("background-position").unwrap();
+        let mut positions = position.split_whitespace();
+        if let Some(first) = positions.next() {
+            if let Some(second) = positions.next() {
+                if is_vertical_position(first) && is_horizontal_position(second) {
+                    (second, first)
+                } else {
+                    (first, second)
+                }
+            } else if is_vertical_position(first) {
+                ("center", first)
+            } else {
+                (first, "center")
+            }
+        } else {
+            ("center", "top")
+        }
+    }
+
+    fn get_background_position<'a>(&'a self) -> (&'a str, &'a str)
+    where
+        'root: 'a,
+    {
+        let (x, y) = self.parse_background_position();
+        (
+            self.attribute("background-position-x").unwrap_or(x),
+            self.attribute("background-position-y").unwrap_or(y),
+        )
+    }
+
+    fn get_background_position_str(&self) -> String {
+        let position = self.get_background_position();
+        format!("{} {}", position.0, position.1)
+    }
+
+    fn get_background(&self) -> Option<String> {
+        let mut res: Vec<Cow<'_, str>> = vec![];
+        if let Some(color) = self.attribute("background-color") {
+            res.push(color.into());
+        }
+        if let Some(url) = self.attribute("background-url") {
+            res.push(format!("url('{url}')").into());
+            // has default value
+            res.push(
+                format!(
+                    "{} / {}",
+                    self.get_background_position_str(),
+                    self.attribute("background-size").unwrap()
+                )
+                .into(),
+            );
+            // has default value
+            res.push(self.attribute("background-repeat").unwrap().into());
+        }
+
+        if res.is_empty() {
+            None
+        } else {
+            Some(res.join(" "))
+        }
+    }
+
+    fn set_background_style<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        if self.has_background() {
+            tag.maybe_add_style("background", self.get_background())
+                .add_style("background-position", self.get_background_position_str())
+                .maybe_add_style("background-repeat", self.attribute("background-repeat"))
+                .maybe_add_style("background-size", self.attribute("background-size"))
+        } else {
+            tag.maybe_add_style("background", self.attribute("background-color"))
+                .maybe_add_style("background-color", self.attribute("background-color"))
+        }
+    }
+
+    fn get_vfill_position(&self) -> (Cow<'root, str>, Cow<'root, str>) {
+        if self.attribute_equals("background-size", "auto") {
+            return ("0.5, 0".into(), "0.5, 0".into());
+        }
+        let (bg_position_x, bg_position_y) = self.get_background_position();
+        let bg_repeat = self.attribute_equals("background-repeat", "repeat");
+        let bg_position_x = match bg_position_x {
+            "left" => "0%",
+            "center" => "50%",
+            "right" => "100%",
+            _ => {
+                if bg_position_x.ends_with('%') {
+                    bg_position_x
+                } else {
+                    "50%"
+                }
+            }
+        };
+        let bg_position_y = match bg_position_y {
+            "top" => "0%",
+            "center" => "50%",
+            "bottom" => "100%",
+            _ => {
+                if bg_position_y.ends_with('%') {
+                    bg_position_y
+                } else {
+                    "0%"
+                }
+            }
+        };
+        let position_x = if let Ok(position) = Percent::try_from(bg_position_x) {
+            if bg_repeat {
+                position.value() * 0.01
+            } else {
+                (position.value() - 50.0) * 0.01
+            }
+        } else if bg_repeat {
+            0.5
+        } else {
+            0.0
+        };
+        let position_y = if let Ok(position) = Percent::try_from(bg_position_y) {
+            if bg_repeat {
+                position.value() * 0.01
+            } else {
+                (position.value() - 50.0) * 0.01
+            }
+        } else if bg_repeat {
+            0.5
+        } else {
+            0.0
+        };
+        (
+            format!("{position_x}, {position_y}").into(),
+            format!("{position_x}, {position_y}").into(),
+        )
+    }
+
+    fn get_vfill_tag<'a>(&'a self) -> Tag<'a>
+    where
+        'root: 'a,
+    {
+        let bg_no_repeat = self.attribute_equals("background-repeat", "no-repeat");
+        let bg_size = self.attribute("background-size");
+        let bg_size_auto = bg_size
+            .as_ref()
+            .map(|value| *value == "auto")
+            .unwrap_or(false);
+        let vml_type = if bg_no_repeat && !bg_size_auto {
+            "frame"
+        } else {
+            "tile"
+        };
+        let vsize = match bg_size {
+            Some("cover") | Some("contain") => Some("1,1".to_string()),
+            Some("auto") => None,
+            Some(value) => Some(value.replace(' ', ",")),
+            None => None,
+        };
+        let aspect = match bg_size {
+            Some("cover") => Some("atleast".to_string()),
+            Some("contain") => Some("atmost".to_string()),
+            Some("auto") => None,
+            Some(other) => {
+                if other.split(' ').count() == 1 {
+                    Some("atmost".to_string())
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+
+        let (vfill_position, vfill_origin) = self.get_vfill_position();
+        Tag::new("v:fill")
+            .add_attribute("position", vfill_position)
+            .add_attribute("origin", vfill_origin)
+            .maybe_add_attribute("src", self.attribute("background-url"))
+            .maybe_add_attribute("color", self.attribute("background-color"))
+            .maybe_add_attribute("size", vsize)
+            .add_attribute("type", vml_type)
+            .maybe_add_attribute("aspect", aspect)
+    }
+
+    /// Wraps the content in the VML rectangle rendering the background in
+    /// Outlook. It's expected to be called inside a conditional comment.
+    fn render_vml_background<F>(
+        &self,
+        cursor: &mut RenderCursor,
+        full_width: bool,
+        width: Option<String>,
+        content: F,
+    ) -> Result<(), Error>
+    where
+        F: Fn(&mut RenderCursor) -> Result<(), Error>,
+    {
+        let vrect = Tag::new("v:rect")
+            .maybe_add_attribute(
+                "mso-width-percent",
+                if full_width { Some("1000") } else { None },
+            )
+            .maybe_add_style("width", width)
+            .add_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")
+            .add_attribute("fill", "true")
+            .add_attribute("stroke", "false");
+        let vfill = self.get_vfill_tag();
+        let vtextbox = Tag::new("v:textbox")
+            .add_attribute("inset", "0,0,0,0")
+            .add_style("mso-fit-shape-to-text", "true");
+
+        vrect.render_open(&mut cursor.buffer)?;
+        vfill.render_closed(&mut cursor.buffer)?;
+        vtextbox.render_open(&mut cursor.buffer)?;
+        cursor.buffer.end_conditional_tag();
+        content(cursor)?;
+        cursor.buffer.start_conditional_tag();
+        vtextbox.render_close(&mut cursor.buffer);
+        vrect.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+//! Templates of `resources/compare/success`, for the tests going through all
+//! of them.
+
+use std::path::PathBuf;
+
+/// Some tests are only meaningful if they went through most of the templates.
+const MIN_TEMPLATES: usize = 100;
+
+/// The sources of the compared templates, with their path, sorted by name.
+pub fn compared_templates() -> Vec<(PathBuf, String)> {
+    let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/compare/success");
+    let mut result = std::fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("mjml"))
+        .map(|path| {
+            let source = std::fs::read_to_string(&path).unwrap();
+            (path, source)
+        })
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| a.0.cmp(&b.0));
+    assert!(
+        result.len() >= MIN_TEMPLATES,
+        "only {} templates found in {}",
+        result.len(),
+        directory
+    );
+    result
+}
@@ -1,8 +1,13 @@
 #[cfg(feature = "render")]
+pub mod background;
+#[cfg(any(feature = "parse", feature = "render"))]
 pub mod size;
 #[cfg(feature = "render")]
 pub mod sort;
-#[cfg(feature = "render")]
+#[cfg(any(feature = "parse", feature = "render"))]
 pub mod spacing;
 #[cfg(feature = "render")]
 pub mod style;
+
+#[cfg(test)]
+pub mod compare;
@@ -15,6 +15,7 @@ pub enum SpacingParserError {
 }
 
 /// representation of spacing
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Spacing {
     Single(Pixel),
     Two(Pixel, Pixel),
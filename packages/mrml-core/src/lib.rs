@@ -40,6 +40,7 @@
 //! let loader = MemoryIncludeLoader::from(vec![("partial.mjml", "<mj-button>Hello</mj-button>")]);
 //! let options = ParserOptions {
 //!     include_loader: Box::new(loader),
+//!     ..Default::default()
 //! };
 //! match mrml::parse_with_options("<mjml><mj-head /><mj-body><mj-include path=\"partial.mjml\" /></mj-body></mjml>", &options) {
 //!     Ok(_) => println!("Success!"),
@@ -75,6 +76,7 @@
 //!     .with_any(Box::<NoopIncludeLoader>::default());
 //! let parser_options = AsyncParserOptions {
 //!     include_loader: Box::new(resolver),
+//!     ..Default::default()
 //! };
 //! let render_options = RenderOptions::default();
 //! let json = r#"<mjml>
@@ -146,6 +148,8 @@ pub mod mj_head;
 pub mod mj_hero;
 pub mod mj_image;
 pub mod mj_include;
+pub mod mj_list;
+pub mod mj_list_item;
 pub mod mj_navbar;
 pub mod mj_navbar_link;
 pub mod mj_preview;
@@ -158,9 +162,11 @@ pub mod mj_style;
 pub mod mj_table;
 pub mod mj_text;
 pub mod mj_title;
+pub mod mj_video;
 pub mod mj_wrapper;
 pub mod mjml;
 pub mod node;
+pub mod placeholder;
 pub mod prelude;
 pub mod text;
 
@@ -186,6 +192,7 @@ mod helper;
 ///
 /// let options = ParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// };
 /// match mrml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
 ///     Ok(_) => println!("Success!"),
@@ -224,6 +231,7 @@ pub fn parse_with_options<T: AsRef<str>>(
 ///
 /// let options = std::sync::Arc::new(AsyncParserOptions {
 ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+///     ..Default::default()
 /// });
 /// match mrml::async_parse_with_options("<mjml><mj-head /><mj-body /></mjml>", options).await {
 ///     Ok(_) => println!("Success!"),
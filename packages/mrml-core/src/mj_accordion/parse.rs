@@ -1,6 +1,7 @@
 use super::MjAccordionChild;
 use crate::comment::Comment;
 use crate::mj_accordion_element::NAME as MJ_ACCORDION_ELEMENT;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
@@ -18,6 +19,9 @@ impl<'opts> ParseChildren<Vec<MjAccordionChild>> for MrmlParser<'opts> {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjAccordionChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_ACCORDION_ELEMENT {
                         result.push(MjAccordionChild::MjAccordionElement(
@@ -62,6 +66,9 @@ impl AsyncParseChildren<Vec<MjAccordionChild>> for AsyncMrmlParser {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjAccordionChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_ACCORDION_ELEMENT {
                         result.push(MjAccordionChild::MjAccordionElement(
@@ -86,6 +86,7 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordion, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         self.update_header(&mut cursor.header);
 
         let tbody = Tag::tbody();
@@ -110,10 +111,11 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordion, ()> {
 
         for child in self.element.children.iter() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             children_attrs.iter().copied().for_each(|(key, value)| {
                 renderer.add_extra_attribute(key, value);
             });
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         tbody.render_close(&mut cursor.buffer);
         table.render_close(&mut cursor.buffer);
@@ -138,6 +140,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjAccordionChild {
         match self {
             Self::MjAccordionElement(elt) => elt.renderer(context),
             Self::Comment(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
         }
     }
 }
@@ -24,7 +24,7 @@ impl StaticTag for MjAccordionElementTag {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct MjAccordionElementChildren {
     pub title: Option<MjAccordionTitle>,
     pub text: Option<MjAccordionText>,
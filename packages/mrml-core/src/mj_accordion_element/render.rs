@@ -24,34 +24,38 @@ impl<'root> Renderer<'root, MjAccordionElement, MjAccordionElementExtra<'root>>
     fn render_title(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if let Some(ref child) = self.element.children.title {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             CHILDREN_ATTRIBUTES.iter().for_each(|name| {
                 renderer.maybe_add_extra_attribute(name, self.attribute(name));
             });
-            renderer.render(cursor)
+            renderer.render_component(cursor)
         } else {
             let child = MjAccordionTitle::default();
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             CHILDREN_ATTRIBUTES.iter().for_each(|name| {
                 renderer.maybe_add_extra_attribute(name, self.attribute(name));
             });
-            renderer.render(cursor)
+            renderer.render_component(cursor)
         }
     }
 
     fn render_text(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if let Some(ref child) = self.element.children.text {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             CHILDREN_ATTRIBUTES.iter().for_each(|name| {
                 renderer.maybe_add_extra_attribute(name, self.attribute(name));
             });
-            renderer.render(cursor)
+            renderer.render_component(cursor)
         } else {
             let child = MjAccordionText::default();
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             CHILDREN_ATTRIBUTES.iter().for_each(|name| {
                 renderer.maybe_add_extra_attribute(name, self.attribute(name));
             });
-            renderer.render(cursor)
+            renderer.render_component(cursor)
         }
     }
 
@@ -85,6 +89,7 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionElement, MjAccordionEle
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let input = Tag::new("input")
             .add_attribute("type", "checkbox")
             .add_class("mj-accordion-checkbox")
@@ -144,7 +149,7 @@ mod tests {
     fn basic() {
         let opts = RenderOptions::default();
         let head = Header::new(None, None);
-        let ctx = RenderContext::new(&opts, head);
+        let ctx = RenderContext::new(&opts, &head);
 
         let element = MjAccordionElement::new(
             Default::default(),
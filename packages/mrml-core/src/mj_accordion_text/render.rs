@@ -24,7 +24,7 @@ impl<'root> Renderer<'root, MjAccordionText, MjAccordionTextExtra<'root>> {
         td.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
             let renderer = child.renderer(self.context());
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         td.render_close(&mut cursor.buffer);
 
@@ -63,6 +63,7 @@ impl<'root> Render<'root> for Renderer<'root, MjAccordionText, MjAccordionTextEx
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_families = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_families);
 
@@ -16,7 +16,7 @@ mod tests {
                     name: "name".into(),
                     others: Default::default(),
                 },
-                (),
+                Vec::new(),
             ),
         ));
         assert_eq!(
@@ -51,6 +51,15 @@ impl MjAttributes {
             })
     }
 
+    pub(crate) fn mj_attributes_class_element_iter(
+        &self,
+    ) -> impl Iterator<Item = (&str, &str, &str, &str)> {
+        self.children
+            .iter()
+            .filter_map(|child| child.as_mj_attributes_class())
+            .flat_map(|child| child.mj_attributes_element_iter())
+    }
+
     pub(crate) fn mj_attributes_element_iter(&self) -> impl Iterator<Item = (&str, &str, &str)> {
         self.children
             .iter()
@@ -16,6 +16,7 @@ impl JsonAttributes for super::MjAttributesClassAttributes {
 #[cfg(test)]
 mod tests {
     use crate::mj_attributes_class::{MjAttributesClass, MjAttributesClassAttributes};
+    use crate::mj_attributes_element::MjAttributesElement;
 
     #[test]
     fn serialize() {
@@ -24,7 +25,7 @@ mod tests {
                 name: "classname".into(),
                 others: Default::default(),
             },
-            (),
+            Vec::new(),
         );
         elt.attributes
             .others
@@ -35,9 +36,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_with_children() {
+        let mut child = MjAttributesElement::new("mj-text".into());
+        child.attributes.insert("color".into(), "blue".into());
+        let elt =
+            MjAttributesClass::new(MjAttributesClassAttributes::new("blue".into()), vec![child]);
+        let json = serde_json::to_string(&elt).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"mj-class","attributes":{"name":"blue"},"children":[{"type":"mj-element","name":"mj-text","attributes":{"color":"blue"}}]}"#
+        );
+        let res: MjAttributesClass = serde_json::from_str(&json).unwrap();
+        assert_eq!(res.children.len(), 1);
+    }
+
     #[test]
     fn deserialize() {
-        let elt = MjAttributesClass::new(MjAttributesClassAttributes::new("classname".into()), ());
+        let elt = MjAttributesClass::new(
+            MjAttributesClassAttributes::new("classname".into()),
+            Vec::new(),
+        );
         let json = serde_json::to_string(&elt).unwrap();
         let _res: MjAttributesClass = serde_json::from_str(&json).unwrap();
     }
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::mj_attributes_element::MjAttributesElement;
 use crate::prelude::hash::Map;
 use crate::prelude::{Component, StaticTag};
 
@@ -20,7 +21,7 @@ impl StaticTag for MjAttributesClassTag {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct MjAttributesClassAttributes {
     pub name: String,
@@ -28,8 +29,33 @@ pub struct MjAttributesClassAttributes {
     pub others: Map<String, String>,
 }
 
-pub type MjAttributesClass =
-    Component<PhantomData<MjAttributesClassTag>, MjAttributesClassAttributes, ()>;
+/// The children define defaults for the matching elements inside the ones
+/// using the class, like `<mj-class name="blue"><mj-text color="blue" /></mj-class>`.
+pub type MjAttributesClass = Component<
+    PhantomData<MjAttributesClassTag>,
+    MjAttributesClassAttributes,
+    Vec<MjAttributesElement>,
+>;
+
+#[cfg(feature = "render")]
+impl MjAttributesClass {
+    /// Iterates over the defaults of the child elements, as `(class, tag, key,
+    /// value)`.
+    pub(crate) fn mj_attributes_element_iter(
+        &self,
+    ) -> impl Iterator<Item = (&str, &str, &str, &str)> {
+        self.children.iter().flat_map(move |child| {
+            child.attributes.iter().map(move |(k, v)| {
+                (
+                    self.attributes.name.as_str(),
+                    child.name.as_str(),
+                    k.as_str(),
+                    v.as_str(),
+                )
+            })
+        })
+    }
+}
 
 #[cfg(all(test, feature = "json"))]
 impl MjAttributesClassAttributes {
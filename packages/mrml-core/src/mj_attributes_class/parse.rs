@@ -1,8 +1,11 @@
 use xmlparser::StrSpan;
 
 use super::{MjAttributesClass, MjAttributesClassAttributes};
+use crate::mj_attributes_element::parse_element;
 use crate::prelude::hash::Map;
-use crate::prelude::parser::{parse_attributes_map, Error, MrmlCursor, MrmlParser, ParseElement};
+use crate::prelude::parser::{
+    parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseElement,
+};
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseElement};
 
@@ -18,12 +21,30 @@ fn parse<'a>(cursor: &mut MrmlCursor<'a>, tag: StrSpan<'a>) -> Result<MjAttribut
         })?;
     let attributes = MjAttributesClassAttributes { name, others };
 
+    let mut children = Vec::new();
     let ending = cursor.assert_element_end()?;
     if !ending.empty {
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::ElementStart(inner) => {
+                    children.push(parse_element(cursor, inner.local)?);
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    break;
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
         cursor.assert_element_close()?;
     }
 
-    Ok(MjAttributesClass::new(attributes, ()))
+    Ok(MjAttributesClass::new(attributes, children))
 }
 
 impl<'opts> ParseElement<MjAttributesClass> for MrmlParser<'opts> {
@@ -68,6 +89,17 @@ mod tests {
         should_close,
         MjAttributesClass,
         r#"<mj-class name="div" color="red"><whatever>"#,
-        "UnexpectedToken { origin: Root, position: Span { start: 33, end: 42 } }"
+        "EndOfStream { origin: Root }"
+    );
+    crate::should_sync_parse!(
+        parse_with_children,
+        MjAttributesClass,
+        r#"<mj-class name="blue"><mj-text color="blue" /><mj-button background-color="blue" /></mj-class>"#
+    );
+    crate::should_not_sync_parse!(
+        should_not_have_text,
+        MjAttributesClass,
+        r#"<mj-class name="blue">hello</mj-class>"#,
+        "UnexpectedToken { origin: Root, position: Span { start: 22, end: 27 } }"
     );
 }
@@ -16,4 +16,21 @@ mod tests {
         let item = crate::mj_attributes_all::MjAttributesAll::default();
         assert_eq!("<mj-all />", item.print_dense().unwrap());
     }
+
+    #[test]
+    fn with_children() {
+        let mut child = crate::mj_attributes_element::MjAttributesElement::new("mj-text".into());
+        child.attributes.insert("color".into(), "blue".into());
+        let item = crate::mj_attributes_class::MjAttributesClass::new(
+            crate::mj_attributes_class::MjAttributesClassAttributes {
+                name: "blue".into(),
+                others: Default::default(),
+            },
+            vec![child],
+        );
+        assert_eq!(
+            "<mj-class name=\"blue\"><mj-text color=\"blue\" /></mj-class>",
+            item.print_dense().unwrap()
+        );
+    }
 }
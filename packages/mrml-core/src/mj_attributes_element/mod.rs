@@ -7,7 +7,10 @@ mod parse;
 #[cfg(feature = "print")]
 mod print;
 
-#[derive(Clone, Debug, Default)]
+#[cfg(feature = "parse")]
+pub(crate) use parse::parse as parse_element;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct MjAttributesElement {
     pub name: String,
     pub attributes: Map<String, String>,
@@ -7,7 +7,10 @@ use crate::prelude::parser::{parse_attributes_map, Error, MrmlCursor, MrmlParser
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseElement};
 
 #[inline]
-fn parse<'a>(cursor: &mut MrmlCursor<'a>, tag: StrSpan<'a>) -> Result<MjAttributesElement, Error> {
+pub(crate) fn parse<'a>(
+    cursor: &mut MrmlCursor<'a>,
+    tag: StrSpan<'a>,
+) -> Result<MjAttributesElement, Error> {
     let attributes: Map<String, String> = parse_attributes_map(cursor)?;
     let ending = cursor.assert_element_end()?;
     if !ending.empty {
@@ -8,6 +8,7 @@ use crate::mj_group::MjGroup;
 use crate::mj_hero::MjHero;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
+use crate::mj_list::MjList;
 use crate::mj_navbar::MjNavbar;
 use crate::mj_raw::MjRaw;
 use crate::mj_section::MjSection;
@@ -15,13 +16,15 @@ use crate::mj_social::MjSocial;
 use crate::mj_spacer::MjSpacer;
 use crate::mj_table::MjTable;
 use crate::mj_text::MjText;
+use crate::mj_video::MjVideo;
 use crate::mj_wrapper::MjWrapper;
 use crate::node::Node;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "render")]
-use crate::prelude::render::{Render, RenderContext, Renderable};
+use crate::prelude::render::{node_renderer, Render, RenderContext, Renderable};
 use crate::text::Text;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
@@ -36,6 +39,7 @@ pub enum MjBodyChild {
     MjHero(MjHero),
     MjInclude(MjIncludeBody),
     MjImage(MjImage),
+    MjList(MjList),
     MjNavbar(MjNavbar),
     MjRaw(MjRaw),
     MjSection(MjSection),
@@ -43,8 +47,10 @@ pub enum MjBodyChild {
     MjSpacer(MjSpacer),
     MjTable(MjTable),
     MjText(MjText),
+    MjVideo(MjVideo),
     MjWrapper(MjWrapper),
     Node(Node<MjBodyChild>),
+    Placeholder(Placeholder),
     Text(Text),
 }
 
@@ -62,6 +68,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
             Self::MjHero(elt) => elt.is_raw(),
             Self::MjInclude(elt) => elt.is_raw(),
             Self::MjImage(elt) => elt.is_raw(),
+            Self::MjList(elt) => elt.is_raw(),
             Self::MjNavbar(elt) => elt.is_raw(),
             Self::MjRaw(elt) => elt.is_raw(),
             Self::MjSection(elt) => elt.is_raw(),
@@ -69,8 +76,10 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
             Self::MjSpacer(elt) => elt.is_raw(),
             Self::MjTable(elt) => elt.is_raw(),
             Self::MjText(elt) => elt.is_raw(),
+            Self::MjVideo(elt) => elt.is_raw(),
             Self::MjWrapper(elt) => elt.is_raw(),
             Self::Node(elt) => elt.is_raw(),
+            Self::Placeholder(elt) => elt.is_raw(),
             Self::Text(elt) => elt.is_raw(),
         }
     }
@@ -90,6 +99,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
             Self::MjHero(elt) => elt.renderer(context),
             Self::MjInclude(elt) => elt.renderer(context),
             Self::MjImage(elt) => elt.renderer(context),
+            Self::MjList(elt) => elt.renderer(context),
             Self::MjNavbar(elt) => elt.renderer(context),
             Self::MjRaw(elt) => elt.renderer(context),
             Self::MjSection(elt) => elt.renderer(context),
@@ -97,8 +107,10 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjBodyChild {
             Self::MjSpacer(elt) => elt.renderer(context),
             Self::MjTable(elt) => elt.renderer(context),
             Self::MjText(elt) => elt.renderer(context),
+            Self::MjVideo(elt) => elt.renderer(context),
             Self::MjWrapper(elt) => elt.renderer(context),
-            Self::Node(elt) => elt.renderer(context),
+            Self::Node(elt) => node_renderer(context, elt),
+            Self::Placeholder(elt) => elt.renderer(context),
             Self::Text(elt) => elt.renderer(context),
         }
     }
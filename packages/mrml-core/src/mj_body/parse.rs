@@ -11,6 +11,7 @@ use crate::mj_group::NAME as MJ_GROUP;
 use crate::mj_hero::NAME as MJ_HERO;
 use crate::mj_image::NAME as MJ_IMAGE;
 use crate::mj_include::NAME as MJ_INCLUDE;
+use crate::mj_list::NAME as MJ_LIST;
 use crate::mj_navbar::NAME as MJ_NAVBAR;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_section::NAME as MJ_SECTION;
@@ -18,11 +19,14 @@ use crate::mj_social::NAME as MJ_SOCIAL;
 use crate::mj_spacer::NAME as MJ_SPACER;
 use crate::mj_table::NAME as MJ_TABLE;
 use crate::mj_text::NAME as MJ_TEXT;
+use crate::mj_video::NAME as MJ_VIDEO;
 use crate::mj_wrapper::NAME as MJ_WRAPPER;
 use crate::node::Node;
+use crate::placeholder::Placeholder;
 use crate::prelude::is_void_element;
 use crate::prelude::parser::{
-    parse_attributes_map, Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+    parse_attributes_map, parse_custom_element, Error, MrmlCursor, MrmlParser, MrmlToken,
+    ParseChildren, ParseElement,
 };
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
@@ -34,25 +38,22 @@ impl<'opts> ParseElement<Node<MjBodyChild>> for MrmlParser<'opts> {
         cursor: &mut MrmlCursor<'a>,
         tag: StrSpan<'a>,
     ) -> Result<Node<MjBodyChild>, Error> {
-        let tag = tag.to_string();
+        let name = tag.to_string();
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
-        if ending.empty || is_void_element(tag.as_str()) {
-            return Ok(Node {
-                tag,
-                attributes,
-                children: Vec::new(),
-            });
-        }
-        let children = self.parse_children(cursor)?;
-
-        cursor.assert_element_close()?;
-
-        Ok(Node {
-            tag,
+        let children = if ending.empty || is_void_element(name.as_str()) {
+            Vec::new()
+        } else {
+            let children = self.parse_children(cursor)?;
+            cursor.assert_element_close()?;
+            children
+        };
+        let element = Node {
+            tag: name,
             attributes,
             children,
-        })
+        };
+        parse_custom_element(&self.options.components, cursor, tag, element)
     }
 }
 
@@ -65,25 +66,22 @@ impl AsyncParseElement<Node<MjBodyChild>> for AsyncMrmlParser {
         cursor: &mut MrmlCursor<'a>,
         tag: StrSpan<'a>,
     ) -> Result<Node<MjBodyChild>, Error> {
-        let tag = tag.to_string();
+        let name = tag.to_string();
         let attributes = parse_attributes_map(cursor)?;
         let ending = cursor.assert_element_end()?;
-        if ending.empty || is_void_element(tag.as_str()) {
-            return Ok(Node {
-                tag,
-                attributes,
-                children: Vec::new(),
-            });
-        }
-        let children = self.async_parse_children(cursor).await?;
-
-        cursor.assert_element_close()?;
-
-        Ok(Node {
-            tag,
+        let children = if ending.empty || is_void_element(name.as_str()) {
+            Vec::new()
+        } else {
+            let children = self.async_parse_children(cursor).await?;
+            cursor.assert_element_close()?;
+            children
+        };
+        let element = Node {
+            tag: name,
             attributes,
             children,
-        })
+        };
+        parse_custom_element(&self.options.components, cursor, tag, element)
     }
 }
 
@@ -103,6 +101,7 @@ impl<'opts> ParseElement<MjBodyChild> for MrmlParser<'opts> {
             MJ_HERO => Ok(MjBodyChild::MjHero(self.parse(cursor, tag)?)),
             MJ_IMAGE => Ok(MjBodyChild::MjImage(self.parse(cursor, tag)?)),
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.parse(cursor, tag)?)),
+            MJ_LIST => Ok(MjBodyChild::MjList(self.parse(cursor, tag)?)),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.parse(cursor, tag)?)),
             MJ_SECTION => Ok(MjBodyChild::MjSection(self.parse(cursor, tag)?)),
@@ -110,6 +109,7 @@ impl<'opts> ParseElement<MjBodyChild> for MrmlParser<'opts> {
             MJ_SPACER => Ok(MjBodyChild::MjSpacer(self.parse(cursor, tag)?)),
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.parse(cursor, tag)?)),
+            MJ_VIDEO => Ok(MjBodyChild::MjVideo(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.parse(cursor, tag)?)),
             _ => Ok(MjBodyChild::Node(self.parse(cursor, tag)?)),
         }
@@ -139,6 +139,7 @@ impl AsyncParseElement<MjBodyChild> for AsyncMrmlParser {
             MJ_HERO => Ok(MjBodyChild::MjHero(self.async_parse(cursor, tag).await?)),
             MJ_IMAGE => Ok(MjBodyChild::MjImage(self.async_parse(cursor, tag).await?)),
             MJ_INCLUDE => Ok(MjBodyChild::MjInclude(self.async_parse(cursor, tag).await?)),
+            MJ_LIST => Ok(MjBodyChild::MjList(self.async_parse(cursor, tag).await?)),
             MJ_NAVBAR => Ok(MjBodyChild::MjNavbar(self.async_parse(cursor, tag).await?)),
             MJ_RAW => Ok(MjBodyChild::MjRaw(self.async_parse(cursor, tag).await?)),
             MJ_SECTION => Ok(MjBodyChild::MjSection(self.async_parse(cursor, tag).await?)),
@@ -146,6 +147,7 @@ impl AsyncParseElement<MjBodyChild> for AsyncMrmlParser {
             MJ_SPACER => Ok(MjBodyChild::MjSpacer(self.async_parse(cursor, tag).await?)),
             MJ_TABLE => Ok(MjBodyChild::MjTable(self.async_parse(cursor, tag).await?)),
             MJ_TEXT => Ok(MjBodyChild::MjText(self.async_parse(cursor, tag).await?)),
+            MJ_VIDEO => Ok(MjBodyChild::MjVideo(self.async_parse(cursor, tag).await?)),
             MJ_WRAPPER => Ok(MjBodyChild::MjWrapper(self.async_parse(cursor, tag).await?)),
             _ => Ok(MjBodyChild::Node(self.async_parse(cursor, tag).await?)),
         }
@@ -160,6 +162,9 @@ impl<'opts> ParseChildren<Vec<MjBodyChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjBodyChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::Text(inner) => {
                     result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
                 }
@@ -196,6 +201,9 @@ impl AsyncParseChildren<Vec<MjBodyChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     result.push(MjBodyChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjBodyChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::Text(inner) => {
                     result.push(MjBodyChild::Text(Text::from(inner.text.as_str())));
                 }
@@ -14,9 +14,13 @@ impl<'root> Renderer<'root, MjBody, ()> {
         self.set_body_style(Tag::new("body").add_style("word-spacing", "normal"))
     }
 
-    fn get_content_div_tag(&self) -> Tag {
+    fn get_content_div_tag(&self, dark_background: Option<&str>) -> Tag {
+        let classname = match (self.attribute("css-class"), dark_background) {
+            (Some(css_class), Some(dark)) => Some(format!("{css_class} {dark}")),
+            (css_class, dark) => css_class.or(dark).map(String::from),
+        };
         self.set_body_style(Tag::new("div"))
-            .maybe_add_attribute("class", self.attribute("css-class"))
+            .maybe_add_attribute("class", classname)
             .maybe_add_attribute("lang", self.context.header.lang())
     }
 
@@ -36,24 +40,27 @@ impl<'root> Renderer<'root, MjBody, ()> {
         }
     }
 
-    fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let div = self.get_content_div_tag();
+    fn render_content(
+        &self,
+        cursor: &mut RenderCursor,
+        dark_background: Option<&str>,
+    ) -> Result<(), Error> {
+        let div = self.get_content_div_tag(dark_background);
         let element_width = self.get_width();
 
         div.render_open(&mut cursor.buffer)?;
-        let raw_siblings = self
-            .element
-            .children
-            .iter()
-            .filter(|item| item.is_raw())
-            .count();
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
+        let children = cursor.buffer.bind_children(&self.element.children);
+        let raw_siblings = children.iter().filter(|item| item.element.is_raw()).count();
+        for (index, child) in children.iter().enumerate() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_container_width(element_width);
             renderer.set_index(index);
             renderer.set_raw_siblings(raw_siblings);
-            renderer.set_siblings(self.element.children.len());
-            renderer.render(cursor)?;
+            renderer.set_siblings(children.len());
+            renderer.render_component(cursor)?;
+            cursor.buffer.leave_child(mark);
         }
         div.render_close(&mut cursor.buffer);
         Ok(())
@@ -77,10 +84,14 @@ impl<'root> Render<'root> for Renderer<'root, MjBody, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let body = self.get_body_tag();
+        self.warn_attributes(cursor);
+        let dark_background = self.dark_class(cursor, "dark-background-color", "background-color");
+        let body = self
+            .get_body_tag()
+            .maybe_add_class(dark_background.as_deref());
         body.render_open(&mut cursor.buffer)?;
         self.render_preview(&mut cursor.buffer);
-        self.render_content(cursor)?;
+        self.render_content(cursor, dark_background.as_deref())?;
         body.render_close(&mut cursor.buffer);
         Ok(())
     }
@@ -11,7 +11,7 @@ mod print;
 
 pub const NAME: &str = "mj-breakpoint";
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct MjBreakpointAttributes {
     #[cfg_attr(feature = "json", serde(skip_serializing_if = "String::is_empty"))]
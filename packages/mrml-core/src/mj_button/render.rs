@@ -1,36 +1,20 @@
 use super::{MjButton, NAME};
-use crate::helper::size::Pixel;
+use crate::helper::size::{Pixel, Size};
+use crate::prelude::attributes::{MjButtonAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Renderer<'root, MjButton, ()> {
+impl<'root> Renderer<'root, MjButton, MjButtonAttributes> {
     fn content_width(&self) -> Option<String> {
-        if let Some(width) = self.attribute_as_pixel("width") {
-            let pad_left = self
-                .attribute_as_pixel("inner-padding-left")
-                .map(|pad| pad.value())
-                .or_else(|| {
-                    self.attribute_as_spacing("inner-padding")
-                        .map(|pad| pad.left().value())
-                })
-                .unwrap_or(0.0);
-            let pad_right = self
-                .attribute_as_pixel("inner-padding-right")
-                .map(|pad| pad.value())
-                .or_else(|| {
-                    self.attribute_as_spacing("inner-padding")
-                        .map(|pad| pad.right().value())
-                })
-                .unwrap_or(0.0);
-            Some(Pixel::new(width.value() - pad_left - pad_right).to_string())
-        } else {
-            None
-        }
+        self.attribute_as_pixel("width").map(|width| {
+            let padding = self.get_inner_padding("left") + self.get_inner_padding("right");
+            Pixel::new(width.value() - padding).to_string()
+        })
     }
 
     fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         for child in self.element.children.iter() {
             let renderer = child.renderer(self.context());
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         Ok(())
     }
@@ -64,6 +48,105 @@ impl<'root> Renderer<'root, MjButton, ()> {
             .maybe_add_style("background", self.attribute("background-color"))
     }
 
+    fn get_inner_padding(&self, side: &str) -> f32 {
+        self.attribute_as_pixel(&format!("inner-padding-{side}"))
+            .map(|pad| pad.value())
+            .or_else(|| {
+                self.attribute_as_spacing("inner-padding")
+                    .map(|pad| match side {
+                        "top" => pad.top().value(),
+                        "bottom" => pad.bottom().value(),
+                        "left" => pad.left().value(),
+                        _ => pad.right().value(),
+                    })
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// Width of the VML button: the given width in pixels or, when it's a
+    /// percentage, estimated from the width of the box containing the button.
+    /// Without a width, the button gets the width of its content.
+    fn get_vml_width(&self) -> Option<f32> {
+        match self.attribute_as_size("width")? {
+            Size::Pixel(width) => Some(width.value()),
+            Size::Percent(width) => self.container_width.as_ref().map(|container| {
+                let box_width = container.value() - self.get_padding_horizontal().value();
+                (box_width * width.value() / 100.0).round()
+            }),
+            Size::Raw(_) => None,
+        }
+    }
+
+    /// Height of the VML button: the given height or the one of a line of text
+    /// with the inner padding.
+    fn get_vml_height(&self) -> f32 {
+        if let Some(height) = self.attribute_as_pixel("height") {
+            return height.value();
+        }
+        let font_size = self
+            .attribute_as_pixel("font-size")
+            .map(|size| size.value())
+            .unwrap_or(13.0);
+        let line_height = match self.attribute_as_size("line-height") {
+            Some(Size::Percent(value)) => font_size * value.value() / 100.0,
+            Some(Size::Pixel(value)) => value.value(),
+            _ => self
+                .attribute("line-height")
+                .and_then(|value| value.parse::<f32>().ok())
+                .map(|value| font_size * value)
+                .unwrap_or(font_size),
+        };
+        (line_height + self.get_inner_padding("top") + self.get_inner_padding("bottom")).round()
+    }
+
+    /// Renders the button as a VML rounded rectangle for Outlook, that ignores
+    /// the padding and the border radius of the link. The content is the one
+    /// already rendered for the link.
+    fn render_vml(&self, cursor: &mut RenderCursor, content: &str) -> Result<(), Error> {
+        let width = self.get_vml_width();
+        let height = self.get_vml_height();
+        let radius = self
+            .attribute_as_pixel("border-radius")
+            .map(|radius| radius.value())
+            .unwrap_or(0.0);
+        let side = width.map_or(height, |width| width.min(height));
+        let arcsize = if side > 0.0 {
+            (radius * 100.0 / side).round()
+        } else {
+            0.0
+        };
+        let border = self.attribute("border").filter(|value| *value != "none");
+        let stroke_weight = border.and_then(|value| value.split_whitespace().next());
+        let stroke_color = border.and_then(|value| value.split_whitespace().last());
+        let roundrect = Tag::new("v:roundrect")
+            .add_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")
+            .add_attribute("xmlns:w", "urn:schemas-microsoft-com:office:word")
+            .maybe_add_attribute("href", self.attribute("href"))
+            .add_attribute("arcsize", format!("{arcsize}%"))
+            .add_attribute("stroke", if border.is_some() { "t" } else { "f" })
+            .maybe_add_attribute("strokeweight", stroke_weight)
+            .maybe_add_attribute("strokecolor", stroke_color)
+            .maybe_add_attribute("fillcolor", self.attribute("background-color"))
+            .add_style("height", Pixel::new(height).to_string())
+            .add_style("v-text-anchor", "middle")
+            .maybe_add_style("width", width.map(|width| Pixel::new(width).to_string()));
+        let center = Tag::new("center")
+            .maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("font-weight", self.attribute("font-weight"));
+
+        cursor.buffer.start_mso_conditional_tag();
+        roundrect.render_open(&mut cursor.buffer)?;
+        cursor.buffer.push_str("<w:anchorlock/>");
+        center.render_open(&mut cursor.buffer)?;
+        cursor.buffer.push_verbatim(content);
+        center.render_close(&mut cursor.buffer);
+        roundrect.render_close(&mut cursor.buffer);
+        cursor.buffer.end_conditional_tag();
+        Ok(())
+    }
+
     fn set_style_content<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -88,7 +171,7 @@ impl<'root> Renderer<'root, MjButton, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjButton, MjButtonAttributes> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "align" => Some("center"),
@@ -110,6 +193,10 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -122,15 +209,24 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
         self.context
     }
 
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_family = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_family);
 
+        let dark_background = self.dark_class(cursor, "dark-background-color", "background-color");
+        let dark_color = self.dark_class(cursor, "dark-color", "color");
+
         let table = self.set_style_table(Tag::table_presentation());
         let tbody = Tag::tbody();
         let tr = Tag::tr();
         let td = self
             .set_style_td(Tag::td())
+            .maybe_add_class(dark_background.clone())
             .add_attribute("align", "center")
             .maybe_add_attribute("bgcolor", self.attribute("background-color"))
             .add_attribute("role", "presentation")
@@ -144,20 +240,42 @@ impl<'root> Render<'root> for Renderer<'root, MjButton, ()> {
                 self.attribute("href")
                     .and_then(|_v| self.attribute("target")),
             );
-        let link = self.set_style_content(link);
+        let link = self
+            .set_style_content(link)
+            .maybe_add_class(dark_background)
+            .maybe_add_class(dark_color);
+
+        // the content is rendered once and written in both buttons
+        let content = if self.attribute_equals("vml", "true") {
+            let previous = cursor.buffer.take();
+            self.render_children(cursor)?;
+            let content = std::mem::replace(&mut cursor.buffer, previous);
+            self.render_vml(cursor, content.as_ref())?;
+            cursor.buffer.start_mso_negation_conditional_tag();
+            Some(content)
+        } else {
+            None
+        };
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
         tr.render_open(&mut cursor.buffer)?;
         td.render_open(&mut cursor.buffer)?;
         link.render_open(&mut cursor.buffer)?;
-        self.render_children(cursor)?;
+        match content {
+            Some(ref content) => cursor.buffer.push_verbatim(content.as_ref()),
+            None => self.render_children(cursor)?,
+        }
         link.render_close(&mut cursor.buffer);
         td.render_close(&mut cursor.buffer);
         tr.render_close(&mut cursor.buffer);
         tbody.render_close(&mut cursor.buffer);
         table.render_close(&mut cursor.buffer);
 
+        if content.is_some() {
+            cursor.buffer.end_negation_conditional_tag();
+        }
+
         Ok(())
     }
 }
@@ -167,7 +285,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjButton {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjButtonAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -198,4 +320,58 @@ mod tests {
     crate::should_render!(text_transform, "mj-button-text-transform");
     crate::should_render!(vertical_align, "mj-button-vertical-align");
     crate::should_render!(width, "mj-button-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_vml_button() {
+        let template = r##"<mjml><mj-body><mj-section><mj-column>
+<mj-button vml="true" href="https://example.com" width="200px" border-radius="10px">Go</mj-button>
+<mj-button vml="true" border="2px solid #000000" font-size="20px" inner-padding="5px 10px">Fill</mj-button>
+<mj-button vml="true" width="50%">Half</mj-button>
+<mj-button>Plain</mj-button>
+</mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r##"<!--[if mso]><v:roundrect xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="urn:schemas-microsoft-com:office:word" href="https://example.com" arcsize="28%" stroke="f" fillcolor="#414141" style="height:36px;v-text-anchor:middle;width:200px;"><w:anchorlock/><center style="color:#ffffff;font-family:Ubuntu, Helvetica, Arial, sans-serif;font-size:13px;font-weight:normal;">Go</center></v:roundrect><![endif]--><!--[if !mso]><!--><table"##));
+        assert!(html.contains(r##"stroke="t" strokeweight="2px" strokecolor="#000000" fillcolor="#414141" style="height:34px;v-text-anchor:middle;""##));
+        // the percentage is estimated from the 550px of the column content
+        assert!(html.contains(r##"style="height:36px;v-text-anchor:middle;width:275px;""##));
+        assert_eq!(html.matches("<v:roundrect").count(), 3);
+        assert_eq!(html.matches("<!--[if !mso]><!--><table").count(), 3);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_vml_button_content_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        use crate::prelude::render::{RenderHook, RenderOptions, Tag};
+
+        #[derive(Debug, Default)]
+        struct Counter(AtomicUsize);
+
+        impl RenderHook for Counter {
+            fn on_tag(&self, _component: Option<&str>, tag: &mut Tag<'_>) {
+                if tag.name() == "b" {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        }
+
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-button vml="true" height="0px" border-radius="10px"><b>Go</b></mj-button>
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let counter = Arc::new(Counter::default());
+        let opts = RenderOptions {
+            hook: Some(counter.clone()),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"arcsize="0%""#), "{}", html);
+        assert_eq!(html.matches("<b>Go</b>").count(), 2);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
 }
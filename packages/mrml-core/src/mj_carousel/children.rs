@@ -1,11 +1,13 @@
 use crate::comment::Comment;
 use crate::mj_carousel_image::MjCarouselImage;
+use crate::placeholder::Placeholder;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
 pub enum MjCarouselChild {
     Comment(Comment),
     MjCarouselImage(MjCarouselImage),
+    Placeholder(Placeholder),
 }
@@ -1,6 +1,7 @@
 use super::MjCarouselChild;
 use crate::comment::Comment;
 use crate::mj_carousel_image::NAME as MJ_CAROUSEL_IMAGE;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
@@ -16,6 +17,9 @@ impl<'opts> ParseChildren<Vec<MjCarouselChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     result.push(MjCarouselChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjCarouselChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_CAROUSEL_IMAGE {
                         result.push(MjCarouselChild::MjCarouselImage(
@@ -58,6 +62,9 @@ impl AsyncParseChildren<Vec<MjCarouselChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     result.push(MjCarouselChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjCarouselChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_CAROUSEL_IMAGE {
                         result.push(MjCarouselChild::MjCarouselImage(
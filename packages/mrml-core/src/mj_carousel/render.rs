@@ -20,6 +20,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCarouselChild {
         match self {
             Self::MjCarouselImage(elt) => elt.renderer(context),
             Self::Comment(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
         }
     }
 }
@@ -101,6 +102,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
 
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.add_extra_attribute("carousel-id", &self.extra.id);
             renderer.maybe_add_extra_attribute("border-radius", border_radius);
             renderer.maybe_add_extra_attribute("tb-border", tb_border);
@@ -122,6 +124,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
 
             for (index, child) in self.element.children.iter().enumerate() {
                 let mut renderer = child.renderer(self.context());
+                renderer.set_mj_class_scope(self.children_mj_class_scope());
                 renderer.add_extra_attribute("carousel-id", &self.extra.id);
                 renderer.maybe_add_extra_attribute("border-radius", border_radius);
                 renderer.maybe_add_extra_attribute("tb-border", tb_border);
@@ -185,6 +188,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
 
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.add_extra_attribute("carousel-id", &self.extra.id);
             renderer.maybe_add_extra_attribute("border-radius", self.attribute("border-radius"));
             renderer.maybe_add_extra_attribute("tb-border", self.attribute("tb-border"));
@@ -192,7 +196,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
                 .maybe_add_extra_attribute("tb-border-radius", self.attribute("tb-border-radius"));
             renderer.set_index(index);
             renderer.set_container_width(self.container_width);
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
 
         div.render_close(&mut cursor.buffer);
@@ -240,6 +244,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
             .find_map(|child| child.as_mj_carousel_image())
         {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.add_extra_attribute("carousel-id", &self.extra.id);
             renderer.maybe_add_extra_attribute("border-radius", self.attribute("border-radius"));
             renderer.maybe_add_extra_attribute("tb-border", self.attribute("tb-border"));
@@ -248,7 +253,7 @@ impl<'root> Renderer<'root, MjCarousel, MjCarouselExtra> {
             renderer.set_container_width(self.container_width);
 
             cursor.buffer.start_mso_conditional_tag();
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
             cursor.buffer.end_conditional_tag();
         }
         Ok(())
@@ -452,6 +457,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarousel, MjCarouselExtra> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         cursor.header.maybe_add_style(self.render_style());
 
         let inner_div = self
@@ -480,7 +486,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjCarousel {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        let id = context.generator.next_id();
+        let id = context.generator.next_id_for(self);
         Box::new(Renderer::new(context, self, MjCarouselExtra { id }))
     }
 }
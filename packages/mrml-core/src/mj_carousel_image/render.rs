@@ -192,6 +192,7 @@ impl<'root> Render<'root> for Renderer<'root, MjCarouselImage, MjCarouselImageEx
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let img = self
             .set_style_images_img(Tag::new("img"))
             .add_attribute("border", "0")
@@ -1,10 +1,13 @@
 use super::{MjColumn, NAME};
+use crate::helper::background::WithBackground;
 use crate::helper::size::{Pixel, Size};
+use crate::prelude::attributes::{MjColumnAttributes, ParsedAttributes};
 use crate::prelude::hash::Map;
 use crate::prelude::render::*;
 
 struct MjColumnExtra<'a> {
     attributes: Map<&'a str, &'a str>,
+    parsed: MjColumnAttributes,
 }
 
 impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
@@ -165,8 +168,12 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         'root: 'a,
         'a: 't,
     {
-        tag.maybe_add_style("background-color", self.attribute("background-color"))
-            .maybe_add_style("border", self.attribute("border"))
+        let tag = if self.has_background() {
+            self.set_background_style(tag)
+        } else {
+            tag.maybe_add_style("background-color", self.attribute("background-color"))
+        };
+        tag.maybe_add_style("border", self.attribute("border"))
             .maybe_add_style("border-bottom", self.attribute("border-bottom"))
             .maybe_add_style("border-left", self.attribute("border-left"))
             .maybe_add_style("border-radius", self.attribute("border-radius"))
@@ -192,7 +199,9 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         let table = Tag::table_presentation().add_attribute("width", "100%");
         let tbody = Tag::tbody();
         let tr = Tag::tr();
-        let td = self.set_style_gutter_td(Tag::td());
+        let td = self
+            .set_style_gutter_td(Tag::td())
+            .maybe_add_class(self.dark_class(cursor, "dark-background-color", "background-color"));
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
@@ -220,25 +229,35 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     }
 
     fn render_column(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let dark_background = if self.has_gutter() {
+            self.dark_class(cursor, "dark-inner-background-color", "background-color")
+                .or_else(|| self.dark_class(cursor, "dark-background-color", "background-color"))
+        } else {
+            self.dark_class(cursor, "dark-background-color", "background-color")
+        };
         let table = self
             .set_style_table(Tag::table_presentation())
-            .add_attribute("width", "100%");
+            .add_attribute("width", "100%")
+            .maybe_add_class(dark_background);
         let tbody = Tag::tbody();
-        let siblings = self.element.children.len();
-        let raw_siblings = self.element.children.iter().filter(|i| i.is_raw()).count();
+        let children = cursor.buffer.bind_children(&self.element.children);
+        let siblings = children.len();
+        let raw_siblings = children.iter().filter(|i| i.element.is_raw()).count();
         let current_width = self.current_width();
 
         table.render_open(&mut cursor.buffer)?;
         tbody.render_open(&mut cursor.buffer)?;
 
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
+        for (index, child) in children.iter().enumerate() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_siblings(siblings);
             renderer.set_container_width(current_width);
-            if child.is_raw() {
-                renderer.render(cursor)?;
+            if child.element.is_raw() {
+                renderer.render_component(cursor)?;
             } else {
                 let tr = Tag::tr();
                 let td = Tag::td()
@@ -259,10 +278,11 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
 
                 tr.render_open(&mut cursor.buffer)?;
                 td.render_open(&mut cursor.buffer)?;
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 td.render_close(&mut cursor.buffer);
                 tr.render_close(&mut cursor.buffer);
             }
+            cursor.buffer.leave_child(mark);
         }
 
         tbody.render_close(&mut cursor.buffer);
@@ -272,9 +292,14 @@ impl<'root> Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     }
 }
 
+impl<'root> WithBackground<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {}
+
 impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
+            "background-position" => Some("top center"),
+            "background-repeat" => Some("repeat"),
+            "background-size" => Some("auto"),
             "direction" => Some("ltr"),
             "vertical-align" => Some("top"),
             _ => None,
@@ -285,6 +310,10 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
         self.current_width().map(Size::Pixel)
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra.parsed)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -329,6 +358,7 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let (classname, size) = self.get_column_class();
         cursor.header.add_media_query(classname.clone(), size);
 
@@ -338,14 +368,24 @@ impl<'root> Render<'root> for Renderer<'root, MjColumn, MjColumnExtra<'root>> {
             .add_class(classname)
             .maybe_add_class(self.attribute("css-class"));
 
-        div.render_open(&mut cursor.buffer)?;
-        if self.has_gutter() {
-            self.render_gutter(cursor)?;
+        let render_div = |cursor: &mut RenderCursor| -> Result<(), Error> {
+            div.render_open(&mut cursor.buffer)?;
+            if self.has_gutter() {
+                self.render_gutter(cursor)?;
+            } else {
+                self.render_column(cursor)?;
+            }
+            div.render_close(&mut cursor.buffer);
+            Ok(())
+        };
+        if self.has_background() {
+            cursor.buffer.start_conditional_tag();
+            self.render_vml_background(cursor, false, Some(self.get_width_as_pixel()), render_div)?;
+            cursor.buffer.end_conditional_tag();
+            Ok(())
         } else {
-            self.render_column(cursor)?;
+            render_div(cursor)
         }
-        div.render_close(&mut cursor.buffer);
-        Ok(())
     }
 }
 
@@ -359,6 +399,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjColumn {
             self,
             MjColumnExtra {
                 attributes: Map::new(),
+                parsed: MjColumnAttributes::for_render(&self.attributes),
             },
         ))
     }
@@ -375,4 +416,21 @@ mod tests {
     crate::should_render!(padding, "mj-column-padding");
     crate::should_render!(vertical_align, "mj-column-vertical-align");
     crate::should_render!(width, "mj-column-width");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_background_image() {
+        let template = r##"<mjml><mj-body><mj-section><mj-column width="50%" background-url="https://example.com/bg.png" background-color="#ff0000" background-size="cover"><mj-text>Hello</mj-text></mj-column><mj-column><mj-text>World</mj-text></mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(
+            "background:#ff0000 url('https://example.com/bg.png') center top / cover repeat;"
+        ));
+        assert!(html.contains(r#"<v:rect xmlns:v="urn:schemas-microsoft-com:vml" fill="true" stroke="false" style="width:300px;">"#));
+        assert!(html.contains(
+            r##"src="https://example.com/bg.png" color="#ff0000" size="1,1" type="tile" aspect="atleast""##
+        ));
+        assert_eq!(html.matches("<v:rect").count(), 1);
+    }
 }
@@ -1,8 +1,9 @@
 use super::{MjDivider, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::prelude::attributes::{MjDividerAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Renderer<'root, MjDivider, ()> {
+impl<'root> Renderer<'root, MjDivider, MjDividerAttributes> {
     fn set_style_p_without_width<'t>(&self, tag: Tag<'t>) -> Tag<'t> {
         tag.add_style(
             "border-top",
@@ -69,7 +70,7 @@ impl<'root> Renderer<'root, MjDivider, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjDivider, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjDivider, MjDividerAttributes> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "align" => Some("center"),
@@ -82,6 +83,10 @@ impl<'root> Render<'root> for Renderer<'root, MjDivider, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -99,7 +104,10 @@ impl<'root> Render<'root> for Renderer<'root, MjDivider, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let p = self.set_style_p(Tag::new("p"));
+        self.warn_attributes(cursor);
+        let p = self
+            .set_style_p(Tag::new("p"))
+            .maybe_add_class(self.dark_class(cursor, "dark-border-color", "border-top-color"));
         p.render_text(&mut cursor.buffer, "")?;
 
         self.render_after(&mut cursor.buffer)?;
@@ -112,7 +120,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjDivider {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjDividerAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
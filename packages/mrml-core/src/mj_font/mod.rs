@@ -11,7 +11,7 @@ mod print;
 
 pub const NAME: &str = "mj-font";
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct MjFontAttributes {
     pub name: String,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use super::{MjGroup, NAME};
 use crate::helper::size::{Pixel, Size};
+use crate::mj_section::warn_column_widths;
 use crate::prelude::render::*;
 
 impl<'root> Renderer<'root, MjGroup, ()> {
@@ -76,23 +77,28 @@ impl<'root> Renderer<'root, MjGroup, ()> {
 
     fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let current_width = self.current_width();
-        let siblings = self.element.children.len();
-        let raw_siblings = self
-            .element
-            .children
-            .iter()
-            .filter(|item| item.is_raw())
-            .count();
-
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
+        warn_column_widths(
+            self.context,
+            NAME,
+            &self.element.children,
+            Some(current_width),
+            cursor,
+        );
+        let children = cursor.buffer.bind_children(&self.element.children);
+        let siblings = children.len();
+        let raw_siblings = children.iter().filter(|item| item.element.is_raw()).count();
+
+        for (index, child) in children.iter().enumerate() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(Some(current_width));
             renderer.add_extra_attribute("mobile-width", "mobile-width");
-            if child.is_raw() {
-                renderer.render(cursor)?;
+            if child.element.is_raw() {
+                renderer.render_component(cursor)?;
             } else {
                 let td = Tag::td()
                     .maybe_add_style("align", renderer.attribute("align"))
@@ -108,11 +114,12 @@ impl<'root> Renderer<'root, MjGroup, ()> {
                 cursor.buffer.start_conditional_tag();
                 td.render_open(&mut cursor.buffer)?;
                 cursor.buffer.end_conditional_tag();
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 cursor.buffer.start_conditional_tag();
                 td.render_close(&mut cursor.buffer);
                 cursor.buffer.end_conditional_tag();
             }
+            cursor.buffer.leave_child(mark);
         }
         Ok(())
     }
@@ -166,6 +173,7 @@ impl<'root> Render<'root> for Renderer<'root, MjGroup, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let (classname, size) = self.get_column_class();
         cursor.header.add_media_query(classname.clone(), size);
 
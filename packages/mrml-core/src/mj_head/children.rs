@@ -7,8 +7,9 @@ use crate::mj_preview::MjPreview;
 use crate::mj_raw::MjRaw;
 use crate::mj_style::MjStyle;
 use crate::mj_title::MjTitle;
+use crate::placeholder::Placeholder;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
@@ -23,4 +24,5 @@ pub enum MjHeadChild {
     MjRaw(MjRaw),
     MjStyle(MjStyle),
     MjTitle(MjTitle),
+    Placeholder(Placeholder),
 }
@@ -10,6 +10,7 @@ use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_style::NAME as MJ_STYLE;
 use crate::mj_title::NAME as MJ_TITLE;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
@@ -24,6 +25,9 @@ impl<'opts> ParseChildren<Vec<MjHeadChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjHeadChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.parse(cursor, inner.local)?);
                 }
@@ -58,6 +62,9 @@ impl AsyncParseChildren<Vec<MjHeadChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     result.push(MjHeadChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjHeadChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.async_parse(cursor, inner.local).await?);
                 }
@@ -72,6 +72,28 @@ impl MjHead {
             .fold(Map::new(), combine_attribute_map)
     }
 
+    pub fn build_attributes_class_element(&self) -> Map<&str, Map<&str, Map<&str, &str>>> {
+        self.children
+            .iter()
+            .flat_map(|item| {
+                item.as_mj_attributes()
+                    .into_iter()
+                    .flat_map(|inner| inner.mj_attributes_class_element_iter())
+                    .chain(
+                        item.as_mj_include()
+                            .filter(|item| item.0.attributes.kind.is_mjml())
+                            .into_iter()
+                            .flat_map(|inner| inner.mj_attributes_class_element_iter()),
+                    )
+            })
+            .fold(Map::new(), |mut res, (class, tag, key, value)| {
+                let entry = res.entry(class).or_default();
+                let entry = entry.entry(tag).or_default();
+                entry.insert(key, value);
+                res
+            })
+    }
+
     pub fn build_attributes_element(&self) -> Map<&str, Map<&str, &str>> {
         self.children
             .iter()
@@ -107,16 +129,51 @@ impl MjHead {
     }
 }
 
+// font families that don't need to be imported to be used
+const SAFE_FONT_FAMILIES: [&str; 16] = [
+    "Arial",
+    "Courier",
+    "Courier New",
+    "Georgia",
+    "Helvetica",
+    "Tahoma",
+    "Times",
+    "Times New Roman",
+    "Trebuchet MS",
+    "Verdana",
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+];
+
+// swaps the images having a `dark-src` with their dark variant
+const DARK_IMAGE_RULES: [&str; 2] = [
+    ".mj-dark-img { display:block !important; max-height:none !important; overflow:visible !important; } ",
+    ".mj-light-img { display:none !important; } ",
+];
+
+fn is_safe_font_family(name: &str) -> bool {
+    let name = name.trim_matches(|c| c == '"' || c == '\'');
+    SAFE_FONT_FAMILIES
+        .iter()
+        .any(|item| item.eq_ignore_ascii_case(name))
+}
+
 fn render_font_import(target: &mut String, href: &str) {
     target.push_str("@import url(");
     target.push_str(href);
     target.push_str(");");
 }
 
-fn render_font_link(target: &mut String, href: &str) {
-    target.push_str("<link href=\"");
-    target.push_str(href);
-    target.push_str("\" rel=\"stylesheet\" type=\"text/css\">");
+fn render_font_link(buffer: &mut RenderBuffer, href: &str) -> std::fmt::Result {
+    Tag::new("link")
+        .add_attribute("href", href)
+        .add_attribute("rel", "stylesheet")
+        .add_attribute("type", "text/css")
+        .render_open(buffer)
 }
 
 impl<'root> Renderer<'root, MjHead, ()> {
@@ -159,24 +216,31 @@ impl<'root> Renderer<'root, MjHead, ()> {
             return;
         }
 
-        let mut links = String::default();
+        let mut links: Vec<&str> = Vec::new();
         let mut imports = String::default();
+        let mut unknown = Vec::new();
         for name in cursor.header.used_font_families().iter() {
             if let Some(href) = self.context.header.font_families().get(name.as_str()) {
-                render_font_link(&mut links, href);
+                links.push(*href);
                 render_font_import(&mut imports, href);
             } else if let Some(href) = self.context.options.fonts.get(name) {
-                render_font_link(&mut links, href);
+                links.push(href.as_ref());
                 render_font_import(&mut imports, href);
-            } else {
-                // TODO log a warning
+            } else if !is_safe_font_family(name) {
+                unknown.push(name.clone());
             }
         }
+        for name in unknown {
+            cursor.add_warning(WarningKind::UnknownFontFamily, None, name);
+        }
 
         if links.is_empty() && imports.is_empty() {
         } else {
             cursor.buffer.start_mso_negation_conditional_tag();
-            cursor.buffer.push_str(&links);
+            for href in links {
+                // the links go through the render hook, to proxy the fonts
+                let _ = render_font_link(&mut cursor.buffer, href);
+            }
             if !imports.is_empty() {
                 cursor.buffer.push_str("<style type=\"text/css\">");
                 cursor.buffer.push_str(&imports);
@@ -227,6 +291,53 @@ impl<'root> Renderer<'root, MjHead, ()> {
         cursor.buffer.push_str("</style>");
     }
 
+    fn render_dark_mode(&self, cursor: &mut RenderCursor) {
+        if !cursor.header.has_dark_mode() {
+            return;
+        }
+        let mut rules = String::new();
+        let mut outlook_rules = String::new();
+        for (classname, (property, value)) in cursor.header.dark_styles().iter() {
+            let declaration = format!(" {{ {property}:{value} !important; }} ");
+            rules.push('.');
+            rules.push_str(classname);
+            rules.push_str(&declaration);
+            // outlook.com flags the changed text colors and background colors
+            // with different attributes
+            outlook_rules.push_str(if property.starts_with("background") {
+                "[data-ogsb] ."
+            } else {
+                "[data-ogsc] ."
+            });
+            outlook_rules.push_str(classname);
+            outlook_rules.push_str(&declaration);
+        }
+        if cursor.header.has_dark_images() {
+            for rule in DARK_IMAGE_RULES {
+                rules.push_str(rule);
+                outlook_rules.push_str("[data-ogsc] ");
+                outlook_rules.push_str(rule);
+            }
+        }
+        cursor
+            .buffer
+            .push_str("<meta name=\"color-scheme\" content=\"light dark\">");
+        cursor
+            .buffer
+            .push_str("<meta name=\"supported-color-schemes\" content=\"light dark\">");
+        cursor.buffer.push_str("<style type=\"text/css\">");
+        cursor
+            .buffer
+            .push_str(":root { color-scheme: light dark; supported-color-schemes: light dark; } ");
+        cursor
+            .buffer
+            .push_str("@media (prefers-color-scheme: dark) { ");
+        cursor.buffer.push_str(&rules);
+        cursor.buffer.push_str("} ");
+        cursor.buffer.push_str(&outlook_rules);
+        cursor.buffer.push_str("</style>");
+    }
+
     fn render_styles(&self, cursor: &mut RenderCursor) {
         if !cursor.header.styles().is_empty() {
             cursor.buffer.push_str("<style type=\"text/css\">");
@@ -244,6 +355,16 @@ impl<'root> Renderer<'root, MjHead, ()> {
         cursor.buffer.push_str("</style>");
     }
 
+    /// Renders the styles that the rendered body elements rely on: the used
+    /// fonts, the media queries, the dark mode rules, the styles they registered
+    /// and the `mj-style` content.
+    pub(crate) fn render_head_styles(&self, cursor: &mut RenderCursor) {
+        self.render_font_families(cursor);
+        self.render_media_queries(cursor);
+        self.render_dark_mode(cursor);
+        self.render_styles(cursor);
+    }
+
     fn render_raw(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let mut index: usize = 0;
         let siblings = self.element.children.len();
@@ -252,16 +373,24 @@ impl<'root> Renderer<'root, MjHead, ()> {
                 let mut renderer = mj_raw.renderer(self.context());
                 renderer.set_index(index);
                 renderer.set_siblings(siblings);
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 index += 1;
+            } else if let Some(placeholder) = child.as_placeholder() {
+                placeholder
+                    .renderer(self.context())
+                    .render_component(cursor)?;
             } else if let Some(mj_include) = child.as_mj_include() {
                 for include_child in mj_include.0.children.iter() {
                     if let Some(mj_raw) = include_child.as_mj_raw() {
                         let mut renderer = mj_raw.renderer(self.context());
                         renderer.set_index(index);
                         renderer.set_siblings(siblings);
-                        renderer.render(cursor)?;
+                        renderer.render_component(cursor)?;
                         index += 1;
+                    } else if let Some(placeholder) = include_child.as_placeholder() {
+                        placeholder
+                            .renderer(self.context())
+                            .render_component(cursor)?;
                     }
                 }
             }
@@ -271,33 +400,42 @@ impl<'root> Renderer<'root, MjHead, ()> {
 }
 
 impl<'root> Render<'root> for Renderer<'root, MjHead, ()> {
+    fn tag(&self) -> Option<&str> {
+        Some(super::NAME)
+    }
+
     fn context(&self) -> &'root RenderContext<'root> {
         self.context
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        cursor.buffer.push_str("<head>");
+        // the tags are written through the render hook, like the ones of the
+        // body
+        Tag::new("head").render_open(&mut cursor.buffer)?;
         // we write the title even though there is no content
-        cursor.buffer.push_str("<title>");
-        if let Some(title) = self.element.title().map(|item| item.content()) {
-            cursor.buffer.push_str(title);
-        }
-        cursor.buffer.push_str("</title>");
+        Tag::new("title").render_text(
+            &mut cursor.buffer,
+            self.element
+                .title()
+                .map(|item| item.content())
+                .unwrap_or_default(),
+        )?;
         cursor.buffer.start_mso_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"X-UA-Compatible\" content=\"IE=edge\">");
+        Tag::new("meta")
+            .add_attribute("http-equiv", "X-UA-Compatible")
+            .add_attribute("content", "IE=edge")
+            .render_open(&mut cursor.buffer)?;
         cursor.buffer.end_negation_conditional_tag();
-        cursor
-            .buffer
-            .push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=UTF-8\">");
-        cursor
-            .buffer
-            .push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+        Tag::new("meta")
+            .add_attribute("http-equiv", "Content-Type")
+            .add_attribute("content", "text/html; charset=UTF-8")
+            .render_open(&mut cursor.buffer)?;
+        Tag::new("meta")
+            .add_attribute("name", "viewport")
+            .add_attribute("content", "width=device-width, initial-scale=1")
+            .render_open(&mut cursor.buffer)?;
         cursor.buffer.push_str(STYLE_BASE);
-        self.render_font_families(cursor);
-        self.render_media_queries(cursor);
-        self.render_styles(cursor);
+        self.render_head_styles(cursor);
         self.render_raw(cursor)?;
         cursor.buffer.push_str("</head>");
         Ok(())
@@ -392,7 +530,7 @@ mod tests {
                                     String::from("42px"),
                                 )]),
                             },
-                            (),
+                            Vec::new(),
                         ),
                     )],
                 )),
@@ -412,7 +550,7 @@ mod tests {
                                         String::from("21px"),
                                     )]),
                                 },
-                                (),
+                                Vec::new(),
                             )),
                             MjAttributesChild::MjAttributesClass(MjAttributesClass::new(
                                 MjAttributesClassAttributes {
@@ -422,7 +560,7 @@ mod tests {
                                         String::from("center"),
                                     )]),
                                 },
-                                (),
+                                Vec::new(),
                             )),
                         ],
                     ))],
@@ -438,7 +576,7 @@ mod tests {
                                     String::from("left"),
                                 )]),
                             },
-                            (),
+                            Vec::new(),
                         ),
                     )],
                 )),
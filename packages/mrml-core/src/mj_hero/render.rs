@@ -140,15 +140,18 @@ impl<'root> Renderer<'root, MjHero, ()> {
     }
 
     fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let siblings = self.element.children.len();
-        let raw_siblings = self.element.children.iter().filter(|c| c.is_raw()).count();
-        for (index, child) in self.element.children.iter().enumerate() {
-            let mut renderer = child.renderer(self.context());
+        let children = cursor.buffer.bind_children(&self.element.children);
+        let siblings = children.len();
+        let raw_siblings = children.iter().filter(|c| c.element.is_raw()).count();
+        for (index, child) in children.iter().enumerate() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
-            if child.is_raw() {
-                renderer.render(cursor)?;
+            if child.element.is_raw() {
+                renderer.render_component(cursor)?;
             } else {
                 let tr = Tag::tr();
                 let td = Tag::td()
@@ -172,10 +175,11 @@ impl<'root> Renderer<'root, MjHero, ()> {
 
                 tr.render_open(&mut cursor.buffer)?;
                 td.render_open(&mut cursor.buffer)?;
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 td.render_close(&mut cursor.buffer);
                 tr.render_close(&mut cursor.buffer);
             };
+            cursor.buffer.leave_child(mark);
         }
 
         Ok(())
@@ -309,6 +313,7 @@ impl<'root> Render<'root> for Renderer<'root, MjHero, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let outlook_table = self
             .set_style_outlook_table(Tag::table_presentation())
             .add_attribute("align", "center")
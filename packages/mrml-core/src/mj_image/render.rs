@@ -1,8 +1,24 @@
+use std::borrow::Cow;
+
 use super::{MjImage, NAME};
 use crate::helper::size::Pixel;
+use crate::prelude::attributes::{MjImageAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Renderer<'root, MjImage, ()> {
+/// Builds the address of the high density variant of an image by replacing
+/// `{name}` and `{extension}` in the pattern, keeping the query and fragment.
+fn retina_source(pattern: &str, src: &str) -> Option<String> {
+    let end = src.find(['?', '#']).unwrap_or(src.len());
+    let (path, suffix) = src.split_at(end);
+    let filename_start = path.rfind('/').map_or(0, |index| index + 1);
+    let dot = path[filename_start..].rfind('.')? + filename_start;
+    let variant = pattern
+        .replace("{name}", &path[..dot])
+        .replace("{extension}", &path[dot + 1..]);
+    Some(variant + suffix)
+}
+
+impl<'root> Renderer<'root, MjImage, MjImageAttributes> {
     fn is_fluid_on_mobile(&self) -> bool {
         self.attribute("fluid-on-mobile")
             .and_then(|value| value.parse::<bool>().ok())
@@ -37,7 +53,7 @@ impl<'root> Renderer<'root, MjImage, ()> {
             .or_else(|| self.get_box_width())
     }
 
-    fn set_style_img<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    fn set_style_img<'a, 't>(&'a self, tag: Tag<'t>, hidden: bool) -> Tag<'t>
     where
         'root: 'a,
         'a: 't,
@@ -49,12 +65,26 @@ impl<'root> Renderer<'root, MjImage, ()> {
             .maybe_add_style("border-top", self.attribute("top"))
             .maybe_add_style("border-bottom", self.attribute("bottom"))
             .maybe_add_style("border-radius", self.attribute("border-radius"))
-            .add_style("display", "block")
+            .add_style("display", if hidden { "none" } else { "block" })
             .add_style("outline", "none")
             .add_style("text-decoration", "none")
             .maybe_add_style("height", self.attribute("height"))
-            .maybe_add_style("max-height", self.attribute("max-height"))
+            .maybe_add_style(
+                "max-height",
+                if hidden {
+                    Some("0")
+                } else {
+                    self.attribute("max-height")
+                },
+            )
             .add_style("width", "100%");
+        // the dark variant of the image is shown by the dark mode rules
+        let tag = if hidden {
+            tag.add_style("overflow", "hidden")
+                .add_style("mso-hide", "all")
+        } else {
+            tag
+        };
         let tag = if self.is_full_width() {
             tag.add_style("min-width", "100%")
                 .add_style("max-width", "100%")
@@ -84,7 +114,28 @@ impl<'root> Renderer<'root, MjImage, ()> {
             .add_style("border-spacing", "0px")
     }
 
-    fn render_image(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
+    /// The `srcset` given to the image or, when a
+    /// [retina pattern](RenderOptions::retina_pattern) is configured, the one
+    /// listing the high density variant. The `src` stays the fallback for the
+    /// clients ignoring it.
+    fn get_srcset<'a>(&'a self, src: Option<&'a str>, hidden: bool) -> Option<Cow<'a, str>>
+    where
+        'root: 'a,
+    {
+        if !hidden {
+            if let Some(srcset) = self.attribute("srcset") {
+                return Some(Cow::Borrowed(srcset));
+            }
+        }
+        let pattern = self.context.options.retina_pattern.as_deref()?;
+        let src = src?;
+        retina_source(pattern, src).map(|variant| Cow::Owned(format!("{src} 1x, {variant} 2x")))
+    }
+
+    fn get_img_tag<'a>(&'a self, src: Option<&'a str>, hidden: bool) -> Tag<'a>
+    where
+        'root: 'a,
+    {
         let img = Tag::new("img")
             .maybe_add_attribute("alt", self.attribute("alt"))
             .add_attribute(
@@ -93,8 +144,16 @@ impl<'root> Renderer<'root, MjImage, ()> {
                     .map(|size| size.value().to_string())
                     .unwrap_or_else(|| "auto".into()),
             )
-            .maybe_add_attribute("src", self.attribute("src"))
-            .maybe_add_attribute("srcset", self.attribute("srcset"))
+            .maybe_add_attribute("src", src)
+            .maybe_add_attribute("srcset", self.get_srcset(src, hidden))
+            .maybe_add_attribute(
+                "sizes",
+                if hidden {
+                    None
+                } else {
+                    self.attribute("sizes")
+                },
+            )
             .maybe_add_attribute("title", self.attribute("title"))
             .maybe_add_attribute(
                 "width",
@@ -102,8 +161,25 @@ impl<'root> Renderer<'root, MjImage, ()> {
                     .map(|size| size.value().to_string()),
             )
             .maybe_add_attribute("usemap", self.attribute("usemap"));
-        let img = self.set_style_img(img);
-        img.render_closed(buf)
+        self.set_style_img(img, hidden)
+    }
+
+    fn render_image(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
+        let Some(dark_src) = self.attribute("dark-src") else {
+            return self
+                .get_img_tag(self.attribute("src"), false)
+                .render_closed(buf);
+        };
+        self.get_img_tag(self.attribute("src"), false)
+            .add_class("mj-light-img")
+            .render_closed(buf)?;
+        // outlook doesn't support the dark mode rules, it keeps the light image
+        buf.start_mso_negation_conditional_tag();
+        self.get_img_tag(Some(dark_src), true)
+            .add_class("mj-dark-img")
+            .render_closed(buf)?;
+        buf.end_negation_conditional_tag();
+        Ok(())
     }
 
     fn render_link(&self, buf: &mut RenderBuffer) -> std::fmt::Result {
@@ -127,7 +203,7 @@ impl<'root> Renderer<'root, MjImage, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjImage, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjImage, MjImageAttributes> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "align" => Some("center"),
@@ -140,6 +216,10 @@ impl<'root> Render<'root> for Renderer<'root, MjImage, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -157,7 +237,11 @@ impl<'root> Render<'root> for Renderer<'root, MjImage, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         cursor.header.add_style(self.render_style());
+        if self.attribute_exists("dark-src") {
+            cursor.header.add_dark_image();
+        }
         //
         let class = if self.is_fluid_on_mobile() {
             Some("mj-full-width-mobile")
@@ -196,7 +280,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjImage {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjImageAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -214,4 +302,44 @@ mod tests {
     crate::should_render!(height, "mj-image-height");
     crate::should_render!(href, "mj-image-href");
     crate::should_render!(padding, "mj-image-padding");
+
+    #[test]
+    fn should_build_retina_source() {
+        let pattern = "{name}@2x.{extension}";
+        assert_eq!(
+            super::retina_source(pattern, "https://example.com/img/logo.png?v=2").unwrap(),
+            "https://example.com/img/logo@2x.png?v=2"
+        );
+        assert_eq!(
+            super::retina_source(pattern, "logo.min.jpg").unwrap(),
+            "logo.min@2x.jpg"
+        );
+        assert!(super::retina_source(pattern, "https://example.com.br/logo").is_none());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_srcset_and_sizes() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-image src="a.png" />
+<mj-image src="b.png" srcset="b.png 300w, b-large.png 600w" sizes="(max-width: 480px) 100vw, 300px" />
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let opts = RenderOptions {
+            retina_pattern: Some("{name}@2x.{extension}".into()),
+            ..Default::default()
+        };
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"src="a.png" srcset="a.png 1x, a@2x.png 2x""#));
+        assert!(html.contains(
+            r#"srcset="b.png 300w, b-large.png 600w" sizes="(max-width: 480px) 100vw, 300px""#
+        ));
+        // width inferred from the container
+        assert!(html.contains(r#"width="550""#));
+
+        let html = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(!html.contains("a@2x.png"));
+    }
 }
@@ -11,7 +11,7 @@ use std::marker::PhantomData;
 
 use crate::prelude::{Component, StaticTag};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 pub enum MjIncludeBodyChild {
@@ -24,6 +24,7 @@ pub enum MjIncludeBodyChild {
     MjGroup(crate::mj_group::MjGroup),
     MjHero(crate::mj_hero::MjHero),
     MjImage(crate::mj_image::MjImage),
+    MjList(crate::mj_list::MjList),
     MjNavbar(crate::mj_navbar::MjNavbar),
     MjRaw(crate::mj_raw::MjRaw),
     MjSection(crate::mj_section::MjSection),
@@ -31,12 +32,14 @@ pub enum MjIncludeBodyChild {
     MjSpacer(crate::mj_spacer::MjSpacer),
     MjTable(crate::mj_table::MjTable),
     MjText(crate::mj_text::MjText),
+    MjVideo(crate::mj_video::MjVideo),
     MjWrapper(crate::mj_wrapper::MjWrapper),
     Node(crate::node::Node<crate::mj_body::MjBodyChild>),
+    Placeholder(crate::placeholder::Placeholder),
     Text(crate::text::Text),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
 pub enum MjIncludeBodyKind {
@@ -66,7 +69,7 @@ impl Default for MjIncludeBodyKind {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 pub struct MjIncludeBodyAttributes {
     pub path: String,
@@ -107,7 +110,7 @@ impl StaticTag for MjIncludeBodyTag {
 pub type MjIncludeBodyInner =
     Component<PhantomData<MjIncludeBodyTag>, MjIncludeBodyAttributes, Vec<MjIncludeBodyChild>>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(transparent))]
 pub struct MjIncludeBody(pub MjIncludeBodyInner);
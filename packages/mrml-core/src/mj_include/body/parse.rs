@@ -11,6 +11,7 @@ use crate::mj_divider::NAME as MJ_DIVIDER;
 use crate::mj_group::NAME as MJ_GROUP;
 use crate::mj_hero::NAME as MJ_HERO;
 use crate::mj_image::NAME as MJ_IMAGE;
+use crate::mj_list::NAME as MJ_LIST;
 use crate::mj_navbar::NAME as MJ_NAVBAR;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_section::NAME as MJ_SECTION;
@@ -18,12 +19,14 @@ use crate::mj_social::NAME as MJ_SOCIAL;
 use crate::mj_spacer::NAME as MJ_SPACER;
 use crate::mj_table::NAME as MJ_TABLE;
 use crate::mj_text::NAME as MJ_TEXT;
+use crate::mj_video::NAME as MJ_VIDEO;
 use crate::mj_wrapper::{MjWrapper, NAME as MJ_WRAPPER};
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    Error, MaskedSource, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
 use crate::text::Text;
 
@@ -42,6 +45,7 @@ impl<'opts> ParseElement<MjIncludeBodyChild> for MrmlParser<'opts> {
             MJ_GROUP => Ok(MjIncludeBodyChild::MjGroup(self.parse(cursor, tag)?)),
             MJ_HERO => Ok(MjIncludeBodyChild::MjHero(self.parse(cursor, tag)?)),
             MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(self.parse(cursor, tag)?)),
+            MJ_LIST => Ok(MjIncludeBodyChild::MjList(self.parse(cursor, tag)?)),
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(self.parse(cursor, tag)?)),
             MJ_RAW => Ok(MjIncludeBodyChild::MjRaw(self.parse(cursor, tag)?)),
             MJ_SECTION => Ok(MjIncludeBodyChild::MjSection(self.parse(cursor, tag)?)),
@@ -49,6 +53,7 @@ impl<'opts> ParseElement<MjIncludeBodyChild> for MrmlParser<'opts> {
             MJ_SPACER => Ok(MjIncludeBodyChild::MjSpacer(self.parse(cursor, tag)?)),
             MJ_TABLE => Ok(MjIncludeBodyChild::MjTable(self.parse(cursor, tag)?)),
             MJ_TEXT => Ok(MjIncludeBodyChild::MjText(self.parse(cursor, tag)?)),
+            MJ_VIDEO => Ok(MjIncludeBodyChild::MjVideo(self.parse(cursor, tag)?)),
             MJ_WRAPPER => Ok(MjIncludeBodyChild::MjWrapper(self.parse(cursor, tag)?)),
             _ => Err(Error::UnexpectedElement {
                 origin: cursor.origin(),
@@ -92,6 +97,9 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_IMAGE => Ok(MjIncludeBodyChild::MjImage(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_LIST => Ok(MjIncludeBodyChild::MjList(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_NAVBAR => Ok(MjIncludeBodyChild::MjNavbar(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -113,6 +121,9 @@ impl AsyncParseElement<MjIncludeBodyChild> for AsyncMrmlParser {
             MJ_TEXT => Ok(MjIncludeBodyChild::MjText(
                 self.async_parse(cursor, tag).await?,
             )),
+            MJ_VIDEO => Ok(MjIncludeBodyChild::MjVideo(
+                self.async_parse(cursor, tag).await?,
+            )),
             MJ_WRAPPER => Ok(MjIncludeBodyChild::MjWrapper(
                 self.async_parse(cursor, tag).await?,
             )),
@@ -206,6 +217,11 @@ impl<'opts> ParseChildren<Vec<MjIncludeBodyChild>> for MrmlParser<'opts> {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjIncludeBodyChild::Placeholder(Placeholder::from(
+                        inner.text,
+                    )));
+                }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.parse(cursor, inner.local)?);
                 }
@@ -246,6 +262,11 @@ impl AsyncParseChildren<Vec<MjIncludeBodyChild>> for AsyncMrmlParser {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjIncludeBodyChild::Placeholder(Placeholder::from(
+                        inner.text,
+                    )));
+                }
                 MrmlToken::ElementStart(inner) => {
                     result.push(self.async_parse(cursor, inner.local).await?);
                 }
@@ -291,7 +312,8 @@ impl<'opts> ParseElement<MjIncludeBody> for MrmlParser<'opts> {
                 })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children: Vec<MjBodyChild> = self.parse_children(&mut sub)?;
                     cursor.with_warnings(sub.warnings());
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
@@ -300,7 +322,8 @@ impl<'opts> ParseElement<MjIncludeBody> for MrmlParser<'opts> {
                     ))]
                 }
                 MjIncludeBodyKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children = self.parse_children(&mut sub)?;
                     cursor.with_warnings(sub.warnings());
                     children
@@ -342,7 +365,8 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                 })?;
             match attributes.kind {
                 MjIncludeBodyKind::Html => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children: Vec<MjBodyChild> = self.async_parse_children(&mut sub).await?;
                     vec![MjIncludeBodyChild::MjWrapper(MjWrapper::new(
                         Default::default(),
@@ -350,7 +374,8 @@ impl crate::prelude::parser::AsyncParseElement<MjIncludeBody> for AsyncMrmlParse
                     ))]
                 }
                 MjIncludeBodyKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children = self.async_parse_children(&mut sub).await?;
                     cursor.with_warnings(sub.warnings());
                     children
@@ -411,6 +436,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -428,6 +454,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-button>Hello</mj-button>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -444,6 +471,7 @@ mod tests {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -460,6 +488,7 @@ mod tests {
         let resolver = MemoryIncludeLoader::from(vec![("partial.html", "<h1>Hello World!</h1>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -527,6 +556,7 @@ mod tests {
         )]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="partial.html" type="html" />"#;
         let mut cursor = MrmlCursor::new(raw);
@@ -15,6 +15,7 @@ impl MjIncludeBodyChild {
             Self::MjGroup(elt) => elt,
             Self::MjHero(elt) => elt,
             Self::MjImage(elt) => elt,
+            Self::MjList(elt) => elt,
             Self::MjNavbar(elt) => elt,
             Self::MjRaw(elt) => elt,
             Self::MjSection(elt) => elt,
@@ -22,8 +23,10 @@ impl MjIncludeBodyChild {
             Self::MjSpacer(elt) => elt,
             Self::MjTable(elt) => elt,
             Self::MjText(elt) => elt,
+            Self::MjVideo(elt) => elt,
             Self::MjWrapper(elt) => elt,
             Self::Node(elt) => elt,
+            Self::Placeholder(elt) => elt,
             Self::Text(elt) => elt,
         }
     }
@@ -38,7 +41,10 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjIncludeBodyChild
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        self.as_renderable().renderer(context)
+        match self {
+            Self::Node(elt) => node_renderer(context, elt),
+            _ => self.as_renderable().renderer(context),
+        }
     }
 }
 
@@ -58,9 +64,10 @@ impl<'root> Render<'root> for Renderer<'root, MjIncludeBody, ()> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         for (index, child) in self.element.0.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             renderer.set_siblings(self.element.0.children.len());
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         Ok(())
     }
@@ -94,7 +101,7 @@ mod tests {
         let mj_head = Some(MjHead::default());
         let expected: String = {
             let header = Header::new(mj_head.as_ref(), None);
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
             let elt = MjText::default();
             let renderer = elt.renderer(&context);
@@ -103,7 +110,7 @@ mod tests {
         };
         let result: String = {
             let header = Header::new(mj_head.as_ref(), None);
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
             let elt = MjIncludeBody::new(
                 MjIncludeBodyAttributes::new("memory:foo.mjml"),
@@ -123,7 +130,7 @@ mod tests {
 
         let expected: String = {
             let header = Header::new(mj_head.as_ref(), None);
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
 
             let mut node = Node::from("span");
@@ -138,7 +145,7 @@ mod tests {
         };
         let result: String = {
             let header = Header::new(mj_head.as_ref(), None);
-            let context = RenderContext::new(&opts, header);
+            let context = RenderContext::new(&opts, &header);
             let mut cursor = RenderCursor::default();
 
             let mut node = Node::from("span");
@@ -11,7 +11,7 @@ use std::marker::PhantomData;
 
 use crate::prelude::{Component, StaticTag};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "render", derive(enum_as_inner::EnumAsInner))]
@@ -24,10 +24,11 @@ pub enum MjIncludeHeadChild {
     MjRaw(crate::mj_raw::MjRaw),
     MjStyle(crate::mj_style::MjStyle),
     MjTitle(crate::mj_title::MjTitle),
+    Placeholder(crate::placeholder::Placeholder),
     Text(crate::text::Text),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
 pub enum MjIncludeHeadKind {
@@ -59,7 +60,7 @@ impl Default for MjIncludeHeadKind {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 pub struct MjIncludeHeadAttributes {
     pub path: String,
@@ -100,7 +101,7 @@ impl StaticTag for MjIncludeHeadTag {
 pub type MjIncludeHeadInner =
     Component<PhantomData<MjIncludeHeadTag>, MjIncludeHeadAttributes, Vec<MjIncludeHeadChild>>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(transparent))]
 pub struct MjIncludeHead(pub MjIncludeHeadInner);
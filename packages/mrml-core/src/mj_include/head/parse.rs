@@ -9,11 +9,12 @@ use crate::mj_preview::NAME as MJ_PREVIEW;
 use crate::mj_raw::NAME as MJ_RAW;
 use crate::mj_style::NAME as MJ_STYLE;
 use crate::mj_title::NAME as MJ_TITLE;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    WarningKind,
+    Error, MaskedSource, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, WarningKind,
 };
 use crate::text::Text;
 
@@ -153,6 +154,11 @@ impl<'opts> ParseChildren<Vec<MjIncludeHeadChild>> for MrmlParser<'opts> {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjIncludeHeadChild::Placeholder(Placeholder::from(
+                        inner.text,
+                    )));
+                }
                 MrmlToken::Text(inner) => {
                     result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
                 }
@@ -191,6 +197,11 @@ impl AsyncParseChildren<Vec<MjIncludeHeadChild>> for AsyncMrmlParser {
                         inner.text.as_str(),
                     )));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjIncludeHeadChild::Placeholder(Placeholder::from(
+                        inner.text,
+                    )));
+                }
                 MrmlToken::Text(inner) => {
                     result.push(MjIncludeHeadChild::Text(Text::from(inner.text.as_str())));
                 }
@@ -242,7 +253,8 @@ impl<'opts> ParseElement<MjIncludeHead> for MrmlParser<'opts> {
                 }
                 MjIncludeHeadKind::Css { inline: true } => unimplemented!(),
                 MjIncludeHeadKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children = self.parse_children(&mut sub)?;
                     cursor.with_warnings(sub.warnings());
                     children
@@ -290,7 +302,8 @@ impl AsyncParseElement<MjIncludeHead> for AsyncMrmlParser {
                 }
                 MjIncludeHeadKind::Css { inline: true } => unimplemented!(),
                 MjIncludeHeadKind::Mjml => {
-                    let mut sub = cursor.new_child(&attributes.path, child.as_str());
+                    let source = MaskedSource::new(child.as_str(), &self.options.placeholders);
+                    let mut sub = cursor.new_child(&attributes.path, &source);
                     let children = self.async_parse_children(&mut sub).await?;
                     cursor.with_warnings(sub.warnings());
                     children
@@ -397,6 +410,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = MrmlParser::new(&opts);
@@ -415,6 +429,7 @@ mod tests {
             MemoryIncludeLoader::from(vec![("basic.mjml", "<mj-title>Hello</mj-title>")]);
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let raw = r#"<mj-include path="basic.mjml" />"#;
         let parser = AsyncMrmlParser::new(opts.into());
@@ -431,6 +446,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = ParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = MrmlParser::new(&opts);
         let mut cursor = MrmlCursor::new(raw);
@@ -452,6 +468,7 @@ mod tests {
         let raw = r#"<mj-include path="partial.css" type="css" />"#;
         let opts = AsyncParserOptions {
             include_loader: Box::new(resolver),
+            ..Default::default()
         };
         let parser = AsyncMrmlParser::new(opts.into());
         let mut cursor = MrmlCursor::new(raw);
@@ -34,6 +34,16 @@ impl super::MjIncludeHead {
             })
     }
 
+    pub(crate) fn mj_attributes_class_element_iter(
+        &self,
+    ) -> impl Iterator<Item = (&str, &str, &str, &str)> {
+        self.0
+            .children
+            .iter()
+            .filter_map(|child| child.as_mj_attributes())
+            .flat_map(|child| child.mj_attributes_class_element_iter())
+    }
+
     pub(crate) fn mj_attributes_element_iter(&self) -> impl Iterator<Item = (&str, &str, &str)> {
         self.0
             .children
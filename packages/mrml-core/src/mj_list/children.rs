@@ -0,0 +1,13 @@
+use crate::comment::Comment;
+use crate::mj_list_item::MjListItem;
+use crate::placeholder::Placeholder;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "json", serde(untagged))]
+#[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
+pub enum MjListChild {
+    Comment(Comment),
+    MjListItem(MjListItem),
+    Placeholder(Placeholder),
+}
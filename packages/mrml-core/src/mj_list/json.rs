@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_list::MjList;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjList::default();
+        elt.attributes.insert("list-style".into(), "decimal".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-list","attributes":{"list-style":"decimal"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-list","attributes":{"spacing":"8px"},"children":[{"type":"mj-list-item"},{"type":"comment","children":"World"}]}"#;
+        let res: MjList = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 2);
+    }
+}
@@ -0,0 +1,28 @@
+mod children;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+pub use children::MjListChild;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-list";
+
+pub struct MjListTag;
+
+impl StaticTag for MjListTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjList = Component<PhantomData<MjListTag>, Map<String, String>, Vec<MjListChild>>;
@@ -0,0 +1,127 @@
+use super::MjListChild;
+use crate::comment::Comment;
+use crate::mj_list_item::NAME as MJ_LIST_ITEM;
+use crate::placeholder::Placeholder;
+#[cfg(feature = "async")]
+use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
+use crate::prelude::parser::{
+    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseElement,
+};
+
+impl<'opts> ParseChildren<Vec<MjListChild>> for MrmlParser<'opts> {
+    fn parse_children(&self, cursor: &mut MrmlCursor<'_>) -> Result<Vec<MjListChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjListChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjListChild::Placeholder(Placeholder::from(inner.text)));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_LIST_ITEM {
+                        result.push(MjListChild::MjListItem(self.parse(cursor, inner.local)?));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncParseChildren<Vec<MjListChild>> for AsyncMrmlParser {
+    async fn async_parse_children<'a>(
+        &self,
+        cursor: &mut MrmlCursor<'a>,
+    ) -> Result<Vec<MjListChild>, Error> {
+        let mut result = Vec::new();
+
+        loop {
+            match cursor.assert_next()? {
+                MrmlToken::Comment(inner) => {
+                    result.push(MjListChild::Comment(Comment::from(inner.text.as_str())));
+                }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjListChild::Placeholder(Placeholder::from(inner.text)));
+                }
+                MrmlToken::ElementStart(inner) => {
+                    if inner.local.as_str() == MJ_LIST_ITEM {
+                        result.push(MjListChild::MjListItem(
+                            self.async_parse(cursor, inner.local).await?,
+                        ));
+                    } else {
+                        return Err(Error::UnexpectedElement {
+                            origin: cursor.origin(),
+                            position: inner.span.into(),
+                        });
+                    }
+                }
+                MrmlToken::ElementClose(inner) => {
+                    cursor.rewind(MrmlToken::ElementClose(inner));
+                    return Ok(result);
+                }
+                other => {
+                    return Err(Error::UnexpectedToken {
+                        origin: cursor.origin(),
+                        position: other.span(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_list::MjList;
+
+    macro_rules! assert_success {
+        ($title:ident, $template:expr) => {
+            crate::should_sync_parse!($title, MjList, $template);
+        };
+    }
+
+    macro_rules! assert_fail {
+        ($title:ident, $template:expr, $error:expr) => {
+            crate::should_not_sync_parse!($title, MjList, $template, $error);
+        };
+    }
+
+    assert_success!(should_handle_empty_children, "<mj-list />");
+
+    assert_success!(
+        should_handle_comments,
+        "<mj-list><!-- comment --></mj-list>"
+    );
+
+    assert_fail!(
+        should_error_with_text,
+        "<mj-list>Hello</mj-list>",
+        "UnexpectedToken { origin: Root, position: Span { start: 9, end: 14 } }"
+    );
+
+    assert_fail!(
+        should_error_with_other_element,
+        "<mj-list><span /></mj-list>",
+        "UnexpectedElement { origin: Root, position: Span { start: 9, end: 14 } }"
+    );
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let item = crate::mj_list::MjList::default();
+        assert_eq!("<mj-list />", item.print_dense().unwrap());
+    }
+}
@@ -0,0 +1,123 @@
+use super::{MjList, MjListChild, NAME};
+use crate::helper::size::Pixel;
+use crate::prelude::render::*;
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjListChild {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        match self {
+            Self::MjListItem(elt) => elt.renderer(context),
+            Self::Comment(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
+        }
+    }
+}
+
+const EXTRA_CHILD_KEY: [&str; 17] = [
+    "bullet",
+    "bullet-color",
+    "bullet-image",
+    "bullet-image-width",
+    "color",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-weight",
+    "indent",
+    "letter-spacing",
+    "line-height",
+    "list-style",
+    "spacing",
+    "start",
+    "text-decoration",
+    "text-transform",
+];
+
+impl<'root> Renderer<'root, MjList, ()> {
+    // the defaults of the list are the ones of the items, forwarding them would
+    // override what the items get from the head
+    fn build_child_attributes(&self) -> Vec<(&str, &str)> {
+        EXTRA_CHILD_KEY
+            .iter()
+            .filter_map(|key| self.explicit_attribute(key).map(|value| (*key, value)))
+            .collect::<Vec<_>>()
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjList, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("left"),
+            "color" => Some("#000000"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("13px"),
+            "indent" => Some("24px"),
+            "line-height" => Some("1.5"),
+            "list-style" => Some("disc"),
+            "padding" => Some("10px 25px"),
+            "spacing" => Some("4px"),
+            "start" => Some("1"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+
+        let table = Tag::table_presentation()
+            .add_attribute("width", "100%")
+            .add_style("border-collapse", "collapse")
+            .add_style("width", "100%");
+        let tbody = Tag::tbody();
+        let child_attributes = self.build_child_attributes();
+
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        // the comments don't count when numbering the items
+        let mut index = 0;
+        for child in self.element.children.iter() {
+            let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
+            renderer.set_container_width(self.container_width);
+            if matches!(child, MjListChild::MjListItem(_)) {
+                renderer.set_index(index);
+                index += 1;
+            }
+            child_attributes.iter().for_each(|(key, value)| {
+                renderer.add_extra_attribute(key, value);
+            });
+            renderer.render_component(cursor)?;
+        }
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjList {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
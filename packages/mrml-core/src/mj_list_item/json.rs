@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_list_item::MjListItem;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjListItem::default();
+        elt.attributes.insert("bullet".into(), "-".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-list-item","attributes":{"bullet":"-"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-list-item","attributes":{"color":"red"},"children":["Hello",{"type":"comment","children":"World"}]}"#;
+        let res: MjListItem = serde_json::from_str(json).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.children.len(), 2);
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+use std::marker::PhantomData;
+
+use crate::mj_raw::MjRawChild;
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+pub const NAME: &str = "mj-list-item";
+
+pub struct MjListItemTag;
+
+impl StaticTag for MjListItemTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+pub type MjListItem = Component<PhantomData<MjListItemTag>, Map<String, String>, Vec<MjRawChild>>;
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_list_item::MjListItem;
+
+    macro_rules! assert_success {
+        ($title:ident, $template:expr) => {
+            crate::should_sync_parse!($title, MjListItem, $template);
+        };
+    }
+
+    assert_success!(should_handle_empty_children, "<mj-list-item />");
+
+    assert_success!(
+        should_handle_comments,
+        "<mj-list-item><!-- comment --></mj-list-item>"
+    );
+
+    assert_success!(should_work_with_text, "<mj-list-item>Hello</mj-list-item>");
+
+    assert_success!(
+        should_work_with_other_element,
+        "<mj-list-item><span /></mj-list-item>"
+    );
+}
@@ -0,0 +1,11 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_list_item::MjListItem::default();
+        item.attributes.insert("bullet".to_string(), "-".into());
+        assert_eq!("<mj-list-item bullet=\"-\" />", item.print_dense().unwrap());
+    }
+}
@@ -0,0 +1,334 @@
+use super::{MjListItem, NAME};
+use crate::helper::size::Pixel;
+use crate::prelude::hash::Map;
+use crate::prelude::render::*;
+
+struct MjListItemExtra<'a> {
+    attributes: Map<&'a str, &'a str>,
+}
+
+impl<'a> Default for MjListItemExtra<'a> {
+    fn default() -> Self {
+        Self {
+            attributes: Map::new(),
+        }
+    }
+}
+
+/// Numbering with letters, `a` to `z` then `aa`, `ab`...
+fn to_alpha(mut value: usize) -> String {
+    let mut result = Vec::new();
+    while value > 0 {
+        value -= 1;
+        result.push((b'a' + (value % 26) as u8) as char);
+        value /= 26;
+    }
+    result.iter().rev().collect()
+}
+
+fn to_roman(mut value: usize) -> String {
+    const SYMBOLS: [(usize, &str); 13] = [
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+    let mut result = String::new();
+    for (step, symbol) in SYMBOLS {
+        while value >= step {
+            result.push_str(symbol);
+            value -= step;
+        }
+    }
+    result
+}
+
+/// Attributes choosing the marker of an item.
+const MARKER_ATTRIBUTES: [&str; 3] = ["bullet-image", "bullet", "list-style"];
+
+/// Builds the marker of the item at the given number, `None` for the unknown
+/// list styles.
+fn list_marker(style: &str, number: usize) -> Option<String> {
+    match style {
+        "none" => Some(String::new()),
+        "disc" => Some("&#8226;".to_string()),
+        "circle" => Some("&#9702;".to_string()),
+        "square" => Some("&#9642;".to_string()),
+        "decimal" => Some(format!("{number}.")),
+        "lower-alpha" => Some(format!("{}.", to_alpha(number))),
+        "upper-alpha" => Some(format!("{}.", to_alpha(number).to_uppercase())),
+        "lower-roman" => Some(format!("{}.", to_roman(number))),
+        "upper-roman" => Some(format!("{}.", to_roman(number).to_uppercase())),
+        _ => None,
+    }
+}
+
+impl<'root> Renderer<'root, MjListItem, MjListItemExtra<'root>> {
+    fn set_style_text<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
+    where
+        'root: 'a,
+        'a: 't,
+    {
+        tag.add_style("vertical-align", "top")
+            .maybe_add_style("padding-top", self.get_spacing())
+            .maybe_add_style("font-family", self.attribute("font-family"))
+            .maybe_add_style("font-size", self.attribute("font-size"))
+            .maybe_add_style("font-style", self.attribute("font-style"))
+            .maybe_add_style("font-weight", self.attribute("font-weight"))
+            .maybe_add_style("letter-spacing", self.attribute("letter-spacing"))
+            .maybe_add_style("line-height", self.attribute("line-height"))
+            .maybe_add_style("text-decoration", self.attribute("text-decoration"))
+            .maybe_add_style("text-transform", self.attribute("text-transform"))
+    }
+
+    /// The spacing separates the item from the previous one.
+    fn get_spacing(&self) -> Option<&str> {
+        if self.index > 0 {
+            self.attribute("spacing")
+        } else {
+            None
+        }
+    }
+
+    fn get_number(&self) -> usize {
+        let start = self
+            .attribute("start")
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(1);
+        start + self.index
+    }
+
+    /// The marker set on the item wins over the one forwarded by the list.
+    fn marker_attribute<'a>(&'a self, key: &str) -> Option<&'a str>
+    where
+        'root: 'a,
+    {
+        if MARKER_ATTRIBUTES
+            .iter()
+            .any(|name| self.raw_attribute(name).is_some())
+        {
+            self.raw_attribute(key)
+        } else {
+            self.attribute(key)
+        }
+    }
+
+    /// The bullet image comes first, then the bullet character and finally
+    /// the marker of the list style.
+    fn render_marker(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(src) = self.marker_attribute("bullet-image") {
+            let width = self.attribute_as_pixel("bullet-image-width");
+            Tag::new("img")
+                .add_attribute("src", src)
+                .add_attribute("alt", "")
+                .maybe_add_attribute("width", width.as_ref().map(|w| w.value().to_string()))
+                .add_style("display", "block")
+                .add_style("border", "0")
+                .add_style("outline", "none")
+                .maybe_add_style("width", width.as_ref().map(|w| w.to_string()))
+                .render_closed(&mut cursor.buffer)?;
+        } else if let Some(bullet) = self.marker_attribute("bullet") {
+            cursor.buffer.push_str(bullet);
+        } else {
+            let style = self.marker_attribute("list-style").unwrap_or("disc");
+            let marker = list_marker(style, self.get_number()).unwrap_or_else(|| {
+                cursor.add_warning(WarningKind::UnknownListStyle, self.tag(), style);
+                list_marker("disc", 0).unwrap_or_default()
+            });
+            cursor.buffer.push_str(&marker);
+        }
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjListItem, MjListItemExtra<'root>> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "bullet-image-width" => Some("8px"),
+            "color" => Some("#000000"),
+            "font-family" => Some("Ubuntu, Helvetica, Arial, sans-serif"),
+            "font-size" => Some("13px"),
+            "indent" => Some("24px"),
+            "line-height" => Some("1.5"),
+            "list-style" => Some("disc"),
+            "spacing" => Some("4px"),
+            _ => None,
+        }
+    }
+
+    fn add_extra_attribute(&mut self, key: &'root str, value: &'root str) {
+        self.extra.attributes.insert(key, value);
+    }
+
+    fn raw_extra_attribute(&self, key: &str) -> Option<&'root str> {
+        self.extra.attributes.get(key).copied()
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+        let font_families = self.attribute("font-family");
+        cursor.header.maybe_add_font_families(font_families);
+
+        let indent = self.attribute_as_pixel("indent");
+        let tr = Tag::tr().maybe_add_class(self.attribute("css-class"));
+        let bullet = self
+            .set_style_text(Tag::td())
+            .maybe_add_attribute("width", indent.as_ref().map(|w| w.value().to_string()))
+            .add_attribute("valign", "top")
+            .maybe_add_style("width", indent.as_ref().map(|w| w.to_string()))
+            .maybe_add_style(
+                "color",
+                self.attribute("bullet-color")
+                    .or_else(|| self.attribute("color")),
+            );
+        let content = self
+            .set_style_text(Tag::td())
+            .add_attribute("valign", "top")
+            .maybe_add_style("color", self.attribute("color"))
+            .maybe_add_style("text-align", self.attribute("align"));
+
+        tr.render_open(&mut cursor.buffer)?;
+        bullet.render_open(&mut cursor.buffer)?;
+        self.render_marker(cursor)?;
+        bullet.render_close(&mut cursor.buffer);
+        content.render_open(&mut cursor.buffer)?;
+        for child in self.element.children.iter() {
+            child.renderer(self.context()).render_component(cursor)?;
+        }
+        content.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjListItem {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, MjListItemExtra::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_marker, to_alpha, to_roman};
+
+    #[test]
+    fn should_build_markers() {
+        assert_eq!(to_alpha(1), "a");
+        assert_eq!(to_alpha(26), "z");
+        assert_eq!(to_alpha(28), "ab");
+        assert_eq!(to_roman(4), "iv");
+        assert_eq!(to_roman(1994), "mcmxciv");
+        assert_eq!(list_marker("upper-roman", 9).unwrap(), "IX.");
+        assert_eq!(list_marker("decimal", 3).unwrap(), "3.");
+        assert!(list_marker("unknown", 1).is_none());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_numbered_items() {
+        let template = r##"<mjml><mj-body><mj-section><mj-column>
+<mj-list list-style="lower-alpha" start="2" color="#333333" spacing="6px">
+<mj-list-item>First</mj-list-item>
+<!-- skipped -->
+<mj-list-item color="#ff0000">Second</mj-list-item>
+</mj-list>
+</mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r##"<td width="24" valign="top" style="vertical-align:top;font-family:Ubuntu, Helvetica, Arial, sans-serif;font-size:13px;line-height:1.5;width:24px;color:#333333;">b.</td>"##));
+        assert!(html.contains(">c.</td>"));
+        assert!(html.contains("padding-top:6px;"));
+        assert!(html.contains("color:#ff0000;\">Second</td>"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_prefer_the_mj_class_of_the_item_to_the_list_defaults() {
+        let template = r##"<mjml>
+<mj-head><mj-attributes><mj-class name="red" color="#ff0000" spacing="8px" /></mj-attributes></mj-head>
+<mj-body><mj-section><mj-column>
+<mj-list>
+<mj-list-item>First</mj-list-item>
+<mj-list-item mj-class="red">Second</mj-list-item>
+<mj-list-item>Third</mj-list-item>
+</mj-list>
+</mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains("color:#ff0000;\">Second</td>"));
+        assert!(html.contains("padding-top:8px;"));
+        assert!(html.contains("color:#000000;\">First</td>"));
+        assert!(html.contains("color:#000000;\">Third</td>"));
+        assert!(html.contains("padding-top:4px;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_bullet_image_and_character() {
+        let template = r##"<mjml><mj-body><mj-section><mj-column>
+<mj-list bullet="&#10003;" font-size="16px">
+<mj-list-item>Checked</mj-list-item>
+<mj-list-item bullet-image="https://example.com/dot.png">Image</mj-list-item>
+</mj-list>
+</mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(">&#10003;</td>"));
+        assert!(html.contains(r##"<img src="https://example.com/dot.png" alt="" width="8""##));
+        assert!(html.contains("font-size:16px;"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_prefer_the_marker_of_the_item_to_the_list_one() {
+        let template = r##"<mjml><mj-body><mj-section><mj-column>
+<mj-list bullet-image="https://example.com/dot.png">
+<mj-list-item bullet="-">Dash</mj-list-item>
+<mj-list-item list-style="decimal">Number</mj-list-item>
+<mj-list-item>Image</mj-list-item>
+</mj-list>
+</mj-column></mj-section></mj-body></mjml>"##;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(">-</td>"), "{}", html);
+        assert!(html.contains(">2.</td>"), "{}", html);
+        assert_eq!(html.matches("https://example.com/dot.png").count(), 1);
+    }
+}
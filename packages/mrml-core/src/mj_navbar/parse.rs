@@ -1,6 +1,7 @@
 use super::MjNavbarChild;
 use crate::comment::Comment;
 use crate::mj_navbar_link::NAME as MJ_NAVBAR_LINK;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
@@ -16,6 +17,9 @@ impl<'opts> ParseChildren<Vec<MjNavbarChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     result.push(MjNavbarChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjNavbarChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_NAVBAR_LINK {
                         result.push(MjNavbarChild::MjNavbarLink(
@@ -58,6 +62,9 @@ impl AsyncParseChildren<Vec<MjNavbarChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     result.push(MjNavbarChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjNavbarChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_NAVBAR_LINK {
                         result.push(MjNavbarChild::MjNavbarLink(
@@ -10,6 +10,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbarChild {
         match self {
             Self::MjNavbarLink(elt) => elt.renderer(context),
             Self::Comment(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
         }
     }
 }
@@ -187,6 +188,7 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         cursor.header.add_style(self.render_style());
 
         let div = Tag::div().add_class("mj-inline-links");
@@ -206,8 +208,9 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbar, MjNavbarExtra> {
 
         for child in self.element.children.iter() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.maybe_add_extra_attribute("navbar-base-url", base_url);
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
 
         cursor.buffer.start_conditional_tag();
@@ -225,7 +228,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjNavbar {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        let id = context.generator.next_id();
+        let id = context.generator.next_id_for(self);
         Box::new(Renderer::new(context, self, MjNavbarExtra { id }))
     }
 }
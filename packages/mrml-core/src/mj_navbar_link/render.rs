@@ -71,7 +71,7 @@ impl<'root> Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'root>> {
         link.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
             let renderer = child.renderer(self.context());
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         link.render_close(&mut cursor.buffer);
 
@@ -120,6 +120,7 @@ impl<'root> Render<'root> for Renderer<'root, MjNavbarLink, MjNavbarLinkExtra<'r
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_families = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_families);
 
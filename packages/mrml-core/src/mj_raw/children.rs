@@ -1,13 +1,15 @@
 use crate::comment::Comment;
 use crate::node::Node;
+use crate::placeholder::Placeholder;
 use crate::text::Text;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "json", serde(untagged))]
 #[cfg_attr(feature = "print", enum_dispatch::enum_dispatch)]
 pub enum MjRawChild {
     Comment(Comment),
     Node(Node<MjRawChild>),
+    Placeholder(Placeholder),
     Text(Text),
 }
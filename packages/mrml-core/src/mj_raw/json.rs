@@ -1,6 +1,22 @@
+use super::MjRawAttributes;
+use crate::prelude::json::JsonAttributes;
+
+impl JsonAttributes for MjRawAttributes {
+    fn has_attributes(&self) -> bool {
+        self.position.is_some()
+    }
+
+    fn try_from_serde<Err: serde::de::Error>(this: Option<Self>) -> Result<Self, Err>
+    where
+        Self: Sized,
+    {
+        Ok(this.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::mj_raw::{MjRaw, MjRawChild};
+    use crate::mj_raw::{MjRaw, MjRawAttributes, MjRawChild};
     use crate::text::Text;
 
     #[test]
@@ -22,4 +38,21 @@ mod tests {
         let next = serde_json::to_string(&res).unwrap();
         assert_eq!(next, json);
     }
+
+    #[test]
+    fn serialize_file_start() {
+        let elt = MjRaw::new(
+            MjRawAttributes {
+                position: Some("file-start".into()),
+            },
+            vec![MjRawChild::Text(Text::from("{% extends 'base.html' %}"))],
+        );
+        let json = serde_json::to_string(&elt).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"mj-raw","attributes":{"position":"file-start"},"children":["{% extends 'base.html' %}"]}"#
+        );
+        let res: MjRaw = serde_json::from_str(&json).unwrap();
+        assert!(res.is_file_start());
+    }
 }
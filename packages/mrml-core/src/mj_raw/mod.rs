@@ -16,6 +16,16 @@ use crate::prelude::{Component, StaticTag};
 
 pub const NAME: &str = "mj-raw";
 
+/// Value of the `position` attribute to render the content before the doctype.
+pub const POSITION_FILE_START: &str = "file-start";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct MjRawAttributes {
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub position: Option<String>,
+}
+
 pub struct MjRawTag;
 
 impl StaticTag for MjRawTag {
@@ -24,4 +34,13 @@ impl StaticTag for MjRawTag {
     }
 }
 
-pub type MjRaw = Component<PhantomData<MjRawTag>, (), Vec<MjRawChild>>;
+pub type MjRaw = Component<PhantomData<MjRawTag>, MjRawAttributes, Vec<MjRawChild>>;
+
+impl MjRaw {
+    pub fn is_file_start(&self) -> bool {
+        matches!(
+            self.attributes.position.as_deref(),
+            Some(POSITION_FILE_START)
+        )
+    }
+}
@@ -1,16 +1,52 @@
 use xmlparser::StrSpan;
 
-use super::MjRawChild;
+use super::{MjRawAttributes, MjRawChild};
 use crate::comment::Comment;
 use crate::node::Node;
+use crate::placeholder::Placeholder;
 use crate::prelude::is_void_element;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
     Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
+    WarningKind,
 };
 use crate::text::Text;
 
+#[inline]
+fn parse_attributes(cursor: &mut MrmlCursor<'_>) -> Result<MjRawAttributes, Error> {
+    let mut result = MjRawAttributes::default();
+    while let Some(attr) = cursor.next_attribute()? {
+        if attr.local.as_str() == "position" {
+            result.position = Some(attr.value.to_string());
+        } else {
+            cursor.add_warning(WarningKind::UnexpectedAttribute, attr.span);
+        }
+    }
+    Ok(result)
+}
+
+impl<'opts> ParseAttributes<MjRawAttributes> for MrmlParser<'opts> {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ParseAttributes<MjRawAttributes> for AsyncMrmlParser {
+    fn parse_attributes(
+        &self,
+        cursor: &mut MrmlCursor<'_>,
+        _tag: &StrSpan<'_>,
+    ) -> Result<MjRawAttributes, Error> {
+        parse_attributes(cursor)
+    }
+}
+
 impl<'opts> ParseElement<Node<MjRawChild>> for MrmlParser<'opts> {
     fn parse<'a>(
         &self,
@@ -77,6 +113,9 @@ impl<'opts> ParseChildren<Vec<MjRawChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     children.push(MjRawChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    children.push(MjRawChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(elt) => {
                     children.push(MjRawChild::Node(self.parse(cursor, elt.local)?));
                 }
@@ -113,6 +152,9 @@ impl AsyncParseChildren<Vec<MjRawChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     children.push(MjRawChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    children.push(MjRawChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(elt) => {
                     children.push(MjRawChild::Node(self.async_parse(cursor, elt.local).await?));
                 }
@@ -133,3 +175,26 @@ impl AsyncParseChildren<Vec<MjRawChild>> for AsyncMrmlParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_raw::MjRaw;
+    use crate::prelude::parser::{MrmlCursor, MrmlParser, ParserOptions};
+
+    crate::should_sync_parse!(
+        should_parse_file_start,
+        MjRaw,
+        r#"<mj-raw position="file-start">{% extends 'base.html' %}</mj-raw>"#
+    );
+
+    #[test]
+    fn should_warn_with_unknown_attribute() {
+        let template = r#"<mj-raw oups="true"><span>Hello</span></mj-raw>"#;
+        let opts = ParserOptions::default();
+        let parser = MrmlParser::new(&opts);
+        let mut cursor = MrmlCursor::new(template);
+        let elt: MjRaw = parser.parse_root(&mut cursor).unwrap();
+        assert!(!elt.is_file_start());
+        assert_eq!(cursor.warnings().len(), 1);
+    }
+}
@@ -1,3 +1,14 @@
+use crate::prelude::print::PrintableAttributes;
+
+impl PrintableAttributes for super::MjRawAttributes {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        if let Some(ref position) = self.position {
+            printer.push_attribute("position", position.as_str())?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::print::Printable;
@@ -7,4 +18,20 @@ mod tests {
         let item = crate::mj_raw::MjRaw::default();
         assert_eq!("<mj-raw />", item.print_dense().unwrap());
     }
+
+    #[test]
+    fn file_start() {
+        let item = crate::mj_raw::MjRaw::new(
+            crate::mj_raw::MjRawAttributes {
+                position: Some("file-start".into()),
+            },
+            vec![crate::mj_raw::MjRawChild::Text(crate::text::Text::from(
+                "{% extends 'base.html' %}",
+            ))],
+        );
+        assert_eq!(
+            "<mj-raw position=\"file-start\">{% extends 'base.html' %}</mj-raw>",
+            item.print_dense().unwrap()
+        );
+    }
 }
@@ -10,6 +10,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjRawChild {
         match self {
             Self::Comment(elt) => elt.renderer(context),
             Self::Node(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
             Self::Text(elt) => elt.renderer(context),
         }
     }
@@ -29,6 +30,21 @@ impl<'root> Render<'root> for Renderer<'root, MjRaw, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if self.element.is_file_start() {
+            // rendered aside, the mjml element writes it before the doctype
+            let previous = cursor.buffer.take();
+            self.render_children(cursor)?;
+            let content = std::mem::replace(&mut cursor.buffer, previous);
+            cursor.header.add_file_start(content.as_ref());
+            Ok(())
+        } else {
+            self.render_children(cursor)
+        }
+    }
+}
+
+impl<'root> Renderer<'root, MjRaw, ()> {
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let siblings = self.element.children.len();
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
@@ -36,7 +52,7 @@ impl<'root> Render<'root> for Renderer<'root, MjRaw, ()> {
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(siblings);
             renderer.set_container_width(self.container_width);
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         Ok(())
     }
@@ -55,4 +71,30 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjRaw {
 mod tests {
     crate::should_render!(basic, "mj-raw");
     crate::should_render!(in_head, "mj-raw-head");
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_file_start_before_doctype() {
+        use crate::mjml::Mjml;
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-raw position="file-start">{% extends 'base.html' %}</mj-raw>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-raw position="file-start">{% load static %}</mj-raw>
+        <mj-text>Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.starts_with("{% extends 'base.html' %}{% load static %}<!doctype html>"));
+        assert_eq!(result.matches("{% extends 'base.html' %}").count(), 1);
+        assert_eq!(result.matches("{% load static %}").count(), 1);
+    }
 }
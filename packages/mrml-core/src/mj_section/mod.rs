@@ -11,8 +11,14 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+/// Kept for compatibility, the background helpers are shared with the other
+/// elements supporting a `background-url`.
 #[cfg(feature = "render")]
-pub use render::{SectionLikeRender, WithMjSectionBackground};
+pub use crate::helper::background::WithBackground as WithMjSectionBackground;
+#[cfg(feature = "render")]
+pub(crate) use render::warn_column_widths;
+#[cfg(feature = "render")]
+pub use render::SectionLikeRender;
 
 pub const NAME: &str = "mj-section";
 
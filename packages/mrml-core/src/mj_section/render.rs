@@ -1,212 +1,51 @@
-use std::borrow::Cow;
-use std::convert::TryFrom;
-
 use super::{MjSection, NAME};
-use crate::helper::size::{Percent, Pixel};
+use crate::helper::background::WithBackground;
+use crate::helper::size::{Pixel, Size};
+use crate::prelude::attributes::{MjSectionAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-fn is_horizontal_position(value: &str) -> bool {
-    value == "left" || value == "right" || value == "center"
-}
-
-fn is_vertical_position(value: &str) -> bool {
-    value == "top" || value == "bottom" || value == "center"
-}
-
-pub trait WithMjSectionBackground<'root>: Render<'root> {
-    fn has_background(&self) -> bool {
-        self.attribute_exists("background-url")
-    }
-
-    fn parse_background_position<'a>(&'a self) -> (&'a str, &'a str)
-    where
-        'root: 'a,
-    {
-        // can be unwraped because has default value
-        let position = self.attribute("background-position").unwrap();
-        let mut positions = position.split_whitespace();
-        if let Some(first) = positions.next() {
-            if let Some(second) = positions.next() {
-                if is_vertical_position(first) && is_horizontal_position(second) {
-                    (second, first)
-                } else {
-                    (first, second)
-                }
-            } else if is_vertical_position(first) {
-                ("center", first)
-            } else {
-                (first, "center")
-            }
-        } else {
-            ("center", "top")
-        }
-    }
-
-    fn get_background_position<'a>(&'a self) -> (&'a str, &'a str)
-    where
-        'root: 'a,
-    {
-        let (x, y) = self.parse_background_position();
-        (
-            self.attribute("background-position-x").unwrap_or(x),
-            self.attribute("background-position-y").unwrap_or(y),
-        )
-    }
-
-    fn get_background_position_str(&self) -> String {
-        let position = self.get_background_position();
-        format!("{} {}", position.0, position.1)
-    }
-
-    fn get_background(&self) -> Option<String> {
-        let mut res: Vec<Cow<'_, str>> = vec![];
-        if let Some(color) = self.attribute("background-color") {
-            res.push(color.into());
-        }
-        if let Some(url) = self.attribute("background-url") {
-            res.push(format!("url('{url}')").into());
-            // has default value
-            res.push(
-                format!(
-                    "{} / {}",
-                    self.get_background_position_str(),
-                    self.attribute("background-size").unwrap()
-                )
-                .into(),
-            );
-            // has default value
-            res.push(self.attribute("background-repeat").unwrap().into());
-        }
-
-        if res.is_empty() {
-            None
-        } else {
-            Some(res.join(" "))
-        }
-    }
-
-    fn set_background_style<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
-    where
-        'root: 'a,
-        'a: 't,
-    {
-        if self.has_background() {
-            tag.maybe_add_style("background", self.get_background())
-                .add_style("background-position", self.get_background_position_str())
-                .maybe_add_style("background-repeat", self.attribute("background-repeat"))
-                .maybe_add_style("background-size", self.attribute("background-size"))
-        } else {
-            tag.maybe_add_style("background", self.attribute("background-color"))
-                .maybe_add_style("background-color", self.attribute("background-color"))
-        }
-    }
-
-    fn get_vfill_position(&self) -> (Cow<'root, str>, Cow<'root, str>) {
-        if self.attribute_equals("background-size", "auto") {
-            return ("0.5, 0".into(), "0.5, 0".into());
-        }
-        let (bg_position_x, bg_position_y) = self.get_background_position();
-        let bg_repeat = self.attribute_equals("background-repeat", "repeat");
-        let bg_position_x = match bg_position_x {
-            "left" => "0%",
-            "center" => "50%",
-            "right" => "100%",
-            _ => {
-                if bg_position_x.ends_with('%') {
-                    bg_position_x
-                } else {
-                    "50%"
-                }
-            }
-        };
-        let bg_position_y = match bg_position_y {
-            "top" => "0%",
-            "center" => "50%",
-            "bottom" => "100%",
-            _ => {
-                if bg_position_y.ends_with('%') {
-                    bg_position_y
-                } else {
-                    "0%"
-                }
-            }
-        };
-        let position_x = if let Ok(position) = Percent::try_from(bg_position_x) {
-            if bg_repeat {
-                position.value() * 0.01
-            } else {
-                (position.value() - 50.0) * 0.01
-            }
-        } else if bg_repeat {
-            0.5
-        } else {
-            0.0
-        };
-        let position_y = if let Ok(position) = Percent::try_from(bg_position_y) {
-            if bg_repeat {
-                position.value() * 0.01
-            } else {
-                (position.value() - 50.0) * 0.01
-            }
-        } else if bg_repeat {
-            0.5
-        } else {
-            0.0
-        };
-        (
-            format!("{position_x}, {position_y}").into(),
-            format!("{position_x}, {position_y}").into(),
+/// Warns when the widths of the columns rendered in the same row add up to more
+/// than 100% of the container.
+pub(crate) fn warn_column_widths<'root>(
+    context: &'root RenderContext<'root>,
+    element: &str,
+    children: &'root [crate::mj_body::MjBodyChild],
+    container_width: Option<Pixel>,
+    cursor: &mut RenderCursor,
+) {
+    let children = cursor.buffer.bind_children(children);
+    let non_raw_siblings = children
+        .iter()
+        .filter(|child| !child.element.is_raw())
+        .count();
+    if non_raw_siblings == 0 {
+        return;
+    }
+    let total = children
+        .iter()
+        .filter(|child| !child.element.is_raw())
+        .filter_map(
+            |child| match child.element.renderer(context).attribute_as_size("width") {
+                Some(Size::Percent(value)) => Some(value.value()),
+                Some(Size::Pixel(value)) => container_width
+                    .as_ref()
+                    .map(|width| value.value() * 100.0 / width.value()),
+                Some(Size::Raw(_)) => None,
+                None => Some(100.0 / non_raw_siblings as f32),
+            },
         )
-    }
-
-    fn get_vfill_tag<'a>(&'a self) -> Tag<'a>
-    where
-        'root: 'a,
-    {
-        let bg_no_repeat = self.attribute_equals("background-repeat", "no-repeat");
-        let bg_size = self.attribute("background-size");
-        let bg_size_auto = bg_size
-            .as_ref()
-            .map(|value| *value == "auto")
-            .unwrap_or(false);
-        let vml_type = if bg_no_repeat && !bg_size_auto {
-            "frame"
-        } else {
-            "tile"
-        };
-        let vsize = match bg_size {
-            Some("cover") | Some("contain") => Some("1,1".to_string()),
-            Some("auto") => None,
-            Some(value) => Some(value.replace(' ', ",")),
-            None => None,
-        };
-        let aspect = match bg_size {
-            Some("cover") => Some("atleast".to_string()),
-            Some("contain") => Some("atmost".to_string()),
-            Some("auto") => None,
-            Some(other) => {
-                if other.split(' ').count() == 1 {
-                    Some("atmost".to_string())
-                } else {
-                    None
-                }
-            }
-            None => None,
-        };
-
-        let (vfill_position, vfill_origin) = self.get_vfill_position();
-        Tag::new("v:fill")
-            .add_attribute("position", vfill_position)
-            .add_attribute("origin", vfill_origin)
-            .maybe_add_attribute("src", self.attribute("background-url"))
-            .maybe_add_attribute("color", self.attribute("background-color"))
-            .maybe_add_attribute("size", vsize)
-            .add_attribute("type", vml_type)
-            .maybe_add_attribute("aspect", aspect)
+        .sum::<f32>();
+    // leave some room for rounding errors like 3 times 33.34%
+    if total > 100.1 {
+        cursor.add_warning(
+            WarningKind::ColumnWidthOverflow,
+            Some(element),
+            format!("{total}%"),
+        );
     }
 }
 
-pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
+pub trait SectionLikeRender<'root>: WithBackground<'root> {
     fn container_width(&self) -> &Option<Pixel>;
     fn children(&self) -> &Vec<crate::mj_body::MjBodyChild>;
 
@@ -219,37 +58,12 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         F: Fn(&mut RenderCursor) -> Result<(), Error>,
     {
         let full_width = self.is_full_width();
-        let vrect = Tag::new("v:rect")
-            .maybe_add_attribute(
-                "mso-width-percent",
-                if full_width { Some("1000") } else { None },
-            )
-            .maybe_add_style(
-                "width",
-                if full_width {
-                    None
-                } else {
-                    self.container_width().as_ref().map(|v| v.to_string())
-                },
-            )
-            .add_attribute("xmlns:v", "urn:schemas-microsoft-com:vml")
-            .add_attribute("fill", "true")
-            .add_attribute("stroke", "false");
-        let vfill = self.get_vfill_tag();
-        let vtextbox = Tag::new("v:textbox")
-            .add_attribute("inset", "0,0,0,0")
-            .add_style("mso-fit-shape-to-text", "true");
-
-        vrect.render_open(&mut cursor.buffer)?;
-        vfill.render_closed(&mut cursor.buffer)?;
-        vtextbox.render_open(&mut cursor.buffer)?;
-        cursor.buffer.end_conditional_tag();
-        content(cursor)?;
-        cursor.buffer.start_conditional_tag();
-        vtextbox.render_close(&mut cursor.buffer);
-        vrect.render_close(&mut cursor.buffer);
-
-        Ok(())
+        let width = if full_width {
+            None
+        } else {
+            self.container_width().as_ref().map(|v| v.to_string())
+        };
+        self.render_vml_background(cursor, full_width, width, content)
     }
 
     fn set_style_section_div<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -307,28 +121,23 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
         Ok(())
     }
 
-    fn get_siblings(&self) -> usize {
-        self.children().len()
-    }
-
-    fn get_raw_siblings(&self) -> usize {
-        self.children().iter().filter(|elt| elt.is_raw()).count()
-    }
-
     fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let siblings = self.get_siblings();
-        let raw_siblings = self.get_raw_siblings();
+        let children = cursor.buffer.bind_children(self.children());
+        let siblings = children.len();
+        let raw_siblings = children.iter().filter(|c| c.element.is_raw()).count();
         let tr = Tag::tr();
 
         tr.render_open(&mut cursor.buffer)?;
-        for child in self.children().iter() {
-            let mut renderer = child.renderer(self.context());
+        for child in children.iter() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(*self.container_width());
-            if child.is_raw() {
+            if child.element.is_raw() {
                 cursor.buffer.end_conditional_tag();
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 cursor.buffer.start_conditional_tag();
             } else {
                 let td = renderer
@@ -337,10 +146,11 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
                     .maybe_add_suffixed_class(renderer.attribute("css-class"), "outlook");
                 td.render_open(&mut cursor.buffer)?;
                 cursor.buffer.end_conditional_tag();
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 cursor.buffer.start_conditional_tag();
                 td.render_close(&mut cursor.buffer);
             }
+            cursor.buffer.leave_child(mark);
         }
         tr.render_close(&mut cursor.buffer);
         Ok(())
@@ -387,13 +197,19 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
 
     fn render_section(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let is_full_width = self.is_full_width();
+        let dark_background = if is_full_width {
+            None
+        } else {
+            self.dark_class(cursor, "dark-background-color", "background-color")
+        };
         let div = self
             .set_style_section_div(Tag::div())
             .maybe_add_class(if is_full_width {
                 None
             } else {
                 self.attribute("css-class")
-            });
+            })
+            .maybe_add_class(dark_background.clone());
         let inner_div = self.set_style_section_inner_div(Tag::div());
         let table = self.set_style_section_table(
             Tag::table_presentation()
@@ -405,7 +221,8 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
                     } else {
                         self.attribute("background-url")
                     },
-                ),
+                )
+                .maybe_add_class(dark_background),
         );
         let tbody = Tag::tbody();
         let tr = Tag::tr();
@@ -463,7 +280,11 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     }
 
     fn render_full_width(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let table = self.get_full_width_table();
+        let table = self.get_full_width_table().maybe_add_class(self.dark_class(
+            cursor,
+            "dark-background-color",
+            "background-color",
+        ));
         let tbody = Tag::tbody();
         let tr = Tag::tr();
         let td = Tag::td();
@@ -513,8 +334,8 @@ pub trait SectionLikeRender<'root>: WithMjSectionBackground<'root> {
     }
 }
 
-impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjSection, ()> {}
-impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, ()> {
+impl<'root> WithBackground<'root> for Renderer<'root, MjSection, MjSectionAttributes> {}
+impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, MjSectionAttributes> {
     fn children(&self) -> &Vec<crate::mj_body::MjBodyChild> {
         &self.element.children
     }
@@ -524,7 +345,7 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjSection, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjSection, MjSectionAttributes> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
             "background-position" => Some("top center"),
@@ -538,6 +359,10 @@ impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -555,6 +380,14 @@ impl<'root> Render<'root> for Renderer<'root, MjSection, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+        warn_column_widths(
+            self.context,
+            NAME,
+            &self.element.children,
+            self.container_width,
+            cursor,
+        );
         if self.is_full_width() {
             self.render_full_width(cursor)
         } else {
@@ -568,7 +401,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSection {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjSectionAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -1,6 +1,7 @@
 use super::MjSocialChild;
 use crate::comment::Comment;
 use crate::mj_social_element::NAME as MJ_SOCIAL_ELEMENT;
+use crate::placeholder::Placeholder;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
@@ -16,6 +17,9 @@ impl<'opts> ParseChildren<Vec<MjSocialChild>> for MrmlParser<'opts> {
                 MrmlToken::Comment(inner) => {
                     result.push(MjSocialChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjSocialChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_SOCIAL_ELEMENT {
                         result.push(MjSocialChild::MjSocialElement(
@@ -58,6 +62,9 @@ impl AsyncParseChildren<Vec<MjSocialChild>> for AsyncMrmlParser {
                 MrmlToken::Comment(inner) => {
                     result.push(MjSocialChild::Comment(Comment::from(inner.text.as_str())));
                 }
+                MrmlToken::Placeholder(inner) => {
+                    result.push(MjSocialChild::Placeholder(Placeholder::from(inner.text)));
+                }
                 MrmlToken::ElementStart(inner) => {
                     if inner.local.as_str() == MJ_SOCIAL_ELEMENT {
                         result.push(MjSocialChild::MjSocialElement(
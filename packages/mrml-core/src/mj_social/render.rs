@@ -10,6 +10,7 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSocialChild {
         match self {
             Self::MjSocialElement(elt) => elt.renderer(context),
             Self::Comment(elt) => elt.renderer(context),
+            Self::Placeholder(elt) => elt.renderer(context),
         }
     }
 }
@@ -89,11 +90,12 @@ impl<'root> Renderer<'root, MjSocial, ()> {
             inner_table.render_open(&mut cursor.buffer)?;
             inner_tbody.render_open(&mut cursor.buffer)?;
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             child_attributes.iter().for_each(|(key, value)| {
                 renderer.add_extra_attribute(key, value);
             });
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
             inner_tbody.render_close(&mut cursor.buffer);
             inner_table.render_close(&mut cursor.buffer);
             cursor.buffer.start_conditional_tag();
@@ -117,11 +119,12 @@ impl<'root> Renderer<'root, MjSocial, ()> {
         tbody.render_open(&mut cursor.buffer)?;
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_index(index);
             child_attributes.iter().for_each(|(key, value)| {
                 renderer.add_extra_attribute(key, value);
             });
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         tbody.render_close(&mut cursor.buffer);
         table.render_close(&mut cursor.buffer);
@@ -176,6 +179,7 @@ impl<'root> Render<'root> for Renderer<'root, MjSocial, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_families = self.attribute("font-family").unwrap_or_default(); // never happens
         cursor.header.add_font_families(font_families);
 
@@ -214,7 +214,7 @@ impl<'root> Renderer<'root, MjSocialElement, MjSocialElementExtra<'root>> {
         wrapper.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
             let renderer = child.renderer(self.context());
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         wrapper.render_close(&mut cursor.buffer);
         td.render_close(&mut cursor.buffer);
@@ -265,6 +265,12 @@ impl<'root> Render<'root> for Renderer<'root, MjSocialElement, MjSocialElementEx
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+        if self.extra.network.is_none() && !self.attribute_exists("src") {
+            if let Some(name) = self.attribute("name") {
+                cursor.add_warning(WarningKind::UnknownSocialNetwork, self.tag(), name);
+            }
+        }
         let href = self.get_href();
         let tr = Tag::tr().maybe_add_class(self.attribute("css-class"));
         let td = self.set_style_td(Tag::td());
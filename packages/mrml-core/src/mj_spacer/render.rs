@@ -1,8 +1,9 @@
 use super::{MjSpacer, NAME};
 use crate::helper::size::Pixel;
+use crate::prelude::attributes::{MjSpacerAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjSpacer, MjSpacerAttributes> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "height" => Some("20px"),
@@ -10,6 +11,10 @@ impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -27,6 +32,7 @@ impl<'root> Render<'root> for Renderer<'root, MjSpacer, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         Tag::div()
             .maybe_add_style("height", self.attribute("height"))
             .maybe_add_style("line-height", self.attribute("height"))
@@ -40,7 +46,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjSpacer {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjSpacerAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -5,15 +5,7 @@ impl Printable for super::MjStyle {
         printer.push_indent();
         printer.open_tag(super::NAME)?;
         printer.close_tag();
-        printer.push_new_line();
-        printer.increase_indent();
-        for line in self.children.split('\n') {
-            printer.push_indent();
-            printer.push_str(line.trim());
-            printer.push_new_line();
-        }
-        printer.decrease_indent();
-        printer.push_indent();
+        printer.push_lines(&self.children);
         printer.end_tag(super::NAME)?;
         printer.push_new_line();
         Ok(())
@@ -1,9 +1,9 @@
 use super::{MjTable, NAME};
+use crate::helper::background::WithBackground;
 use crate::helper::size::Pixel;
-use crate::mj_section::WithMjSectionBackground;
 use crate::prelude::render::*;
 
-impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjTable, ()> {}
+impl<'root> WithBackground<'root> for Renderer<'root, MjTable, ()> {}
 
 impl<'root> Renderer<'root, MjTable, ()> {
     fn set_style_table<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
@@ -56,6 +56,7 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_family = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_family);
 
@@ -69,7 +70,7 @@ impl<'root> Render<'root> for Renderer<'root, MjTable, ()> {
         for (index, child) in self.element.children.iter().enumerate() {
             let mut renderer = child.renderer(self.context());
             renderer.set_index(index);
-            renderer.render(cursor)?;
+            renderer.render_component(cursor)?;
         }
         table.render_close(&mut cursor.buffer);
         Ok(())
@@ -1,7 +1,8 @@
 use super::{MjText, NAME};
+use crate::prelude::attributes::{MjTextAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Renderer<'root, MjText, ()> {
+impl<'root> Renderer<'root, MjText, MjTextAttributes> {
     fn set_style_text<'a, 't>(&'a self, tag: Tag<'t>) -> Tag<'t>
     where
         'root: 'a,
@@ -21,10 +22,12 @@ impl<'root> Renderer<'root, MjText, ()> {
     }
 
     fn render_content(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        let root = self.set_style_text(Tag::div());
+        let root = self
+            .set_style_text(Tag::div())
+            .maybe_add_class(self.dark_class(cursor, "dark-color", "color"));
         root.render_open(&mut cursor.buffer)?;
         for child in self.element.children.iter() {
-            child.renderer(self.context()).render(cursor)?;
+            child.renderer(self.context()).render_component(cursor)?;
         }
         root.render_close(&mut cursor.buffer);
         Ok(())
@@ -53,7 +56,7 @@ impl<'root> Renderer<'root, MjText, ()> {
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjText, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjText, MjTextAttributes> {
     fn default_attribute(&self, key: &str) -> Option<&'static str> {
         match key {
             "align" => Some("left"),
@@ -66,6 +69,10 @@ impl<'root> Render<'root> for Renderer<'root, MjText, ()> {
         }
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -79,6 +86,7 @@ impl<'root> Render<'root> for Renderer<'root, MjText, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         let font_family = self.attribute("font-family");
         cursor.header.maybe_add_font_families(font_family);
 
@@ -95,7 +103,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjText {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjTextAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::mj_video::MjVideo;
+
+    #[test]
+    fn serialize() {
+        let mut elt = MjVideo::default();
+        elt.attributes
+            .insert("src".into(), "https://example.com/video.mp4".into());
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"mj-video","attributes":{"src":"https://example.com/video.mp4"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":"mj-video","attributes":{"src":"https://example.com/video.mp4"}}"#;
+        let res: MjVideo = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.attributes.get("src").unwrap(),
+            "https://example.com/video.mp4"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use std::marker::PhantomData;
+
+use crate::prelude::hash::Map;
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub const NAME: &str = "mj-video";
+
+pub struct MjVideoTag;
+
+impl StaticTag for MjVideoTag {
+    fn static_tag() -> &'static str {
+        NAME
+    }
+}
+
+/// Video played inline by the email clients supporting it (Apple Mail, iOS),
+/// replaced elsewhere by its `poster` with a play button linking to `href`.
+pub type MjVideo = Component<PhantomData<MjVideoTag>, Map<String, String>, ()>;
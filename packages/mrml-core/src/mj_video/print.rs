@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn empty() {
+        let mut item = crate::mj_video::MjVideo::default();
+        item.attributes
+            .insert("src".to_string(), "http://localhost/video.mp4".into());
+        assert_eq!(
+            "<mj-video src=\"http://localhost/video.mp4\" />",
+            item.print_dense().unwrap()
+        );
+    }
+}
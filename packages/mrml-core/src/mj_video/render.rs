@@ -0,0 +1,279 @@
+use super::{MjVideo, NAME};
+use crate::helper::size::Pixel;
+use crate::helper::style::Style;
+use crate::prelude::render::*;
+
+/// The video is shown by the clients matching a webkit only media query, except
+/// for the webmails known to not play it, the others keep the fallback.
+fn render_style() -> String {
+    let player = Style::default()
+        .add_selector(".mj-video-player")
+        .add_content("display: block !important;")
+        .add_content("max-height: none !important;")
+        .add_content("overflow: visible !important;");
+    let fallback = Style::default()
+        .add_selector(".mj-video-fallback")
+        .add_content("display: none !important;");
+    let webmail_player = Style::default()
+        .add_selector("[owa] .mj-video-player")
+        .add_selector("#MessageViewBody .mj-video-player")
+        .add_content("display: none !important;");
+    let webmail_fallback = Style::default()
+        .add_selector("[owa] .mj-video-fallback")
+        .add_selector("#MessageViewBody .mj-video-fallback")
+        .add_content("display: block !important;");
+    format!(
+        "@media screen and (-webkit-min-device-pixel-ratio: 0) {{ {player} {fallback} }}\n{webmail_player}\n{webmail_fallback}"
+    )
+}
+
+impl<'root> Renderer<'root, MjVideo, ()> {
+    fn get_box_width(&self) -> Option<Pixel> {
+        self.container_width.as_ref().map(|width| {
+            let hpadding = self.get_padding_horizontal();
+            Pixel::new(width.value() - hpadding.value())
+        })
+    }
+
+    fn get_content_width(&self) -> Option<Pixel> {
+        self.attribute_as_pixel("width")
+            .map(|width| match self.get_box_width() {
+                Some(box_size) if box_size.value() < width.value() => box_size,
+                _ => width,
+            })
+            .or_else(|| self.get_box_width())
+    }
+
+    /// The given height or the one of a 16:9 video.
+    fn get_content_height(&self, width: Option<&Pixel>) -> Option<Pixel> {
+        self.attribute_as_pixel("height")
+            .or_else(|| width.map(|width| Pixel::new((width.value() * 9.0 / 16.0).round())))
+    }
+
+    fn render_player(
+        &self,
+        cursor: &mut RenderCursor,
+        width: Option<&Pixel>,
+        height: Option<&Pixel>,
+    ) -> Result<(), Error> {
+        let div = Tag::div()
+            .add_class("mj-video-player")
+            .add_style("display", "none")
+            .add_style("max-height", "0")
+            .add_style("overflow", "hidden")
+            .add_style("mso-hide", "all");
+        let video = Tag::new("video")
+            .add_attribute("controls", "controls")
+            .maybe_add_attribute("poster", self.attribute("poster"))
+            .maybe_add_attribute("width", width.map(|width| width.value().to_string()))
+            .maybe_add_attribute("height", height.map(|height| height.value().to_string()))
+            .maybe_add_attribute("title", self.attribute("alt"))
+            .add_style("display", "block")
+            .add_style("width", "100%")
+            .add_style("height", "auto")
+            .maybe_add_style("border-radius", self.attribute("border-radius"));
+        let source = Tag::new("source")
+            .maybe_add_attribute("src", self.attribute("src"))
+            .maybe_add_attribute("type", self.attribute("type"));
+
+        cursor.buffer.start_mso_negation_conditional_tag();
+        div.render_open(&mut cursor.buffer)?;
+        video.render_open(&mut cursor.buffer)?;
+        source.render_closed(&mut cursor.buffer)?;
+        video.render_close(&mut cursor.buffer);
+        div.render_close(&mut cursor.buffer);
+        cursor.buffer.end_negation_conditional_tag();
+        Ok(())
+    }
+
+    fn render_play_icon(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(icon) = self.attribute("play-icon") {
+            let size = self.attribute_as_pixel("play-icon-width");
+            Tag::new("img")
+                .add_attribute("src", icon)
+                .add_attribute("alt", "Play")
+                .maybe_add_attribute("width", size.as_ref().map(|s| s.value().to_string()))
+                .maybe_add_attribute("height", size.as_ref().map(|s| s.value().to_string()))
+                .add_style("display", "inline-block")
+                .add_style("border", "0")
+                .maybe_add_style("width", size.as_ref().map(|s| s.to_string()))
+                .maybe_add_style("height", size.as_ref().map(|s| s.to_string()))
+                .render_closed(&mut cursor.buffer)?;
+        } else {
+            // a triangle drawn with borders, supported by all the clients
+            Tag::div()
+                .add_style("display", "inline-block")
+                .add_style("width", "0")
+                .add_style("height", "0")
+                .add_style("border-top", "20px solid transparent")
+                .add_style("border-bottom", "20px solid transparent")
+                .add_style(
+                    "border-left",
+                    format!("32px solid {}", self.attribute("play-icon-color").unwrap()),
+                )
+                .render_text(&mut cursor.buffer, "")?;
+        }
+        Ok(())
+    }
+
+    fn render_fallback(
+        &self,
+        cursor: &mut RenderCursor,
+        width: Option<&Pixel>,
+        height: Option<&Pixel>,
+    ) -> Result<(), Error> {
+        let poster = self.attribute("poster");
+        let link = Tag::new("a")
+            .add_class("mj-video-fallback")
+            .maybe_add_attribute("href", self.attribute("href").or(self.attribute("src")))
+            .maybe_add_attribute("target", self.attribute("target"))
+            .add_style("display", "block")
+            .add_style("text-decoration", "none");
+        let table = Tag::table_presentation()
+            .maybe_add_attribute("width", width.map(|width| width.value().to_string()))
+            .maybe_add_attribute("background", poster)
+            .maybe_add_style("background-color", self.attribute("background-color"))
+            .maybe_add_style(
+                "background-image",
+                poster.map(|url| format!("url('{url}')")),
+            )
+            .add_style("background-position", "center")
+            .add_style("background-size", "cover")
+            .add_style("background-repeat", "no-repeat")
+            .add_style("border-collapse", "collapse")
+            .maybe_add_style("border-radius", self.attribute("border-radius"))
+            .maybe_add_style("width", width.map(|width| width.to_string()));
+        let tbody = Tag::tbody();
+        let tr = Tag::tr();
+        let td = Tag::td()
+            .maybe_add_attribute("height", height.map(|height| height.value().to_string()))
+            .add_attribute("align", "center")
+            .add_attribute("valign", "middle")
+            .maybe_add_style("height", height.map(|height| height.to_string()))
+            .add_style("text-align", "center")
+            .add_style("vertical-align", "middle");
+
+        link.render_open(&mut cursor.buffer)?;
+        if let Some(poster) = poster {
+            // outlook ignores the background images, it gets the poster only
+            cursor.buffer.start_mso_conditional_tag();
+            Tag::new("img")
+                .maybe_add_attribute("alt", self.attribute("alt"))
+                .add_attribute("src", poster)
+                .maybe_add_attribute("width", width.map(|width| width.value().to_string()))
+                .maybe_add_attribute("height", height.map(|height| height.value().to_string()))
+                .add_style("display", "block")
+                .add_style("border", "0")
+                .add_style("outline", "none")
+                .add_style("text-decoration", "none")
+                .render_closed(&mut cursor.buffer)?;
+            cursor.buffer.end_conditional_tag();
+            cursor.buffer.start_mso_negation_conditional_tag();
+        }
+        table.render_open(&mut cursor.buffer)?;
+        tbody.render_open(&mut cursor.buffer)?;
+        tr.render_open(&mut cursor.buffer)?;
+        td.render_open(&mut cursor.buffer)?;
+        self.render_play_icon(cursor)?;
+        td.render_close(&mut cursor.buffer);
+        tr.render_close(&mut cursor.buffer);
+        tbody.render_close(&mut cursor.buffer);
+        table.render_close(&mut cursor.buffer);
+        if poster.is_some() {
+            cursor.buffer.end_negation_conditional_tag();
+        }
+        link.render_close(&mut cursor.buffer);
+        Ok(())
+    }
+}
+
+impl<'root> Render<'root> for Renderer<'root, MjVideo, ()> {
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        match key {
+            "align" => Some("center"),
+            "background-color" => Some("#000000"),
+            "padding" => Some("10px 25px"),
+            "play-icon-color" => Some("#ffffff"),
+            "play-icon-width" => Some("64px"),
+            "target" => Some("_blank"),
+            _ => None,
+        }
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn tag(&self) -> Option<&str> {
+        Some(NAME)
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+        cursor.header.add_style(render_style());
+
+        let width = self.get_content_width();
+        let height = self.get_content_height(width.as_ref());
+        self.render_player(cursor, width.as_ref(), height.as_ref())?;
+        self.render_fallback(cursor, width.as_ref(), height.as_ref())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for MjVideo {
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_player_and_fallback() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-video src="https://example.com/launch.mp4" type="video/mp4" poster="https://example.com/poster.jpg" href="https://example.com/launch" alt="Launch" />
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"<!--[if !mso]><!--><div class="mj-video-player" style="display:none;max-height:0;overflow:hidden;mso-hide:all;"><video controls="controls" poster="https://example.com/poster.jpg" width="550" height="309" title="Launch""#));
+        assert!(
+            html.contains(r#"<source src="https://example.com/launch.mp4" type="video/mp4" />"#)
+        );
+        assert!(html.contains(
+            r#"<a href="https://example.com/launch" target="_blank" class="mj-video-fallback""#
+        ));
+        assert!(html.contains(r#"<!--[if mso]><img alt="Launch" src="https://example.com/poster.jpg" width="550" height="309""#));
+        assert!(html.contains("background-image:url('https://example.com/poster.jpg');"));
+        assert!(html.contains("border-left:32px solid #ffffff;"));
+        assert!(html.contains("@media screen and (-webkit-min-device-pixel-ratio: 0)"));
+        assert!(html.contains("[owa] .mj-video-fallback"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_render_play_icon_image() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-video src="https://example.com/launch.mp4" width="300px" height="200px" play-icon="https://example.com/play.png" />
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"width="300" height="200""#));
+        assert!(html.contains(
+            r#"<img src="https://example.com/play.png" alt="Play" width="64" height="64""#
+        ));
+        assert!(html.contains(r#"<a href="https://example.com/launch.mp4""#));
+    }
+}
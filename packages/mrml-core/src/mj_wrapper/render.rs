@@ -1,9 +1,11 @@
 use super::{MjWrapper, NAME};
+use crate::helper::background::WithBackground;
 use crate::helper::size::Pixel;
-use crate::mj_section::{SectionLikeRender, WithMjSectionBackground};
+use crate::mj_section::SectionLikeRender;
+use crate::prelude::attributes::{MjWrapperAttributes, ParsedAttributes};
 use crate::prelude::render::*;
 
-impl<'root> Renderer<'root, MjWrapper, ()> {
+impl<'root> Renderer<'root, MjWrapper, MjWrapperAttributes> {
     fn current_width(&self) -> Option<Pixel> {
         self.container_width.as_ref().map(|width| {
             let hborder = self.get_border_horizontal();
@@ -13,9 +15,9 @@ impl<'root> Renderer<'root, MjWrapper, ()> {
     }
 }
 
-impl<'root> WithMjSectionBackground<'root> for Renderer<'root, MjWrapper, ()> {}
+impl<'root> WithBackground<'root> for Renderer<'root, MjWrapper, MjWrapperAttributes> {}
 
-impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, ()> {
+impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, MjWrapperAttributes> {
     fn children(&self) -> &Vec<crate::mj_body::MjBodyChild> {
         &self.element.children
     }
@@ -26,17 +28,20 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, ()> {
 
     fn render_wrapped_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         let tr = Tag::tr();
-        let siblings = self.get_siblings();
-        let raw_siblings = self.get_raw_siblings();
+        let children = cursor.buffer.bind_children(self.children());
+        let siblings = children.len();
+        let raw_siblings = children.iter().filter(|c| c.element.is_raw()).count();
         let current_width = self.current_width();
         let container_width = self.container_width.as_ref().map(|v| v.to_string());
-        for child in self.children().iter() {
-            let mut renderer = child.renderer(self.context());
+        for child in children.iter() {
+            let mark = cursor.buffer.enter_child(child);
+            let mut renderer = child.element.renderer(self.context());
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
             renderer.set_siblings(siblings);
             renderer.set_raw_siblings(raw_siblings);
             renderer.set_container_width(current_width);
-            if child.is_raw() {
-                renderer.render(cursor)?;
+            if child.element.is_raw() {
+                renderer.render_component(cursor)?;
             } else {
                 let td = renderer
                     .set_style("td-outlook", Tag::td())
@@ -46,17 +51,18 @@ impl<'root> SectionLikeRender<'root> for Renderer<'root, MjWrapper, ()> {
                 tr.render_open(&mut cursor.buffer)?;
                 td.render_open(&mut cursor.buffer)?;
                 cursor.buffer.end_conditional_tag();
-                renderer.render(cursor)?;
+                renderer.render_component(cursor)?;
                 cursor.buffer.start_conditional_tag();
                 td.render_close(&mut cursor.buffer);
                 tr.render_close(&mut cursor.buffer);
             }
+            cursor.buffer.leave_child(mark);
         }
         Ok(())
     }
 }
 
-impl<'root> Render<'root> for Renderer<'root, MjWrapper, ()> {
+impl<'root> Render<'root> for Renderer<'root, MjWrapper, MjWrapperAttributes> {
     fn default_attribute(&self, name: &str) -> Option<&'static str> {
         match name {
             "background-position" => Some("top center"),
@@ -74,6 +80,10 @@ impl<'root> Render<'root> for Renderer<'root, MjWrapper, ()> {
         self.context
     }
 
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        Some(&self.extra)
+    }
+
     fn raw_attribute(&self, key: &str) -> Option<&'root str> {
         self.element.attributes.get(key).map(|v| v.as_str())
     }
@@ -87,6 +97,7 @@ impl<'root> Render<'root> for Renderer<'root, MjWrapper, ()> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
         if self.is_full_width() {
             self.render_full_width(cursor)
         } else {
@@ -100,7 +111,11 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for MjWrapper {
         &'root self,
         context: &'root RenderContext<'root>,
     ) -> Box<dyn Render<'root> + 'render> {
-        Box::new(Renderer::new(context, self, ()))
+        Box::new(Renderer::new(
+            context,
+            self,
+            MjWrapperAttributes::for_render(&self.attributes),
+        ))
     }
 }
 
@@ -15,9 +15,12 @@ mod print;
 #[cfg(feature = "render")]
 mod render;
 
+#[cfg(feature = "render")]
+pub use render::CompiledMjml;
+
 pub const NAME: &str = "mjml";
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct MjmlAttributes {
     #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
@@ -28,7 +31,7 @@ pub struct MjmlAttributes {
     pub dir: Option<String>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct MjmlChildren {
     pub head: Option<MjHead>,
     pub body: Option<MjBody>,
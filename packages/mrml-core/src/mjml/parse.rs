@@ -6,8 +6,8 @@ use crate::mj_head::NAME as MJ_HEAD;
 #[cfg(feature = "async")]
 use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren, AsyncParseElement};
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren, ParseElement,
-    ParseOutput, ParserOptions, WarningKind,
+    Error, MaskedSource, MrmlCursor, MrmlParser, MrmlToken, ParseAttributes, ParseChildren,
+    ParseElement, ParseOutput, ParserOptions, WarningKind,
 };
 
 #[inline(always)]
@@ -138,6 +138,7 @@ impl Mjml {
     ///
     /// let options = ParserOptions {
     ///     include_loader: Box::new(MemoryIncludeLoader::default()),
+    ///     ..Default::default()
     /// };
     /// match Mjml::parse_with_options("<mjml><mj-head /><mj-body /></mjml>", &options) {
     ///     Ok(_) => println!("Success!"),
@@ -149,7 +150,8 @@ impl Mjml {
         opts: &ParserOptions,
     ) -> Result<ParseOutput<Self>, Error> {
         let parser = MrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        let source = MaskedSource::new(value.as_ref(), &parser.options.placeholders);
+        let mut cursor = MrmlCursor::from_masked(&source);
         let element = parser.parse_root(&mut cursor)?;
         Ok(ParseOutput {
             element,
@@ -163,7 +165,8 @@ impl Mjml {
         opts: std::sync::Arc<crate::prelude::parser::AsyncParserOptions>,
     ) -> Result<ParseOutput<Self>, Error> {
         let parser = AsyncMrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        let source = MaskedSource::new(value.as_ref(), &parser.options.placeholders);
+        let mut cursor = MrmlCursor::from_masked(&source);
         let element = parser.parse_root(&mut cursor).await?;
         Ok(ParseOutput {
             element,
@@ -307,4 +310,111 @@ mod tests {
         let template = "<mjml><div /></mjml>";
         let _ = Mjml::parse(template).unwrap();
     }
+
+    fn placeholder_options() -> ParserOptions {
+        use crate::prelude::parser::Delimiters;
+
+        ParserOptions {
+            placeholders: vec![Delimiters::new("{{", "}}"), Delimiters::new("{%", "%}")],
+            ..Default::default()
+        }
+    }
+
+    const PLACEHOLDER_TEMPLATE: &str = r#"<mjml>
+  <mj-head>
+    {% block head %}
+    <mj-title>Hello {{ name }}</mj-title>
+  </mj-head>
+  <mj-body>
+    <mj-section {{ section_attrs }}>
+      {% for item in items %}
+      <mj-column>
+        <mj-text color="{{ item.color }}">{% if a < b %}{{ item.name }}{% endif %}</mj-text>
+        <mj-social>{% if twitter %}<mj-social-element name="twitter" />{% endif %}</mj-social>
+      </mj-column>
+      {% endfor %}
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    #[test]
+    fn should_fail_with_placeholders_without_delimiters() {
+        assert!(Mjml::parse(PLACEHOLDER_TEMPLATE).is_err());
+    }
+
+    #[test]
+    fn should_parse_with_placeholders_sync() {
+        use crate::mj_body::MjBodyChild;
+        use crate::mj_head::MjHeadChild;
+
+        let output =
+            Mjml::parse_with_options(PLACEHOLDER_TEMPLATE, &placeholder_options()).unwrap();
+        assert!(output.warnings.is_empty());
+        let head = output.element.head().unwrap();
+        assert!(
+            matches!(&head.children[0], MjHeadChild::Placeholder(inner) if inner.children == "{% block head %}")
+        );
+        assert!(
+            matches!(&head.children[1], MjHeadChild::MjTitle(inner) if inner.children == "Hello {{ name }}")
+        );
+        let body = output.element.body().unwrap();
+        let MjBodyChild::MjSection(section) = &body.children[0] else {
+            panic!("expected a section");
+        };
+        assert!(section
+            .attributes
+            .values()
+            .any(|value| value == "{{ section_attrs }}"));
+        assert!(
+            matches!(&section.children[0], MjBodyChild::Placeholder(inner) if inner.children == "{% for item in items %}")
+        );
+        assert!(
+            matches!(&section.children[2], MjBodyChild::Placeholder(inner) if inner.children == "{% endfor %}")
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_parse_with_placeholders_async() {
+        let options = crate::prelude::parser::AsyncParserOptions {
+            placeholders: placeholder_options().placeholders,
+            ..Default::default()
+        };
+        let output = Mjml::async_parse_with_options(PLACEHOLDER_TEMPLATE, options.into())
+            .await
+            .unwrap();
+        assert!(output.warnings.is_empty());
+        assert_eq!(output.element.body().unwrap().children.len(), 1);
+    }
+
+    #[cfg(feature = "print")]
+    #[test]
+    fn should_print_placeholders_verbatim() {
+        use crate::prelude::print::Printable;
+
+        let template = r#"<mjml><mj-body><mj-section {{ attrs }} padding="0" {{ attrs }}>{% for item in items %}<mj-column><mj-text color="{{ item.color }}">{% if a < b %}{{ item.name }}{% endif %}</mj-text></mj-column>{% endfor %}</mj-section></mj-body></mjml>"#;
+        let output = Mjml::parse_with_options(template, &placeholder_options()).unwrap();
+        assert_eq!(output.element.print_dense().unwrap(), template);
+    }
+
+    #[cfg(all(feature = "json", feature = "print", feature = "render"))]
+    #[test]
+    fn should_keep_attribute_values_with_unusual_names() {
+        use crate::prelude::print::Printable;
+
+        let json = r#"{"type":"mjml","children":[{"type":"mj-body","children":[{"type":"button","attributes":{"@click":"track()"},"children":["Buy"]}]}]}"#;
+        let element: Mjml = serde_json::from_str(json).unwrap();
+        let printed = element.print_dense().unwrap();
+        assert!(
+            printed.contains(r#"<button @click="track()">Buy</button>"#),
+            "{}",
+            printed
+        );
+        let rendered = element.render(&Default::default()).unwrap();
+        assert!(
+            rendered.contains(r#"<button @click="track()">"#),
+            "{}",
+            rendered
+        );
+    }
 }
@@ -1,20 +1,41 @@
 use super::Mjml;
+use crate::helper::size::Pixel;
 use crate::mj_head::MjHead;
 use crate::prelude::render::*;
 
-impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
-    fn context(&self) -> &'root RenderContext<'root> {
-        self.context
-    }
-
-    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+impl<'root> Renderer<'root, Mjml, ()> {
+    fn render_body(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         if let Some(body) = self.element.body() {
-            body.renderer(self.context).render(cursor)?;
+            body.renderer(self.context).render_component(cursor)
         } else {
             cursor.buffer.push_str("<body></body>");
+            Ok(())
+        }
+    }
+
+    fn render_head(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        if let Some(head) = self.element.head() {
+            head.renderer(self.context).render_component(cursor)
+        } else {
+            MjHead::default()
+                .renderer(self.context)
+                .render_component(cursor)
         }
-        let mut body = RenderBuffer::default();
-        std::mem::swap(&mut body, &mut cursor.buffer);
+    }
+
+    /// Renders everything that comes before the body: the `file-start` raw
+    /// elements, the doctype, the opening `html` tag and the head. The head
+    /// depends on what was collected while rendering the body, so this has to
+    /// be called after `render_body`.
+    fn render_preamble(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        // the head comes first in the template but is rendered after the body
+        let body_file_start = cursor.header.take_file_start();
+        let previous = cursor.buffer.take();
+        self.render_head(cursor)?;
+        let head = std::mem::replace(&mut cursor.buffer, previous);
+        // already rendered, with the data interpolated
+        cursor.buffer.push_verbatim(cursor.header.file_start());
+        cursor.buffer.push_verbatim(&body_file_start);
         cursor.buffer.push_str("<!doctype html>");
         cursor.buffer.open_tag("html");
         if let Some(ref lang) = self.element.attributes.lang {
@@ -30,13 +51,32 @@ impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
             .buffer
             .push_attribute("xmlns:o", "urn:schemas-microsoft-com:office:office")?;
         cursor.buffer.close_tag();
-        if let Some(head) = self.element.head() {
-            head.renderer(self.context).render(cursor)?;
-        } else {
-            MjHead::default().renderer(self.context).render(cursor)?;
-        }
-        cursor.buffer.push_str(body.as_ref());
-        cursor.buffer.end_tag("html");
+        cursor.buffer.push_verbatim(head.as_ref());
+        Ok(())
+    }
+
+    /// Renders the document in two parts, the preamble in the cursor buffer and
+    /// the body in the returned buffer, so that they can be written one after
+    /// the other without being copied into a single buffer.
+    fn render_parts(&self, cursor: &mut RenderCursor) -> Result<RenderBuffer, Error> {
+        self.render_body(cursor)?;
+        let body = cursor.buffer.take();
+        self.render_preamble(cursor)?;
+        Ok(body)
+    }
+}
+
+const HTML_END: &str = "</html>";
+
+impl<'root> Render<'root> for Renderer<'root, Mjml, ()> {
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let body = self.render_parts(cursor)?;
+        cursor.buffer.push_verbatim(body.as_ref());
+        cursor.buffer.push_str(HTML_END);
         Ok(())
     }
 }
@@ -50,13 +90,224 @@ impl<'render, 'root: 'render> Renderable<'render, 'root> for Mjml {
     }
 }
 
+/// A template whose head has been processed once (`mj-attributes`, fonts,
+/// breakpoint, etc) and that can be rendered many times. The attributes the
+/// elements of the body get from the head through `mj-class` are resolved
+/// once as well, see [`ResolvedAttributes`].
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// let root = mrml::parse("<mjml><mj-body><mj-text>Hello</mj-text></mj-body></mjml>").expect("parse template");
+/// let opts = mrml::prelude::render::RenderOptions::default();
+/// let compiled = root.element.compile();
+/// for _ in 0..3 {
+///     let html = compiled.render(&opts).expect("render template");
+///     assert!(html.contains("Hello"));
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CompiledMjml<'a> {
+    element: &'a Mjml,
+    header: Header<'a>,
+    resolved: Option<ResolvedAttributes<'a>>,
+}
+
+impl<'a> CompiledMjml<'a> {
+    pub fn new(element: &'a Mjml) -> Self {
+        let header = Header::new(element.head(), element.attributes.lang.as_deref());
+        let resolved = ResolvedAttributes::new(element, &header);
+        Self {
+            element,
+            header,
+            resolved: Some(resolved),
+        }
+    }
+
+    // resolving the attributes takes a walk through the template, which
+    // doesn't pay off when rendering it only once
+    fn without_resolved(element: &'a Mjml) -> Self {
+        Self {
+            element,
+            header: Header::new(element.head(), element.attributes.lang.as_deref()),
+            resolved: None,
+        }
+    }
+
+    pub fn element(&self) -> &'a Mjml {
+        self.element
+    }
+
+    pub fn header(&self) -> &Header<'a> {
+        &self.header
+    }
+
+    pub fn resolved(&self) -> Option<&ResolvedAttributes<'a>> {
+        self.resolved.as_ref()
+    }
+
+    fn context<'c>(&'c self, opts: &'c RenderOptions) -> RenderContext<'c> {
+        let context = RenderContext::new(opts, &self.header);
+        match self.resolved {
+            Some(ref resolved) => context.with_resolved(resolved),
+            None => context,
+        }
+    }
+
+    fn render_parts(
+        &self,
+        opts: &RenderOptions,
+    ) -> Result<(RenderBuffer, RenderBuffer, Vec<Warning>), Error> {
+        let context = self.context(opts);
+        let mut cursor = RenderCursor::new(opts);
+        let body = Renderer::new(&context, self.element, ()).render_parts(&mut cursor)?;
+        Ok((cursor.buffer, body, cursor.warnings))
+    }
+
+    pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
+        self.render_with_warnings(opts).map(|output| output.content)
+    }
+
+    pub fn render_with_warnings(&self, opts: &RenderOptions) -> Result<RenderOutput, Error> {
+        let (preamble, body, warnings) = self.render_parts(opts)?;
+        let body: &str = body.as_ref();
+        // the preamble buffer becomes the output, only the body is copied
+        let mut content = String::from(preamble);
+        content.reserve(body.len() + HTML_END.len());
+        content.push_str(body);
+        content.push_str(HTML_END);
+        Ok(RenderOutput { content, warnings })
+    }
+
+    /// Renders the template into the given writer, see
+    /// [`Mjml::render_to`].
+    pub fn render_to<W: std::fmt::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        let (preamble, body, _) = self.render_parts(opts)?;
+        writer.write_str(preamble.as_ref())?;
+        writer.write_str(body.as_ref())?;
+        writer.write_str(HTML_END)?;
+        Ok(())
+    }
+
+    /// Renders a single element, like an `mj-section` or an `mj-column`, in
+    /// the context of this template's head, without the rest of the document.
+    /// The container width, in pixels, is the width of the parent element.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # {
+    /// use mrml::mj_body::MjBodyChild;
+    /// use mrml::prelude::render::RenderOptions;
+    ///
+    /// let root = mrml::parse("<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>").expect("parse template");
+    /// let compiled = root.element.compile();
+    /// let section = &root.element.body().unwrap().children[0];
+    /// let fragment = compiled
+    ///     .render_element(section, 600.0, &RenderOptions::default())
+    ///     .expect("render element");
+    /// assert!(fragment.content.contains("Hello"));
+    /// assert!(fragment.head.contains("mj-column-per-100"));
+    /// # }
+    /// ```
+    pub fn render_element<E>(
+        &self,
+        element: &E,
+        container_width: f32,
+        opts: &RenderOptions,
+    ) -> Result<RenderFragment, Error>
+    where
+        E: for<'r> Renderable<'r, 'r>,
+    {
+        let context = self.context(opts);
+        let mut cursor = RenderCursor::new(opts);
+        let mut renderer = element.renderer(&context);
+        renderer.set_container_width(Some(Pixel::new(container_width)));
+        renderer.render_component(&mut cursor)?;
+        let content = cursor.buffer.take();
+        let default_head = MjHead::default();
+        let head = self.element.head().unwrap_or(&default_head);
+        Renderer::new(&context, head, ()).render_head_styles(&mut cursor);
+        Ok(RenderFragment {
+            content: content.into(),
+            head: cursor.buffer.into(),
+            warnings: cursor.warnings,
+        })
+    }
+
+    /// Same as [`render_to`](CompiledMjml::render_to) but for a
+    /// [`std::io::Write`] sink like a file, a socket or a response body.
+    pub fn write_to<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        let (preamble, body, _) = self.render_parts(opts)?;
+        let preamble: &str = preamble.as_ref();
+        let body: &str = body.as_ref();
+        writer.write_all(preamble.as_bytes())?;
+        writer.write_all(body.as_bytes())?;
+        writer.write_all(HTML_END.as_bytes())?;
+        Ok(())
+    }
+}
+
 impl Mjml {
+    /// Builds the [`CompiledMjml`] of this template, so that the head and the
+    /// attributes it gives to the elements are only processed once when
+    /// rendering it several times.
+    pub fn compile(&self) -> CompiledMjml<'_> {
+        CompiledMjml::new(self)
+    }
+
     pub fn render(&self, opts: &RenderOptions) -> Result<String, Error> {
-        let header = Header::new(self.children.head.as_ref(), self.attributes.lang.as_deref());
-        let context = RenderContext::new(opts, header);
-        let mut cursor = RenderCursor::default();
-        self.renderer(&context).render(&mut cursor)?;
-        Ok(cursor.buffer.into())
+        CompiledMjml::without_resolved(self).render(opts)
+    }
+
+    /// Renders the template and returns, alongside the generated html, the
+    /// warnings collected while rendering (unknown font families, unparsable
+    /// sizes, etc).
+    pub fn render_with_warnings(&self, opts: &RenderOptions) -> Result<RenderOutput, Error> {
+        CompiledMjml::without_resolved(self).render_with_warnings(opts)
+    }
+
+    /// Renders the template into the given writer.
+    ///
+    /// The head depends on what is collected while rendering the body, so the
+    /// body is rendered in memory first. The head and then the body are
+    /// written to the writer as they are, without being copied into a single
+    /// string containing the whole document.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "parse")]
+    /// # {
+    /// let root = mrml::parse("<mjml><mj-body></mj-body></mjml>").expect("parse template");
+    /// let opts = mrml::prelude::render::RenderOptions::default();
+    /// let mut output = String::new();
+    /// root.element.render_to(&opts, &mut output).expect("render template");
+    /// assert!(output.starts_with("<!doctype html>"));
+    /// # }
+    /// ```
+    pub fn render_to<W: std::fmt::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        CompiledMjml::without_resolved(self).render_to(opts, writer)
+    }
+
+    /// Same as [`render_to`](Mjml::render_to) but for a
+    /// [`std::io::Write`] sink like a file, a socket or a response body.
+    pub fn write_to<W: std::io::Write>(
+        &self,
+        opts: &RenderOptions,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        CompiledMjml::without_resolved(self).write_to(opts, writer)
     }
 
     pub fn get_title(&self) -> Option<String> {
@@ -74,8 +325,9 @@ impl Mjml {
 
 #[cfg(all(test, feature = "parse"))]
 mod tests {
+    use crate::mj_body::MjBodyChild;
     use crate::mjml::Mjml;
-    use crate::prelude::render::RenderOptions;
+    use crate::prelude::render::{RenderOptions, WarningKind};
 
     crate::should_render!(empty, "mjml");
 
@@ -109,4 +361,349 @@ mod tests {
 
         assert_eq!(output_1, output_2);
     }
+
+    #[test]
+    fn render_to_should_match_render() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+
+        let mut output = String::new();
+        root.element.render_to(&opts, &mut output).unwrap();
+        assert_eq!(output, expected);
+
+        let mut output: Vec<u8> = Vec::new();
+        root.element.write_to(&opts, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[derive(Default)]
+    struct RecordingWriter {
+        writes: Vec<String>,
+    }
+
+    impl std::fmt::Write for RecordingWriter {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.writes.push(s.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn render_to_should_write_parts_to_the_writer() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+
+        let mut writer = RecordingWriter::default();
+        root.element.render_to(&opts, &mut writer).unwrap();
+        assert_eq!(writer.writes.concat(), expected);
+        // the head, the body and the end of the document
+        assert_eq!(writer.writes.len(), 3);
+        assert!(writer.writes[0].ends_with("</head>"));
+        assert!(writer.writes[1].starts_with("<body"));
+        assert_eq!(writer.writes[2], "</html>");
+    }
+
+    #[test]
+    fn compiled_should_match_render() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+        let compiled = root.element.compile();
+        assert_eq!(compiled.render(&opts).unwrap(), expected);
+        assert_eq!(compiled.render(&opts).unwrap(), expected);
+    }
+
+    #[test]
+    fn compiled_should_reuse_resolved_attributes() {
+        let opts = RenderOptions::default();
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-class name="red" color="red" />
+      <mj-class name="blue" color="blue" font-size="20px" />
+      <mj-class name="dark">
+        <mj-text color="white" />
+      </mj-class>
+      <mj-text color="green" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section mj-class="dark">
+      <mj-column>
+        <mj-text>scoped</mj-text>
+        <mj-text mj-class="red blue">classes</mj-text>
+        <mj-text>scoped again</mj-text>
+      </mj-column>
+    </mj-section>
+    <mj-section><mj-column><mj-text>default</mj-text></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+        let compiled = root.element.compile();
+        // the section using the class and its descendants, the texts in the same
+        // scope sharing their attributes
+        assert_eq!(compiled.resolved().unwrap().len(), 4);
+        for _ in 0..2 {
+            assert_eq!(compiled.render(&opts).unwrap(), expected);
+        }
+        assert!(expected.contains("color:white"));
+        assert!(expected.contains("color:red"));
+        assert!(expected.contains("font-size:20px"));
+        assert!(expected.contains("color:green"));
+    }
+
+    #[test]
+    fn compiled_should_match_render_for_the_compared_templates() {
+        let opts = RenderOptions::default();
+        for (path, template) in crate::helper::compare::compared_templates() {
+            let root = Mjml::parse(template).unwrap();
+            let expected = root.element.render(&opts).unwrap();
+            assert_eq!(
+                root.element.compile().render(&opts).unwrap(),
+                expected,
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_render_element_with_head_context() {
+        let opts = RenderOptions::default();
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes><mj-text color="red" /></mj-attributes>
+    <mj-style>.foo { color: blue; }</mj-style>
+  </mj-head>
+  <mj-body>
+    <mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let compiled = root.element.compile();
+        let MjBodyChild::MjSection(ref section) = root.element.body().unwrap().children[0] else {
+            panic!("expected a section");
+        };
+        let column = &section.children[0];
+        let fragment = compiled.render_element(column, 300.0, &opts).unwrap();
+        assert!(fragment.content.contains("Hello"));
+        assert!(fragment.content.contains("color:red"));
+        assert!(!fragment.content.contains("<body"));
+        assert!(fragment.head.contains("mj-column-per-100"));
+        assert!(fragment.head.contains(".foo { color: blue; }"));
+        assert!(fragment.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_apply_mj_class_children_to_descendants() {
+        let opts = RenderOptions::default();
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <mj-class name="blue">
+        <mj-text color="blue" />
+      </mj-class>
+      <mj-class name="red" color="red" />
+      <mj-text color="green" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section mj-class="blue">
+      <mj-column>
+        <mj-text>inside</mj-text>
+        <mj-text mj-class="red">own class</mj-text>
+        <mj-text color="yellow">own attribute</mj-text>
+      </mj-column>
+    </mj-section>
+    <mj-section>
+      <mj-column>
+        <mj-text>outside</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let output = root.element.render_with_warnings(&opts).unwrap();
+        assert!(output.warnings.is_empty());
+        let colors = output
+            .content
+            .split("color:")
+            .skip(1)
+            .filter_map(|item| item.split(';').next())
+            .collect::<Vec<_>>();
+        assert_eq!(colors, vec!["blue", "red", "yellow", "green"]);
+    }
+
+    #[test]
+    fn should_render_placeholders_verbatim() {
+        use crate::prelude::parser::{Delimiters, ParserOptions};
+
+        let parser_options = ParserOptions {
+            placeholders: vec![Delimiters::new("{{", "}}"), Delimiters::new("{%", "%}")],
+            ..Default::default()
+        };
+        let template = r#"<mjml>
+  <mj-head>
+    {% block head %}{% endblock %}
+    <mj-title>Hello {{ name }}</mj-title>
+  </mj-head>
+  <mj-body>
+    <mj-section>
+      {% for item in items %}
+      <mj-column>
+        <mj-text color="{{ item.color }}">{% if a < b %}{{ item.name }}{% endif %}</mj-text>
+        <mj-raw><a {{ link_attrs }} href="{{ item.url }}">link</a></mj-raw>
+      </mj-column>
+      {% endfor %}
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse_with_options(template, &parser_options).unwrap();
+        let output = root
+            .element
+            .render_with_warnings(&RenderOptions::default())
+            .unwrap();
+        assert!(output.warnings.is_empty());
+        let html = output.content;
+        assert!(html.contains("{% block head %}{% endblock %}</head>"));
+        assert!(html.contains("<title>Hello {{ name }}</title>"));
+        assert!(html.contains("{% for item in items %}"));
+        assert!(html.contains("{% endfor %}"));
+        assert!(html.contains("color:{{ item.color }};"));
+        assert!(html.contains("{% if a < b %}{{ item.name }}{% endif %}"));
+        assert!(html.contains(r#"<a {{ link_attrs }} href="{{ item.url }}">link</a>"#));
+    }
+
+    #[test]
+    fn template_air_astana_warnings() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/air-astana.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let output = root.element.render_with_warnings(&opts).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        assert_eq!(output.warnings[0].kind, WarningKind::UnknownFontFamily);
+        assert_eq!(output.warnings[0].value, "'Proxima Nova'");
+    }
+
+    fn render_warnings(template: &str) -> Vec<(WarningKind, String)> {
+        let root = Mjml::parse(template).unwrap();
+        root.element
+            .render_with_warnings(&RenderOptions::default())
+            .unwrap()
+            .warnings
+            .into_iter()
+            .map(|warning| (warning.kind, warning.value))
+            .collect()
+    }
+
+    #[test]
+    fn should_warn_unknown_font_family() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text font-family="Foo, Arial, sans-serif">hi</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![(WarningKind::UnknownFontFamily, String::from("Foo"))]
+        );
+    }
+
+    #[test]
+    fn should_warn_unknown_mj_class() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-head><mj-attributes><mj-class name="red" color="red" /></mj-attributes></mj-head><mj-body><mj-section><mj-column><mj-text mj-class="red blue">hi</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![(WarningKind::UnknownMjClass, String::from("blue"))]
+        );
+    }
+
+    #[test]
+    fn should_warn_unparsable_unit() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-image src="foo.png" width="12em" /></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![(WarningKind::UnparsableUnit, String::from("width=12em"))]
+        );
+    }
+
+    #[test]
+    fn should_not_warn_multiple_values_or_font_units() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-button border-radius="4px 0" font-size="1.2em" padding="10px 25px">hi</mj-button></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn should_warn_unknown_social_network() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-social><mj-social-element name="myspace" href="http://example.com">myspace</mj-social-element><mj-social-element name="custom" src="http://example.com/icon.png">custom</mj-social-element></mj-social></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![(WarningKind::UnknownSocialNetwork, String::from("myspace"))]
+        );
+    }
+
+    #[test]
+    fn should_warn_column_width_overflow() {
+        let warnings = render_warnings(
+            r#"<mjml><mj-body><mj-section><mj-column width="60%"></mj-column><mj-column width="300px"></mj-column></mj-section><mj-section><mj-column width="33.34%"></mj-column><mj-column width="33.33%"></mj-column><mj-column></mj-column></mj-section></mj-body></mjml>"#,
+        );
+        assert_eq!(
+            warnings,
+            vec![(WarningKind::ColumnWidthOverflow, String::from("110%"))]
+        );
+    }
+
+    #[test]
+    fn should_render_dark_mode_rules() {
+        let opts = RenderOptions::default();
+        let template = r##"<mjml>
+  <mj-head>
+    <mj-attributes><mj-text dark-color="#eeeeee" /></mj-attributes>
+  </mj-head>
+  <mj-body dark-background-color="#000000">
+    <mj-section background-color="#ffffff" dark-background-color="#111111">
+      <mj-column>
+        <mj-text>Hello</mj-text>
+        <mj-button dark-background-color="rgb(1, 2, 3)">Go</mj-button>
+        <mj-image src="light.png" dark-src="dark.png" />
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##;
+        let root = Mjml::parse(template).unwrap();
+        let html = root.element.render(&opts).unwrap();
+        assert!(html.contains(r#"<meta name="color-scheme" content="light dark">"#));
+        assert!(html.contains("@media (prefers-color-scheme: dark) { "));
+        assert!(html.contains(".mj-dark-color-eeeeee { color:#eeeeee !important; }"));
+        assert!(html.contains("[data-ogsc] .mj-dark-color-eeeeee"));
+        assert!(html.contains("[data-ogsb] .mj-dark-background-color-111111"));
+        assert!(html.contains(
+            ".mj-dark-background-color-rgb-1--2--3 { background-color:rgb(1, 2, 3) !important; }"
+        ));
+        assert!(html.contains(r#"<body class="mj-dark-background-color-000000""#));
+        assert!(html.contains(r#"class="mj-light-img""#));
+        assert!(html.contains(r#"src="dark.png""#));
+        assert!(html.contains(".mj-light-img { display:none !important; }"));
+        assert!(html.contains(r#"class="mj-dark-background-color-111111""#));
+    }
+
+    #[test]
+    fn should_not_render_dark_mode_rules_without_dark_attributes() {
+        let opts = RenderOptions::default();
+        let template = include_str!("../../resources/template/amario.mjml");
+        let root = Mjml::parse(template).unwrap();
+        let html = root.element.render(&opts).unwrap();
+        assert!(!html.contains("color-scheme"));
+    }
 }
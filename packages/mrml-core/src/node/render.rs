@@ -2,6 +2,35 @@ use super::Node;
 use crate::prelude::is_void_element;
 use crate::prelude::render::*;
 
+impl<'render, 'root: 'render, T> Renderer<'root, Node<T>, ()>
+where
+    T: Renderable<'render, 'root>,
+{
+    fn render_children(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        for (index, child) in self.element.children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context);
+            renderer.set_index(index);
+            renderer.render_component(cursor)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the children of an `mj-if` or an `mj-each` element in place of
+    /// the element, as many times as the bound data requires.
+    fn render_control_flow(
+        &self,
+        cursor: &mut RenderCursor,
+        iterations: Vec<Option<(String, DataValue)>>,
+    ) -> Result<(), Error> {
+        for item in iterations {
+            let mark = cursor.buffer.data.enter(item);
+            self.render_children(cursor)?;
+            cursor.buffer.data.leave(mark);
+        }
+        Ok(())
+    }
+}
+
 impl<'render, 'root: 'render, T> Render<'root> for Renderer<'root, Node<T>, ()>
 where
     T: Renderable<'render, 'root>,
@@ -10,31 +39,37 @@ where
         Some(self.element.tag.as_str())
     }
 
+    // the html elements belong to the component containing them
+    fn component_name(&self) -> Option<&str> {
+        None
+    }
+
     fn context(&self) -> &'root RenderContext<'root> {
         self.context
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
-        cursor.buffer.open_tag(&self.element.tag);
-        for (key, value) in self.element.attributes.iter() {
-            cursor.buffer.push_attribute(key, value)?;
+        if let Some(iterations) = cursor.buffer.data.iterations(self.element) {
+            return self.render_control_flow(cursor, iterations);
         }
+        let tag = self
+            .element
+            .attributes
+            .iter()
+            .fold(Tag::new(self.element.tag.as_str()), |tag, (key, value)| {
+                tag.add_attribute(key.as_str(), value.as_str())
+            });
         if self.element.children.is_empty() {
             if is_void_element(self.element.tag.as_str()) {
-                cursor.buffer.closed_tag();
+                tag.render_closed(&mut cursor.buffer)?;
             } else {
-                cursor.buffer.close_tag();
-                cursor.buffer.end_tag(&self.element.tag);
+                tag.render_open(&mut cursor.buffer)?;
+                tag.render_close(&mut cursor.buffer);
             }
         } else {
-            cursor.buffer.close_tag();
-            for (index, child) in self.element.children.iter().enumerate() {
-                // TODO children
-                let mut renderer = child.renderer(self.context);
-                renderer.set_index(index);
-                renderer.render(cursor)?;
-            }
-            cursor.buffer.end_tag(&self.element.tag);
+            tag.render_open(&mut cursor.buffer)?;
+            self.render_children(cursor)?;
+            tag.render_close(&mut cursor.buffer);
         }
         Ok(())
     }
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::placeholder::Placeholder;
+
+    #[test]
+    fn serialize() {
+        let elt = Placeholder::from("{{ name }}");
+        assert_eq!(
+            serde_json::to_string(&elt).unwrap(),
+            r#"{"type":"placeholder","children":"{{ name }}"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let elt = Placeholder::from("{{ name }}");
+        let json = serde_json::to_string(&elt).unwrap();
+        let res: Placeholder = serde_json::from_str(&json).unwrap();
+        assert_eq!(res.children, elt.children);
+    }
+
+    #[test]
+    fn deserialize_as_child() {
+        use crate::mj_body::MjBodyChild;
+
+        let json = r#"{"type":"placeholder","children":"{{ name }}"}"#;
+        let res: MjBodyChild = serde_json::from_str(json).unwrap();
+        assert!(matches!(res, MjBodyChild::Placeholder(inner) if inner.children == "{{ name }}"));
+    }
+}
@@ -0,0 +1,47 @@
+use std::marker::PhantomData;
+
+use crate::prelude::{Component, StaticTag};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "print")]
+mod print;
+#[cfg(feature = "render")]
+mod render;
+
+pub struct PlaceholderTag;
+
+impl StaticTag for PlaceholderTag {
+    fn static_tag() -> &'static str {
+        "placeholder"
+    }
+}
+
+/// Block of a templating language (`{{ name }}`, `{% if %}`, etc) kept as is
+/// by the parser when its delimiters are configured in the
+/// [`ParserOptions`](crate::prelude::parser::ParserOptions), and written
+/// verbatim when printing or rendering.
+pub type Placeholder = Component<PhantomData<PlaceholderTag>, (), String>;
+
+impl<V: Into<String>> From<V> for Placeholder {
+    fn from(value: V) -> Self {
+        Self::new((), value.into())
+    }
+}
+
+/// Prefix of the names given by the parser to the placeholders found where an
+/// attribute is expected. They are stored as attributes named after their
+/// position in the template, so that a placeholder can be repeated, with the
+/// placeholder as value, and written back as is, the null character not being
+/// allowed in a template.
+pub const PLACEHOLDER_ATTRIBUTE: &str = "\u{0}";
+
+#[cfg(feature = "parse")]
+pub(crate) fn placeholder_attribute_name(position: usize) -> String {
+    format!("{PLACEHOLDER_ATTRIBUTE}{position}")
+}
+
+#[cfg(any(feature = "print", feature = "render"))]
+pub(crate) fn is_placeholder_attribute(name: &str) -> bool {
+    name.starts_with(PLACEHOLDER_ATTRIBUTE)
+}
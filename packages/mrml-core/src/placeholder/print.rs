@@ -0,0 +1,21 @@
+use crate::prelude::print::Printable;
+
+impl Printable for super::Placeholder {
+    fn print<P: crate::prelude::print::Printer>(&self, printer: &mut P) -> std::fmt::Result {
+        printer.push_indent();
+        printer.push_str(self.children.as_str());
+        printer.push_new_line();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::print::Printable;
+
+    #[test]
+    fn verbatim() {
+        let item = crate::placeholder::Placeholder::from("{% if user %}");
+        assert_eq!("{% if user %}", item.print_dense().unwrap());
+    }
+}
@@ -0,0 +1,26 @@
+use super::Placeholder;
+use crate::prelude::render::*;
+
+impl<'root> Render<'root> for Renderer<'root, Placeholder, ()> {
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        cursor.buffer.push_verbatim(self.element.children.as_str());
+        Ok(())
+    }
+}
+
+impl<'render, 'root: 'render> Renderable<'render, 'root> for Placeholder {
+    fn is_raw(&self) -> bool {
+        true
+    }
+
+    fn renderer(
+        &'root self,
+        context: &'root RenderContext<'root>,
+    ) -> Box<dyn Render<'root> + 'render> {
+        Box::new(Renderer::new(context, self, ()))
+    }
+}
@@ -0,0 +1,765 @@
+//! Typed representation of the attributes of the body components.
+//!
+//! The components keep their attributes as a [`Map`] of strings, the way
+//! they are written in the template. The structures of this module are a
+//! typed view of those attributes, with the sizes, spacings, colors and
+//! keywords parsed and validated. The attributes without a typed field are
+//! kept as is in `others`.
+//!
+//! Only `mj-button`, `mj-column`, `mj-divider`, `mj-image`, `mj-section`,
+//! `mj-spacer`, `mj-text` and `mj-wrapper` have a typed view. Their renderers
+//! parse the sizes and spacings of the element once, through
+//! [`ParsedAttributes`], the other values and the ones coming from the head
+//! being read from the [`Map`].
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//!
+//! use mrml::mj_button::MjButton;
+//! use mrml::prelude::attributes::{Color, Pixel};
+//! use mrml::prelude::hash::Map;
+//!
+//! let mut attributes = Map::new();
+//! attributes.insert("color".to_string(), "#ffffff".to_string());
+//! attributes.insert("font-size".to_string(), "16px".to_string());
+//! let mut button = MjButton::new(attributes, Vec::new());
+//!
+//! let mut typed = button.typed_attributes().unwrap();
+//! assert_eq!(typed.font_size, Some(Pixel::new(16.0)));
+//! typed.background_color = Some(Color::try_from("#ff0000").unwrap());
+//! button.set_typed_attributes(&typed);
+//! assert_eq!(button.attributes.get("background-color").unwrap(), "#ff0000");
+//! ```
+//!
+//! The values can also be validated when parsing a template by enabling the
+//! `strict_attributes` option of the parser. The values containing one of the
+//! parser `placeholders` are not validated.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+pub use crate::helper::size::{Percent, Pixel, Size, SizeParserError};
+pub use crate::helper::spacing::{Spacing, SpacingParserError};
+use crate::prelude::hash::Map;
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("invalid value {value:?} for attribute {name:?}: {reason}")]
+pub struct AttributeError {
+    pub name: String,
+    pub value: String,
+    pub reason: String,
+}
+
+/// Values that can be parsed from an attribute.
+pub trait FromAttribute: Sized {
+    fn from_attribute(value: &str) -> Result<Self, String>;
+}
+
+impl FromAttribute for String {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Ok(value.to_string())
+    }
+}
+
+impl FromAttribute for bool {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        match value {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err("expected true or false".into()),
+        }
+    }
+}
+
+/// MJML accepts a zero without unit where a pixel value is expected.
+fn with_pixel_zero(value: &str) -> String {
+    value
+        .split_whitespace()
+        .map(|item| if item == "0" { "0px" } else { item })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl FromAttribute for Pixel {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Pixel::try_from(with_pixel_zero(value).as_str()).map_err(|err| err.to_string())
+    }
+}
+
+impl FromAttribute for Size {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Size::try_from(value).map_err(|err| err.to_string())
+    }
+}
+
+impl FromAttribute for Spacing {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Spacing::try_from(with_pixel_zero(value).as_str()).map_err(|err| err.to_string())
+    }
+}
+
+/// Values the renderers read as sizes, parsed the way the renderers parse the
+/// attributes of a template: unlike the validation, a bare `0` isn't a pixel
+/// value there.
+#[cfg(feature = "render")]
+trait RenderValue: Sized {
+    fn parse_rendered(_value: &str) -> Option<Self> {
+        None
+    }
+
+    fn as_pixel(&self) -> Option<Pixel> {
+        None
+    }
+
+    fn as_size(&self) -> Option<Size> {
+        None
+    }
+
+    fn as_spacing(&self) -> Option<Spacing> {
+        None
+    }
+}
+
+#[cfg(feature = "render")]
+impl RenderValue for String {}
+
+#[cfg(feature = "render")]
+impl RenderValue for bool {}
+
+#[cfg(feature = "render")]
+impl RenderValue for Pixel {
+    fn parse_rendered(value: &str) -> Option<Self> {
+        Pixel::try_from(value).ok()
+    }
+
+    fn as_pixel(&self) -> Option<Pixel> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "render")]
+impl RenderValue for Size {
+    fn parse_rendered(value: &str) -> Option<Self> {
+        Size::try_from(value).ok()
+    }
+
+    fn as_size(&self) -> Option<Size> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "render")]
+impl RenderValue for Spacing {
+    fn parse_rendered(value: &str) -> Option<Self> {
+        Spacing::try_from(value).ok()
+    }
+
+    fn as_spacing(&self) -> Option<Spacing> {
+        Some(*self)
+    }
+}
+
+/// Attributes of an element parsed once for its renderer, which reads them
+/// instead of parsing the values of the [`Map`] again. Only the attributes of
+/// the element itself are there, the ones coming from the head being parsed
+/// when read.
+#[cfg(feature = "render")]
+pub trait ParsedAttributes {
+    fn pixel(&self, name: &str) -> Option<Pixel>;
+    fn size(&self, name: &str) -> Option<Size>;
+    fn spacing(&self, name: &str) -> Option<Spacing>;
+}
+
+/// A css color, like `#ff0000`, `rgb(255, 0, 0)` or `red`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Color(String);
+
+impl Color {
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+fn is_valid_color(value: &str) -> bool {
+    if let Some(hex) = value.strip_prefix('#') {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some((function, _)) = value.split_once('(') {
+        matches!(function, "rgb" | "rgba" | "hsl" | "hsla") && value.ends_with(')')
+    } else {
+        !value.is_empty() && value.chars().all(|c| c.is_ascii_alphabetic())
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if is_valid_color(value) {
+            Ok(Self(value.to_string()))
+        } else {
+            Err("invalid color".into())
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "render")]
+impl RenderValue for Color {}
+
+impl FromAttribute for Color {
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Self::try_from(value)
+    }
+}
+
+/// Generates an enum of the keywords accepted by an attribute.
+macro_rules! keyword {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value),*
+                }
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = String;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                match value {
+                    $($value => Ok(Self::$variant),)*
+                    _ => Err(format!(
+                        "expected one of {}",
+                        [$($value),*].join(", ")
+                    )),
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromAttribute for $name {
+            fn from_attribute(value: &str) -> Result<Self, String> {
+                Self::try_from(value)
+            }
+        }
+
+        #[cfg(feature = "render")]
+        impl RenderValue for $name {}
+    };
+}
+
+keyword!(
+    /// Horizontal alignment, the `align` attribute.
+    Align {
+        Left => "left",
+        Center => "center",
+        Right => "right",
+        Justify => "justify",
+    }
+);
+
+keyword!(
+    /// Vertical alignment, the `vertical-align` attribute.
+    VerticalAlign {
+        Top => "top",
+        Middle => "middle",
+        Bottom => "bottom",
+    }
+);
+
+keyword!(
+    /// Direction of the columns, the `direction` attribute.
+    Direction {
+        Ltr => "ltr",
+        Rtl => "rtl",
+    }
+);
+
+keyword!(
+    /// Repetition of the background image, the `background-repeat` attribute.
+    BackgroundRepeat {
+        Repeat => "repeat",
+        NoRepeat => "no-repeat",
+    }
+);
+
+/// The typed values are serialized the way they are written in the
+/// templates.
+#[cfg(feature = "json")]
+macro_rules! string_serde {
+    ($($name:ty),*) => {
+        $(
+            impl serde::Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = String::deserialize(deserializer)?;
+                    <$name as FromAttribute>::from_attribute(&value).map_err(serde::de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "json")]
+string_serde!(
+    Align,
+    BackgroundRepeat,
+    Color,
+    Direction,
+    Pixel,
+    Size,
+    Spacing,
+    VerticalAlign
+);
+
+fn parse_field<T: FromAttribute>(name: &str, value: &str) -> Result<T, AttributeError> {
+    T::from_attribute(value).map_err(|reason| AttributeError {
+        name: name.to_string(),
+        value: value.to_string(),
+        reason,
+    })
+}
+
+/// Generates the typed attributes of a component, with the conversions from
+/// and to its [`Map`] of attributes.
+macro_rules! typed_attributes {
+    (
+        $(#[$attr:meta])*
+        $name:ident for $component:ty {
+            $($field:ident: $kind:ty => $key:literal),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "json", serde(rename_all = "kebab-case"))]
+        pub struct $name {
+            $(
+                #[cfg_attr(feature = "json", serde(default, skip_serializing_if = "Option::is_none"))]
+                pub $field: Option<$kind>,
+            )*
+            /// The attributes without a typed field, kept as is.
+            #[cfg_attr(feature = "json", serde(flatten))]
+            pub others: Map<String, String>,
+        }
+
+        impl $name {
+            /// Checks the value of an attribute, the ones without a typed field
+            /// are always valid.
+            pub fn validate_attribute(name: &str, value: &str) -> Result<(), AttributeError> {
+                match name {
+                    $($key => parse_field::<$kind>(name, value).map(|_| ()),)*
+                    _ => Ok(()),
+                }
+            }
+        }
+
+        impl TryFrom<&Map<String, String>> for $name {
+            type Error = AttributeError;
+
+            fn try_from(attributes: &Map<String, String>) -> Result<Self, Self::Error> {
+                let mut result = Self::default();
+                for (name, value) in attributes.iter() {
+                    match name.as_str() {
+                        $($key => result.$field = Some(parse_field(name, value)?),)*
+                        _ => {
+                            result.others.insert(name.clone(), value.clone());
+                        }
+                    }
+                }
+                Ok(result)
+            }
+        }
+
+        /// The typed fields come first, in the order of the structure, then
+        /// the other attributes.
+        impl From<&$name> for Map<String, String> {
+            fn from(attributes: &$name) -> Self {
+                let mut result = Map::new();
+                $(
+                    if let Some(value) = attributes.$field.as_ref() {
+                        result.insert($key.to_string(), value.to_string());
+                    }
+                )*
+                for (name, value) in attributes.others.iter() {
+                    result.insert(name.clone(), value.clone());
+                }
+                result
+            }
+        }
+
+        impl $component {
+            /// Parses the attributes of the element into their typed
+            /// representation.
+            pub fn typed_attributes(&self) -> Result<$name, AttributeError> {
+                $name::try_from(&self.attributes)
+            }
+
+            /// Updates the attributes of the element, which keep their order,
+            /// the new ones coming last. The values equivalent to the current
+            /// ones are kept the way they're written.
+            pub fn set_typed_attributes(&mut self, attributes: &$name) {
+                $(
+                    match attributes.$field.as_ref() {
+                        Some(value) => {
+                            let current = self
+                                .attributes
+                                .get($key)
+                                .and_then(|current| <$kind>::from_attribute(current).ok());
+                            if current.as_ref() != Some(value) {
+                                self.attributes.insert($key.to_string(), value.to_string());
+                            }
+                        }
+                        None => {
+                            self.attributes.remove($key);
+                        }
+                    }
+                )*
+                self.attributes.retain(|name, _| {
+                    matches!(name.as_str(), $($key)|*) || attributes.others.contains_key(name)
+                });
+                for (name, value) in attributes.others.iter() {
+                    self.attributes.insert(name.clone(), value.clone());
+                }
+            }
+        }
+
+        #[cfg(feature = "render")]
+        impl $name {
+            /// Parses the sizes and spacings of the element for its renderer,
+            /// the values it can't read being left for the [`Map`].
+            pub(crate) fn for_render(attributes: &Map<String, String>) -> Self {
+                Self {
+                    $(
+                        $field: attributes
+                            .get($key)
+                            .and_then(|value| <$kind as RenderValue>::parse_rendered(value)),
+                    )*
+                    others: Map::new(),
+                }
+            }
+        }
+
+        #[cfg(feature = "render")]
+        impl ParsedAttributes for $name {
+            fn pixel(&self, name: &str) -> Option<Pixel> {
+                match name {
+                    $($key => self.$field.as_ref().and_then(RenderValue::as_pixel),)*
+                    _ => None,
+                }
+            }
+
+            fn size(&self, name: &str) -> Option<Size> {
+                match name {
+                    $($key => self.$field.as_ref().and_then(RenderValue::as_size),)*
+                    _ => None,
+                }
+            }
+
+            fn spacing(&self, name: &str) -> Option<Spacing> {
+                match name {
+                    $($key => self.$field.as_ref().and_then(RenderValue::as_spacing),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+typed_attributes!(
+    /// Typed attributes of the [`MjButton`](crate::mj_button::MjButton).
+    MjButtonAttributes for crate::mj_button::MjButton {
+        align: Align => "align",
+        background_color: Color => "background-color",
+        color: Color => "color",
+        container_background_color: Color => "container-background-color",
+        font_family: String => "font-family",
+        font_size: Pixel => "font-size",
+        height: Size => "height",
+        href: String => "href",
+        inner_padding: Spacing => "inner-padding",
+        line_height: Size => "line-height",
+        padding: Spacing => "padding",
+        padding_bottom: Pixel => "padding-bottom",
+        padding_left: Pixel => "padding-left",
+        padding_right: Pixel => "padding-right",
+        padding_top: Pixel => "padding-top",
+        vertical_align: VerticalAlign => "vertical-align",
+        width: Size => "width",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjColumn`](crate::mj_column::MjColumn).
+    MjColumnAttributes for crate::mj_column::MjColumn {
+        background_color: Color => "background-color",
+        direction: Direction => "direction",
+        inner_background_color: Color => "inner-background-color",
+        padding: Spacing => "padding",
+        padding_bottom: Pixel => "padding-bottom",
+        padding_left: Pixel => "padding-left",
+        padding_right: Pixel => "padding-right",
+        padding_top: Pixel => "padding-top",
+        vertical_align: VerticalAlign => "vertical-align",
+        width: Size => "width",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjDivider`](crate::mj_divider::MjDivider).
+    MjDividerAttributes for crate::mj_divider::MjDivider {
+        align: Align => "align",
+        border_color: Color => "border-color",
+        border_width: Pixel => "border-width",
+        container_background_color: Color => "container-background-color",
+        padding: Spacing => "padding",
+        width: Size => "width",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjImage`](crate::mj_image::MjImage).
+    MjImageAttributes for crate::mj_image::MjImage {
+        align: Align => "align",
+        alt: String => "alt",
+        container_background_color: Color => "container-background-color",
+        fluid_on_mobile: bool => "fluid-on-mobile",
+        href: String => "href",
+        padding: Spacing => "padding",
+        src: String => "src",
+        width: Pixel => "width",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjSection`](crate::mj_section::MjSection).
+    MjSectionAttributes for crate::mj_section::MjSection {
+        background_color: Color => "background-color",
+        background_repeat: BackgroundRepeat => "background-repeat",
+        background_url: String => "background-url",
+        direction: Direction => "direction",
+        full_width: String => "full-width",
+        padding: Spacing => "padding",
+        padding_bottom: Pixel => "padding-bottom",
+        padding_left: Pixel => "padding-left",
+        padding_right: Pixel => "padding-right",
+        padding_top: Pixel => "padding-top",
+        text_align: Align => "text-align",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjSpacer`](crate::mj_spacer::MjSpacer).
+    MjSpacerAttributes for crate::mj_spacer::MjSpacer {
+        container_background_color: Color => "container-background-color",
+        height: Pixel => "height",
+        padding: Spacing => "padding",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjText`](crate::mj_text::MjText).
+    MjTextAttributes for crate::mj_text::MjText {
+        align: Align => "align",
+        color: Color => "color",
+        container_background_color: Color => "container-background-color",
+        font_family: String => "font-family",
+        font_size: Pixel => "font-size",
+        height: Size => "height",
+        line_height: Size => "line-height",
+        padding: Spacing => "padding",
+        padding_bottom: Pixel => "padding-bottom",
+        padding_left: Pixel => "padding-left",
+        padding_right: Pixel => "padding-right",
+        padding_top: Pixel => "padding-top",
+    }
+);
+
+typed_attributes!(
+    /// Typed attributes of the [`MjWrapper`](crate::mj_wrapper::MjWrapper).
+    MjWrapperAttributes for crate::mj_wrapper::MjWrapper {
+        background_color: Color => "background-color",
+        background_repeat: BackgroundRepeat => "background-repeat",
+        background_url: String => "background-url",
+        full_width: String => "full-width",
+        padding: Spacing => "padding",
+        padding_bottom: Pixel => "padding-bottom",
+        padding_left: Pixel => "padding-left",
+        padding_right: Pixel => "padding-right",
+        padding_top: Pixel => "padding-top",
+        text_align: Align => "text-align",
+    }
+);
+
+/// Checks the value of an attribute of the element with the given tag, the
+/// elements without typed attributes accept any value.
+pub fn validate_attribute(tag: &str, name: &str, value: &str) -> Result<(), AttributeError> {
+    match tag {
+        crate::mj_button::NAME => MjButtonAttributes::validate_attribute(name, value),
+        crate::mj_column::NAME => MjColumnAttributes::validate_attribute(name, value),
+        crate::mj_divider::NAME => MjDividerAttributes::validate_attribute(name, value),
+        crate::mj_image::NAME => MjImageAttributes::validate_attribute(name, value),
+        crate::mj_section::NAME => MjSectionAttributes::validate_attribute(name, value),
+        crate::mj_spacer::NAME => MjSpacerAttributes::validate_attribute(name, value),
+        crate::mj_text::NAME => MjTextAttributes::validate_attribute(name, value),
+        crate::mj_wrapper::NAME => MjWrapperAttributes::validate_attribute(name, value),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    fn map(items: &[(&str, &str)]) -> Map<String, String> {
+        items
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_typed_attributes() {
+        let attributes = map(&[
+            ("padding", "10px 0"),
+            ("background-color", "#f0f0f0"),
+            ("direction", "rtl"),
+            ("css-class", "hero"),
+        ]);
+        let typed = MjSectionAttributes::try_from(&attributes).unwrap();
+        assert_eq!(typed.padding.as_ref().unwrap().left(), &Pixel::new(0.0));
+        assert_eq!(typed.background_color.as_ref().unwrap().as_str(), "#f0f0f0");
+        assert_eq!(typed.direction, Some(Direction::Rtl));
+        assert_eq!(typed.others.get("css-class").unwrap(), "hero");
+
+        let back = Map::from(&typed);
+        assert_eq!(back.get("padding").unwrap(), "10px 0px");
+        assert_eq!(back.get("direction").unwrap(), "rtl");
+        assert_eq!(back.get("css-class").unwrap(), "hero");
+    }
+
+    #[test]
+    fn should_keep_the_order_of_the_attributes() {
+        let attributes = map(&[
+            ("css-class", "hero"),
+            ("padding", "0"),
+            ("direction", "rtl"),
+            ("background-color", "#f0f0f0"),
+        ]);
+        let mut section = crate::mj_section::MjSection::new(attributes.clone(), Vec::new());
+        let mut typed = section.typed_attributes().unwrap();
+        section.set_typed_attributes(&typed);
+        assert_eq!(section.attributes, attributes);
+
+        typed.direction = None;
+        typed.text_align = Some(Align::Center);
+        typed.background_color = Some(Color::try_from("#000000").unwrap());
+        section.set_typed_attributes(&typed);
+        assert_eq!(
+            section.attributes,
+            map(&[
+                ("css-class", "hero"),
+                ("padding", "0"),
+                ("background-color", "#000000"),
+                ("text-align", "center"),
+            ])
+        );
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_parse_for_render_like_the_renderers() {
+        let attributes = map(&[("padding", "0"), ("width", "50%"), ("height", "{{ h }}px")]);
+        let parsed = MjButtonAttributes::for_render(&attributes);
+        assert!(parsed.spacing("padding").is_none());
+        assert!(parsed.size("width").is_some());
+        assert!(parsed.pixel("width").is_none());
+        assert!(parsed.size("height").is_none());
+    }
+
+    #[test]
+    fn should_reject_invalid_values() {
+        let err = MjButtonAttributes::try_from(&map(&[("font-size", "big"), ("color", "red")]))
+            .unwrap_err();
+        assert_eq!(err.name, "font-size");
+        assert_eq!(err.value, "big");
+
+        assert!(validate_attribute("mj-button", "color", "#12345").is_err());
+        assert!(validate_attribute("mj-button", "color", "rgba(0, 0, 0, 0.5)").is_ok());
+        assert!(validate_attribute("mj-column", "vertical-align", "center").is_err());
+        assert!(validate_attribute("mj-social", "align", "anywhere").is_ok());
+        assert!(validate_attribute("mj-text", "unknown", "anything").is_ok());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_validate_when_parsing() {
+        let options = crate::prelude::parser::ParserOptions {
+            strict_attributes: true,
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-section padding="0"><mj-column><mj-button font-size="large">Go</mj-button></mj-column></mj-section></mj-body></mjml>"#;
+        assert!(crate::parse(template).is_ok());
+        let Err(err) = crate::parse_with_options(template, &options) else {
+            panic!("the font size should be rejected");
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid value for attribute \"font-size\" in root template at position 61:78"
+        );
+        assert!(crate::parse_with_options(template.replace("large", "18px"), &options).is_ok());
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_not_validate_placeholders_when_parsing() {
+        let options = crate::prelude::parser::ParserOptions {
+            strict_attributes: true,
+            placeholders: vec![crate::prelude::parser::Delimiters::new("{{", "}}")],
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-text font-size="{{ size }}px" color="{{ color }}" align="middle">Hello</mj-text></mj-body></mjml>"#;
+        let Err(err) = crate::parse_with_options(template, &options) else {
+            panic!("the alignment should be rejected");
+        };
+        assert!(err.to_string().contains("\"align\""));
+        assert!(crate::parse_with_options(template.replace("middle", "left"), &options).is_ok());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn should_serialize_as_strings() {
+        let typed =
+            MjImageAttributes::try_from(&map(&[("width", "300px"), ("fluid-on-mobile", "true")]))
+                .unwrap();
+        let json = serde_json::to_string(&typed).unwrap();
+        assert_eq!(json, r#"{"fluid-on-mobile":true,"width":"300px"}"#);
+        let back: MjImageAttributes = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, typed);
+        assert!(serde_json::from_str::<MjImageAttributes>(r#"{"align":"middle"}"#).is_err());
+    }
+}
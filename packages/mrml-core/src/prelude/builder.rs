@@ -0,0 +1,969 @@
+//! Typed builders to construct the templates in Rust, without writing the
+//! markup or the json representation.
+//!
+//! Each builder only exposes the children its component accepts, and the
+//! attributes it supports through dedicated setters. Any other attribute can
+//! still be set with [`ComponentBuilder::attribute`].
+//!
+//! ```rust
+//! use mrml::mjml::Mjml;
+//!
+//! let template = Mjml::builder()
+//!     .lang("en")
+//!     .head(|head| head.title("Welcome").preview("Your account is ready"))
+//!     .body(|body| {
+//!         body.width("600px").section(|section| {
+//!             section.background_color("#f0f0f0").column(|column| {
+//!                 column
+//!                     .text(|text| text.color("#333333").content("Hello <b>World</b>"))
+//!                     .button(|button| button.href("https://example.com").content("Go"))
+//!             })
+//!         })
+//!     })
+//!     .build();
+//! assert_eq!(template.head().unwrap().children.len(), 2);
+//! assert_eq!(template.body().unwrap().children.len(), 1);
+//! ```
+
+use std::marker::PhantomData;
+
+use super::hash::Map;
+use super::Component;
+use crate::comment::Comment;
+use crate::mj_accordion::{MjAccordionChild, MjAccordionTag};
+use crate::mj_accordion_element::{MjAccordionElementChildren, MjAccordionElementTag};
+use crate::mj_accordion_text::MjAccordionTextTag;
+use crate::mj_accordion_title::MjAccordionTitleTag;
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::{MjAttributesClass, MjAttributesClassAttributes};
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBodyChild, MjBodyTag};
+use crate::mj_breakpoint::{MjBreakpoint, MjBreakpointAttributes};
+use crate::mj_button::MjButtonTag;
+use crate::mj_carousel::{MjCarouselChild, MjCarouselTag};
+use crate::mj_carousel_image::MjCarouselImageTag;
+use crate::mj_column::MjColumnTag;
+use crate::mj_divider::MjDividerTag;
+use crate::mj_font::{MjFont, MjFontAttributes};
+use crate::mj_group::MjGroupTag;
+use crate::mj_head::{MjHead, MjHeadChild};
+use crate::mj_hero::MjHeroTag;
+use crate::mj_image::MjImageTag;
+use crate::mj_list::{MjListChild, MjListTag};
+use crate::mj_list_item::MjListItemTag;
+use crate::mj_navbar::{MjNavbarChild, MjNavbarTag};
+use crate::mj_navbar_link::MjNavbarLinkTag;
+use crate::mj_preview::MjPreview;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_section::MjSectionTag;
+use crate::mj_social::{MjSocialChild, MjSocialTag};
+use crate::mj_social_element::MjSocialElementTag;
+use crate::mj_spacer::MjSpacerTag;
+use crate::mj_style::{MjStyle, MjStyleAttributes};
+use crate::mj_table::MjTableTag;
+use crate::mj_text::MjTextTag;
+use crate::mj_title::MjTitle;
+use crate::mj_video::MjVideoTag;
+use crate::mj_wrapper::MjWrapperTag;
+use crate::mjml::{Mjml, MjmlAttributes, MjmlChildren};
+use crate::text::Text;
+
+/// Builder of the `mjml` root element.
+#[derive(Debug, Default)]
+pub struct MjmlBuilder {
+    attributes: MjmlAttributes,
+    children: MjmlChildren,
+}
+
+impl MjmlBuilder {
+    pub fn lang(mut self, value: impl Into<String>) -> Self {
+        self.attributes.lang = Some(value.into());
+        self
+    }
+
+    pub fn dir(mut self, value: impl Into<String>) -> Self {
+        self.attributes.dir = Some(value.into());
+        self
+    }
+
+    pub fn owa(mut self, value: impl Into<String>) -> Self {
+        self.attributes.owa = Some(value.into());
+        self
+    }
+
+    pub fn head<F: FnOnce(MjHeadBuilder) -> MjHeadBuilder>(mut self, build: F) -> Self {
+        self.children.head = Some(build(MjHeadBuilder::default()).build());
+        self
+    }
+
+    pub fn body<F: FnOnce(MjBodyBuilder) -> MjBodyBuilder>(mut self, build: F) -> Self {
+        self.children.body = Some(build(MjBodyBuilder::default()).build());
+        self
+    }
+
+    pub fn build(self) -> Mjml {
+        Mjml::new(self.attributes, self.children)
+    }
+}
+
+impl Mjml {
+    pub fn builder() -> MjmlBuilder {
+        MjmlBuilder::default()
+    }
+}
+
+/// Builder of the `mj-head` element.
+#[derive(Debug, Default)]
+pub struct MjHeadBuilder {
+    children: Vec<MjHeadChild>,
+}
+
+impl MjHeadBuilder {
+    pub fn title(mut self, value: impl Into<String>) -> Self {
+        self.children
+            .push(MjHeadChild::MjTitle(MjTitle::new((), value.into())));
+        self
+    }
+
+    pub fn preview(mut self, value: impl Into<String>) -> Self {
+        self.children
+            .push(MjHeadChild::MjPreview(MjPreview::new((), value.into())));
+        self
+    }
+
+    pub fn breakpoint(mut self, width: impl Into<String>) -> Self {
+        self.children
+            .push(MjHeadChild::MjBreakpoint(MjBreakpoint::new(
+                MjBreakpointAttributes {
+                    width: width.into(),
+                },
+                (),
+            )));
+        self
+    }
+
+    pub fn font(mut self, name: impl Into<String>, href: impl Into<String>) -> Self {
+        self.children.push(MjHeadChild::MjFont(MjFont::new(
+            MjFontAttributes {
+                name: name.into(),
+                href: href.into(),
+            },
+            (),
+        )));
+        self
+    }
+
+    pub fn style(mut self, css: impl Into<String>) -> Self {
+        self.children.push(MjHeadChild::MjStyle(MjStyle::new(
+            MjStyleAttributes::default(),
+            css.into(),
+        )));
+        self
+    }
+
+    /// Adds a style that will be inlined in the html elements.
+    pub fn inline_style(mut self, css: impl Into<String>) -> Self {
+        self.children.push(MjHeadChild::MjStyle(MjStyle::new(
+            MjStyleAttributes {
+                inline: Some("inline".into()),
+            },
+            css.into(),
+        )));
+        self
+    }
+
+    pub fn attributes<F: FnOnce(MjAttributesBuilder) -> MjAttributesBuilder>(
+        mut self,
+        build: F,
+    ) -> Self {
+        let children = build(MjAttributesBuilder::default()).children;
+        self.children
+            .push(MjHeadChild::MjAttributes(MjAttributes::new((), children)));
+        self
+    }
+
+    pub fn raw(mut self, html: impl Into<String>) -> Self {
+        self.children.push(MjHeadChild::MjRaw(raw(html)));
+        self
+    }
+
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.children
+            .push(MjHeadChild::Comment(Comment::from(value)));
+        self
+    }
+
+    pub fn build(self) -> MjHead {
+        MjHead::new((), self.children)
+    }
+}
+
+/// Builder of the `mj-attributes` element, defining the default attributes
+/// of the template.
+#[derive(Debug, Default)]
+pub struct MjAttributesBuilder {
+    children: Vec<MjAttributesChild>,
+}
+
+fn attribute_map<I, K, V>(attributes: I) -> Map<String, String>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    attributes
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect()
+}
+
+impl MjAttributesBuilder {
+    /// Attributes applied to all the elements, with `mj-all`.
+    pub fn all<I, K, V>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.children
+            .push(MjAttributesChild::MjAttributesAll(MjAttributesAll::new(
+                attribute_map(attributes),
+                (),
+            )));
+        self
+    }
+
+    /// Attributes applied to the elements using the given `mj-class`.
+    pub fn class<I, K, V>(mut self, name: impl Into<String>, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.children.push(MjAttributesChild::MjAttributesClass(
+            MjAttributesClass::new(
+                MjAttributesClassAttributes {
+                    name: name.into(),
+                    others: attribute_map(attributes),
+                },
+                Vec::new(),
+            ),
+        ));
+        self
+    }
+
+    /// Attributes applied to all the elements with the given tag, like
+    /// `mj-button`.
+    pub fn element<I, K, V>(mut self, tag: impl Into<String>, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.children.push(MjAttributesChild::MjAttributesElement(
+            MjAttributesElement {
+                name: tag.into(),
+                attributes: attribute_map(attributes),
+            },
+        ));
+        self
+    }
+}
+
+fn raw(html: impl Into<String>) -> MjRaw {
+    MjRaw::new(
+        Default::default(),
+        vec![MjRawChild::Text(Text::from(html.into()))],
+    )
+}
+
+/// Builder of the components storing their attributes in a [`Map`].
+#[derive(Debug)]
+pub struct ComponentBuilder<Tag, Children> {
+    attributes: Map<String, String>,
+    children: Children,
+    tag: PhantomData<Tag>,
+}
+
+impl<T, C: Default> Default for ComponentBuilder<T, C> {
+    fn default() -> Self {
+        Self {
+            attributes: Map::new(),
+            children: C::default(),
+            tag: PhantomData,
+        }
+    }
+}
+
+impl<T, C> ComponentBuilder<T, C> {
+    /// Sets any attribute, including the ones without a dedicated setter.
+    pub fn attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn css_class(self, value: impl Into<String>) -> Self {
+        self.attribute("css-class", value)
+    }
+
+    pub fn mj_class(self, value: impl Into<String>) -> Self {
+        self.attribute("mj-class", value)
+    }
+
+    pub fn build(self) -> Component<PhantomData<T>, Map<String, String>, C> {
+        Component::new(self.attributes, self.children)
+    }
+}
+
+impl<T, C: Default> Component<PhantomData<T>, Map<String, String>, C> {
+    pub fn builder() -> ComponentBuilder<T, C> {
+        ComponentBuilder::default()
+    }
+}
+
+impl<T> ComponentBuilder<T, Vec<MjBodyChild>> {
+    pub fn child(mut self, child: MjBodyChild) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn comment(self, value: impl Into<String>) -> Self {
+        self.child(MjBodyChild::Comment(Comment::from(value)))
+    }
+}
+
+pub type MjBodyBuilder = ComponentBuilder<MjBodyTag, Vec<MjBodyChild>>;
+pub type MjWrapperBuilder = ComponentBuilder<MjWrapperTag, Vec<MjBodyChild>>;
+pub type MjSectionBuilder = ComponentBuilder<MjSectionTag, Vec<MjBodyChild>>;
+pub type MjGroupBuilder = ComponentBuilder<MjGroupTag, Vec<MjBodyChild>>;
+pub type MjColumnBuilder = ComponentBuilder<MjColumnTag, Vec<MjBodyChild>>;
+pub type MjHeroBuilder = ComponentBuilder<MjHeroTag, Vec<MjBodyChild>>;
+pub type MjTextBuilder = ComponentBuilder<MjTextTag, Vec<MjBodyChild>>;
+pub type MjButtonBuilder = ComponentBuilder<MjButtonTag, Vec<MjBodyChild>>;
+pub type MjTableBuilder = ComponentBuilder<MjTableTag, Vec<MjBodyChild>>;
+pub type MjImageBuilder = ComponentBuilder<MjImageTag, ()>;
+pub type MjDividerBuilder = ComponentBuilder<MjDividerTag, ()>;
+pub type MjSpacerBuilder = ComponentBuilder<MjSpacerTag, ()>;
+pub type MjVideoBuilder = ComponentBuilder<MjVideoTag, ()>;
+pub type MjAccordionBuilder = ComponentBuilder<MjAccordionTag, Vec<MjAccordionChild>>;
+pub type MjAccordionElementBuilder =
+    ComponentBuilder<MjAccordionElementTag, MjAccordionElementChildren>;
+pub type MjAccordionTitleBuilder = ComponentBuilder<MjAccordionTitleTag, Vec<Text>>;
+pub type MjAccordionTextBuilder = ComponentBuilder<MjAccordionTextTag, Vec<MjRawChild>>;
+pub type MjCarouselBuilder = ComponentBuilder<MjCarouselTag, Vec<MjCarouselChild>>;
+pub type MjCarouselImageBuilder = ComponentBuilder<MjCarouselImageTag, ()>;
+pub type MjListBuilder = ComponentBuilder<MjListTag, Vec<MjListChild>>;
+pub type MjListItemBuilder = ComponentBuilder<MjListItemTag, Vec<MjRawChild>>;
+pub type MjNavbarBuilder = ComponentBuilder<MjNavbarTag, Vec<MjNavbarChild>>;
+pub type MjNavbarLinkBuilder = ComponentBuilder<MjNavbarLinkTag, Vec<MjRawChild>>;
+pub type MjSocialBuilder = ComponentBuilder<MjSocialTag, Vec<MjSocialChild>>;
+pub type MjSocialElementBuilder = ComponentBuilder<MjSocialElementTag, Vec<MjRawChild>>;
+
+/// Generates the setters of the attributes supported by a component.
+macro_rules! attributes {
+    ($builder:ty { $($setter:ident => $name:literal),* $(,)? }) => {
+        impl $builder {
+            $(
+                #[doc = concat!("Sets the `", $name, "` attribute.")]
+                pub fn $setter(self, value: impl Into<String>) -> Self {
+                    self.attribute($name, value)
+                }
+            )*
+        }
+    };
+}
+
+/// Generates the methods adding the children accepted by a component.
+macro_rules! children {
+    ($builder:ty { $($method:ident($child:ty) => $variant:path),* $(,)? }) => {
+        impl $builder {
+            $(
+                pub fn $method<F: FnOnce($child) -> $child>(mut self, build: F) -> Self {
+                    self.children.push($variant(build(<$child>::default()).build()));
+                    self
+                }
+            )*
+        }
+    };
+}
+
+/// Generates the method adding some html content to a component.
+macro_rules! content {
+    ($($builder:ty => $wrap:path),* $(,)?) => {
+        $(
+            impl $builder {
+                /// Adds some content, the html is kept as is.
+                pub fn content(mut self, html: impl Into<String>) -> Self {
+                    self.children.push($wrap(Text::from(html.into())));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+/// Generates the method adding a `mj-raw` element to a component.
+macro_rules! raw {
+    ($($builder:ty),* $(,)?) => {
+        $(
+            impl $builder {
+                /// Adds a `mj-raw` element, the html is kept as is.
+                pub fn raw(mut self, html: impl Into<String>) -> Self {
+                    self.children.push(MjBodyChild::MjRaw(raw(html)));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+children!(MjBodyBuilder {
+    section(MjSectionBuilder) => MjBodyChild::MjSection,
+    wrapper(MjWrapperBuilder) => MjBodyChild::MjWrapper,
+    hero(MjHeroBuilder) => MjBodyChild::MjHero,
+});
+
+children!(MjWrapperBuilder {
+    section(MjSectionBuilder) => MjBodyChild::MjSection,
+    hero(MjHeroBuilder) => MjBodyChild::MjHero,
+});
+
+children!(MjSectionBuilder {
+    column(MjColumnBuilder) => MjBodyChild::MjColumn,
+    group(MjGroupBuilder) => MjBodyChild::MjGroup,
+});
+
+children!(MjGroupBuilder {
+    column(MjColumnBuilder) => MjBodyChild::MjColumn,
+});
+
+children!(MjColumnBuilder {
+    accordion(MjAccordionBuilder) => MjBodyChild::MjAccordion,
+    button(MjButtonBuilder) => MjBodyChild::MjButton,
+    carousel(MjCarouselBuilder) => MjBodyChild::MjCarousel,
+    divider(MjDividerBuilder) => MjBodyChild::MjDivider,
+    image(MjImageBuilder) => MjBodyChild::MjImage,
+    list(MjListBuilder) => MjBodyChild::MjList,
+    navbar(MjNavbarBuilder) => MjBodyChild::MjNavbar,
+    social(MjSocialBuilder) => MjBodyChild::MjSocial,
+    spacer(MjSpacerBuilder) => MjBodyChild::MjSpacer,
+    table(MjTableBuilder) => MjBodyChild::MjTable,
+    text(MjTextBuilder) => MjBodyChild::MjText,
+    video(MjVideoBuilder) => MjBodyChild::MjVideo,
+});
+
+children!(MjHeroBuilder {
+    accordion(MjAccordionBuilder) => MjBodyChild::MjAccordion,
+    button(MjButtonBuilder) => MjBodyChild::MjButton,
+    carousel(MjCarouselBuilder) => MjBodyChild::MjCarousel,
+    divider(MjDividerBuilder) => MjBodyChild::MjDivider,
+    image(MjImageBuilder) => MjBodyChild::MjImage,
+    list(MjListBuilder) => MjBodyChild::MjList,
+    navbar(MjNavbarBuilder) => MjBodyChild::MjNavbar,
+    social(MjSocialBuilder) => MjBodyChild::MjSocial,
+    spacer(MjSpacerBuilder) => MjBodyChild::MjSpacer,
+    table(MjTableBuilder) => MjBodyChild::MjTable,
+    text(MjTextBuilder) => MjBodyChild::MjText,
+    video(MjVideoBuilder) => MjBodyChild::MjVideo,
+});
+
+children!(MjAccordionBuilder {
+    element(MjAccordionElementBuilder) => MjAccordionChild::MjAccordionElement,
+});
+
+children!(MjCarouselBuilder {
+    image(MjCarouselImageBuilder) => MjCarouselChild::MjCarouselImage,
+});
+
+children!(MjListBuilder {
+    item(MjListItemBuilder) => MjListChild::MjListItem,
+});
+
+children!(MjNavbarBuilder {
+    link(MjNavbarLinkBuilder) => MjNavbarChild::MjNavbarLink,
+});
+
+children!(MjSocialBuilder {
+    element(MjSocialElementBuilder) => MjSocialChild::MjSocialElement,
+});
+
+impl MjAccordionElementBuilder {
+    pub fn title<F: FnOnce(MjAccordionTitleBuilder) -> MjAccordionTitleBuilder>(
+        mut self,
+        build: F,
+    ) -> Self {
+        self.children.title = Some(build(MjAccordionTitleBuilder::default()).build());
+        self
+    }
+
+    pub fn text<F: FnOnce(MjAccordionTextBuilder) -> MjAccordionTextBuilder>(
+        mut self,
+        build: F,
+    ) -> Self {
+        self.children.text = Some(build(MjAccordionTextBuilder::default()).build());
+        self
+    }
+}
+
+raw!(
+    MjBodyBuilder,
+    MjWrapperBuilder,
+    MjSectionBuilder,
+    MjGroupBuilder,
+    MjColumnBuilder,
+    MjHeroBuilder,
+);
+
+content!(
+    MjTextBuilder => MjBodyChild::Text,
+    MjButtonBuilder => MjBodyChild::Text,
+    MjTableBuilder => MjBodyChild::Text,
+    MjAccordionTitleBuilder => std::convert::identity,
+    MjAccordionTextBuilder => MjRawChild::Text,
+    MjListItemBuilder => MjRawChild::Text,
+    MjNavbarLinkBuilder => MjRawChild::Text,
+    MjSocialElementBuilder => MjRawChild::Text,
+);
+
+attributes!(MjBodyBuilder {
+    background_color => "background-color",
+    width => "width",
+});
+
+attributes!(MjWrapperBuilder {
+    background_color => "background-color",
+    background_position => "background-position",
+    background_repeat => "background-repeat",
+    background_size => "background-size",
+    background_url => "background-url",
+    border => "border",
+    border_bottom => "border-bottom",
+    border_left => "border-left",
+    border_radius => "border-radius",
+    border_right => "border-right",
+    border_top => "border-top",
+    full_width => "full-width",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    text_align => "text-align",
+});
+
+attributes!(MjSectionBuilder {
+    background_color => "background-color",
+    background_position => "background-position",
+    background_repeat => "background-repeat",
+    background_size => "background-size",
+    background_url => "background-url",
+    border => "border",
+    border_bottom => "border-bottom",
+    border_left => "border-left",
+    border_radius => "border-radius",
+    border_right => "border-right",
+    border_top => "border-top",
+    direction => "direction",
+    full_width => "full-width",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    text_align => "text-align",
+});
+
+attributes!(MjGroupBuilder {
+    background_color => "background-color",
+    direction => "direction",
+    vertical_align => "vertical-align",
+    width => "width",
+});
+
+attributes!(MjColumnBuilder {
+    background_color => "background-color",
+    border => "border",
+    border_bottom => "border-bottom",
+    border_left => "border-left",
+    border_radius => "border-radius",
+    border_right => "border-right",
+    border_top => "border-top",
+    direction => "direction",
+    inner_background_color => "inner-background-color",
+    inner_border => "inner-border",
+    inner_border_radius => "inner-border-radius",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    vertical_align => "vertical-align",
+    width => "width",
+});
+
+attributes!(MjHeroBuilder {
+    background_color => "background-color",
+    background_height => "background-height",
+    background_position => "background-position",
+    background_url => "background-url",
+    background_width => "background-width",
+    border_radius => "border-radius",
+    container_background_color => "container-background-color",
+    height => "height",
+    inner_background_color => "inner-background-color",
+    inner_padding => "inner-padding",
+    mode => "mode",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    vertical_align => "vertical-align",
+});
+
+attributes!(MjTextBuilder {
+    align => "align",
+    color => "color",
+    container_background_color => "container-background-color",
+    font_family => "font-family",
+    font_size => "font-size",
+    font_style => "font-style",
+    font_weight => "font-weight",
+    height => "height",
+    letter_spacing => "letter-spacing",
+    line_height => "line-height",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    text_decoration => "text-decoration",
+    text_transform => "text-transform",
+});
+
+attributes!(MjButtonBuilder {
+    align => "align",
+    background_color => "background-color",
+    border => "border",
+    border_radius => "border-radius",
+    color => "color",
+    container_background_color => "container-background-color",
+    font_family => "font-family",
+    font_size => "font-size",
+    font_style => "font-style",
+    font_weight => "font-weight",
+    height => "height",
+    href => "href",
+    inner_padding => "inner-padding",
+    letter_spacing => "letter-spacing",
+    line_height => "line-height",
+    padding => "padding",
+    padding_bottom => "padding-bottom",
+    padding_left => "padding-left",
+    padding_right => "padding-right",
+    padding_top => "padding-top",
+    rel => "rel",
+    target => "target",
+    text_align => "text-align",
+    text_decoration => "text-decoration",
+    text_transform => "text-transform",
+    vertical_align => "vertical-align",
+    width => "width",
+});
+
+attributes!(MjTableBuilder {
+    align => "align",
+    border => "border",
+    cellpadding => "cellpadding",
+    cellspacing => "cellspacing",
+    color => "color",
+    container_background_color => "container-background-color",
+    font_family => "font-family",
+    font_size => "font-size",
+    line_height => "line-height",
+    padding => "padding",
+    table_layout => "table-layout",
+    width => "width",
+});
+
+attributes!(MjImageBuilder {
+    align => "align",
+    alt => "alt",
+    border => "border",
+    border_radius => "border-radius",
+    container_background_color => "container-background-color",
+    fluid_on_mobile => "fluid-on-mobile",
+    height => "height",
+    href => "href",
+    padding => "padding",
+    rel => "rel",
+    sizes => "sizes",
+    src => "src",
+    srcset => "srcset",
+    target => "target",
+    title => "title",
+    usemap => "usemap",
+    width => "width",
+});
+
+attributes!(MjDividerBuilder {
+    align => "align",
+    border_color => "border-color",
+    border_style => "border-style",
+    border_width => "border-width",
+    container_background_color => "container-background-color",
+    padding => "padding",
+    width => "width",
+});
+
+attributes!(MjSpacerBuilder {
+    container_background_color => "container-background-color",
+    height => "height",
+    padding => "padding",
+});
+
+attributes!(MjVideoBuilder {
+    align => "align",
+    alt => "alt",
+    border_radius => "border-radius",
+    container_background_color => "container-background-color",
+    height => "height",
+    href => "href",
+    padding => "padding",
+    play_icon_color => "play-icon-color",
+    poster => "poster",
+    src => "src",
+    target => "target",
+    title => "title",
+    width => "width",
+});
+
+attributes!(MjAccordionBuilder {
+    border => "border",
+    container_background_color => "container-background-color",
+    font_family => "font-family",
+    icon_align => "icon-align",
+    icon_height => "icon-height",
+    icon_position => "icon-position",
+    icon_unwrapped_alt => "icon-unwrapped-alt",
+    icon_unwrapped_url => "icon-unwrapped-url",
+    icon_width => "icon-width",
+    icon_wrapped_alt => "icon-wrapped-alt",
+    icon_wrapped_url => "icon-wrapped-url",
+    padding => "padding",
+});
+
+attributes!(MjAccordionElementBuilder {
+    background_color => "background-color",
+    border => "border",
+    font_family => "font-family",
+    icon_align => "icon-align",
+    icon_position => "icon-position",
+});
+
+attributes!(MjAccordionTitleBuilder {
+    background_color => "background-color",
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    padding => "padding",
+});
+
+attributes!(MjAccordionTextBuilder {
+    background_color => "background-color",
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    line_height => "line-height",
+    padding => "padding",
+});
+
+attributes!(MjCarouselBuilder {
+    align => "align",
+    border_radius => "border-radius",
+    container_background_color => "container-background-color",
+    icon_width => "icon-width",
+    left_icon => "left-icon",
+    right_icon => "right-icon",
+    tb_border => "tb-border",
+    tb_border_radius => "tb-border-radius",
+    tb_hover_border_color => "tb-hover-border-color",
+    tb_selected_border_color => "tb-selected-border-color",
+    tb_width => "tb-width",
+    thumbnails => "thumbnails",
+});
+
+attributes!(MjCarouselImageBuilder {
+    alt => "alt",
+    href => "href",
+    rel => "rel",
+    src => "src",
+    target => "target",
+    thumbnails_src => "thumbnails-src",
+    title => "title",
+});
+
+attributes!(MjListBuilder {
+    align => "align",
+    bullet => "bullet",
+    bullet_color => "bullet-color",
+    bullet_image => "bullet-image",
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    indent => "indent",
+    line_height => "line-height",
+    list_style => "list-style",
+    padding => "padding",
+    spacing => "spacing",
+    start => "start",
+});
+
+attributes!(MjListItemBuilder {
+    bullet => "bullet",
+    bullet_color => "bullet-color",
+    bullet_image => "bullet-image",
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    font_weight => "font-weight",
+    line_height => "line-height",
+});
+
+attributes!(MjNavbarBuilder {
+    align => "align",
+    base_url => "base-url",
+    hamburger => "hamburger",
+    ico_align => "ico-align",
+    ico_close => "ico-close",
+    ico_color => "ico-color",
+    ico_font_family => "ico-font-family",
+    ico_font_size => "ico-font-size",
+    ico_open => "ico-open",
+    ico_padding => "ico-padding",
+    ico_text_decoration => "ico-text-decoration",
+    ico_text_transform => "ico-text-transform",
+});
+
+attributes!(MjNavbarLinkBuilder {
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    font_style => "font-style",
+    font_weight => "font-weight",
+    href => "href",
+    letter_spacing => "letter-spacing",
+    line_height => "line-height",
+    padding => "padding",
+    rel => "rel",
+    target => "target",
+    text_decoration => "text-decoration",
+    text_transform => "text-transform",
+});
+
+attributes!(MjSocialBuilder {
+    align => "align",
+    border_radius => "border-radius",
+    color => "color",
+    container_background_color => "container-background-color",
+    font_family => "font-family",
+    font_size => "font-size",
+    icon_size => "icon-size",
+    inner_padding => "inner-padding",
+    line_height => "line-height",
+    mode => "mode",
+    padding => "padding",
+    text_decoration => "text-decoration",
+});
+
+attributes!(MjSocialElementBuilder {
+    align => "align",
+    background_color => "background-color",
+    color => "color",
+    font_family => "font-family",
+    font_size => "font-size",
+    href => "href",
+    icon_size => "icon-size",
+    name => "name",
+    padding => "padding",
+    rel => "rel",
+    src => "src",
+    target => "target",
+    title => "title",
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::mj_body::{MjBody, MjBodyChild};
+    #[cfg(feature = "parse")]
+    use crate::mjml::Mjml;
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_build_like_parsed() {
+        let built = Mjml::builder()
+            .head(|head| {
+                head.title("Hello")
+                    .attributes(|attributes| attributes.all([("font-family", "Arial")]))
+            })
+            .body(|body| {
+                body.section(|section| {
+                    section.padding("0").column(|column| {
+                        column
+                            .text(|text| text.color("red").content("Hello World"))
+                            .button(|button| button.href("/").content("Go"))
+                            .image(|image| image.src("/logo.png"))
+                    })
+                })
+            })
+            .build();
+        let parsed = crate::parse(
+            r#"<mjml><mj-head><mj-title>Hello</mj-title><mj-attributes><mj-all font-family="Arial" /></mj-attributes></mj-head><mj-body><mj-section padding="0"><mj-column><mj-text color="red">Hello World</mj-text><mj-button href="/">Go</mj-button><mj-image src="/logo.png" /></mj-column></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(built, parsed.element);
+    }
+
+    #[test]
+    fn should_build_nested_components() {
+        let body = MjBody::builder()
+            .section(|section| {
+                section.column(|column| {
+                    column
+                        .list(|list| {
+                            list.list_style("decimal")
+                                .item(|item| item.content("First"))
+                                .item(|item| item.content("Second"))
+                        })
+                        .accordion(|accordion| {
+                            accordion.element(|element| {
+                                element
+                                    .title(|title| title.content("Question"))
+                                    .text(|text| text.content("Answer"))
+                            })
+                        })
+                })
+            })
+            .attribute("data-custom", "value")
+            .build();
+        assert_eq!(
+            body.attributes.get("data-custom").map(String::as_str),
+            Some("value")
+        );
+        let Some(MjBodyChild::MjSection(section)) = body.children.first() else {
+            panic!("expected a section");
+        };
+        let Some(MjBodyChild::MjColumn(column)) = section.children.first() else {
+            panic!("expected a column");
+        };
+        match column.children.as_slice() {
+            [MjBodyChild::MjList(list), MjBodyChild::MjAccordion(accordion)] => {
+                assert_eq!(list.children.len(), 2);
+                assert_eq!(list.attributes.get("list-style").unwrap(), "decimal");
+                assert_eq!(accordion.children.len(), 1);
+            }
+            other => panic!("unexpected children {:?}", other),
+        }
+    }
+}
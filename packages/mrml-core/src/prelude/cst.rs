@@ -0,0 +1,677 @@
+//! Lossless representation of a template, to edit it without rewriting the
+//! parts that didn't change.
+//!
+//! Unlike the component tree, the concrete syntax tree keeps every byte of
+//! the source: the whitespaces, the comments, the quotes of the attributes
+//! and the encoded entities. Printing it gives back the source as is, except
+//! for the nodes that were edited.
+//!
+//! ```rust
+//! use mrml::prelude::cst::Cst;
+//!
+//! let source = r#"<mjml>
+//!   <mj-body>
+//!     <!-- call to action -->
+//!     <mj-button href='/buy'   color="red">Buy &amp; save</mj-button>
+//!   </mj-body>
+//! </mjml>"#;
+//! let mut cst = Cst::parse(source).unwrap();
+//! assert_eq!(cst.to_string(), source);
+//!
+//! cst.for_each_element_mut(&mut |element| {
+//!     if element.tag() == "mj-button" {
+//!         element.set_attribute("href", "/checkout");
+//!     }
+//! });
+//! assert_eq!(cst.to_string(), source.replace("'/buy'", "'/checkout'"));
+//! ```
+//!
+//! The templating placeholders of the
+//! [`ParserOptions`](crate::prelude::parser::ParserOptions) are kept as
+//! written, including the ones in place of attributes.
+//!
+//! ```rust
+//! use mrml::prelude::cst::Cst;
+//! use mrml::prelude::parser::{Delimiters, ParserOptions};
+//!
+//! let options = ParserOptions {
+//!     placeholders: vec![Delimiters::new("{{", "}}")],
+//!     ..Default::default()
+//! };
+//! let source = r#"<mjml><mj-body><mj-text {{ attrs }}>{{ name }}</mj-text></mj-body></mjml>"#;
+//! let cst = Cst::parse_with_options(source, &options).unwrap();
+//! assert_eq!(cst.to_string(), source);
+//! assert!(cst.to_mjml_with_options(&options).is_ok());
+//! ```
+
+use std::fmt::Display;
+
+use xmlparser::{ElementEnd, StrSpan, Token, Tokenizer};
+
+use super::parser::{Error, MaskedSource, Origin, ParseOutput, ParserOptions, Span};
+use crate::mjml::Mjml;
+
+/// Attribute of an element, with the whitespaces preceding it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CstAttribute {
+    /// The source until the opening quote, like ` href = `.
+    prefix: String,
+    name: String,
+    value: String,
+    /// `None` for a placeholder in place of an attribute, written in the
+    /// prefix with no value.
+    quote: Option<char>,
+}
+
+impl CstAttribute {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value as written in the template, the entities are not decoded.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Keeps the quotes of the attribute, escaping them in the value.
+    fn set_value(&mut self, value: String) {
+        if self.quote.is_none() {
+            // a placeholder gets a value, like `{{ attrs }}="value"`
+            self.prefix.push('=');
+        }
+        self.value = match *self.quote.get_or_insert('"') {
+            '"' => value.replace('"', "&quot;"),
+            _ => value.replace('\'', "&apos;"),
+        };
+    }
+}
+
+impl Display for CstAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.prefix)?;
+        if let Some(quote) = self.quote {
+            write!(f, "{quote}{}{quote}", self.value)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CstElement {
+    /// Position of the element in the original source.
+    pub span: Option<Span>,
+    tag: String,
+    /// The opening of the start tag, like `<mj-text`.
+    start: String,
+    attributes: Vec<CstAttribute>,
+    /// The end of the start tag with the whitespaces preceding it, like
+    /// ` />` or `>`.
+    start_end: String,
+    children: Vec<CstNode>,
+    /// The closing tag, `None` for the self-closing and void elements.
+    end: Option<String>,
+}
+
+impl CstElement {
+    /// Creates an element with a closing tag.
+    pub fn new(tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        Self {
+            span: None,
+            start: format!("<{tag}"),
+            attributes: Vec::new(),
+            start_end: String::from(">"),
+            children: Vec::new(),
+            end: Some(format!("</{tag}>")),
+            tag,
+        }
+    }
+
+    /// Creates a self-closing element, like `<mj-spacer />`.
+    pub fn new_empty(tag: impl Into<String>) -> Self {
+        Self {
+            start_end: String::from(" />"),
+            end: None,
+            ..Self::new(tag)
+        }
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn attributes(&self) -> &[CstAttribute] {
+        &self.attributes
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|item| item.name == name)
+            .map(CstAttribute::value)
+    }
+
+    /// Sets the value of an attribute, keeping its position and quotes when
+    /// it already exists. The entities should already be encoded, only the
+    /// quotes of the value are escaped.
+    pub fn set_attribute(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        let value = value.into();
+        if let Some(attribute) = self.attributes.iter_mut().find(|item| item.name == name) {
+            attribute.set_value(value);
+        } else {
+            let mut attribute = CstAttribute {
+                prefix: format!(" {name}="),
+                name,
+                value: String::new(),
+                quote: Some('"'),
+            };
+            attribute.set_value(value);
+            self.attributes.push(attribute);
+        }
+    }
+
+    /// Removes an attribute and the whitespaces preceding it.
+    pub fn remove_attribute(&mut self, name: &str) -> bool {
+        let length = self.attributes.len();
+        self.attributes.retain(|item| item.name != name);
+        self.attributes.len() != length
+    }
+
+    pub fn children(&self) -> &[CstNode] {
+        &self.children
+    }
+
+    /// Gives access to the children to edit them, a self-closing element
+    /// gets a closing tag to receive them.
+    pub fn children_mut(&mut self) -> &mut Vec<CstNode> {
+        if self.end.is_none() && self.start_end.ends_with("/>") {
+            self.start_end = format!("{}>", self.start_end.trim_end_matches("/>").trim_end());
+            self.end = Some(format!("</{}>", self.tag));
+        }
+        &mut self.children
+    }
+
+    /// The elements in the children.
+    pub fn elements(&self) -> impl Iterator<Item = &CstElement> {
+        self.children.iter().filter_map(CstNode::as_element)
+    }
+
+    /// Calls the function on the element and its descendants, in document
+    /// order.
+    pub fn for_each_element_mut<F: FnMut(&mut CstElement)>(&mut self, func: &mut F) {
+        func(self);
+        for child in self.children.iter_mut() {
+            if let CstNode::Element(element) = child {
+                element.for_each_element_mut(func);
+            }
+        }
+    }
+}
+
+impl Display for CstElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.start)?;
+        for attribute in self.attributes.iter() {
+            attribute.fmt(f)?;
+        }
+        f.write_str(&self.start_end)?;
+        for child in self.children.iter() {
+            child.fmt(f)?;
+        }
+        if let Some(end) = self.end.as_deref() {
+            f.write_str(end)?;
+        }
+        Ok(())
+    }
+}
+
+/// Text, comment or anything else kept as written in the template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CstText {
+    /// Position of the text in the original source.
+    pub span: Option<Span>,
+    raw: String,
+}
+
+impl CstText {
+    /// Creates a text, the entities should already be encoded.
+    pub fn new(raw: impl Into<String>) -> Self {
+        Self {
+            span: None,
+            raw: raw.into(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn set(&mut self, raw: impl Into<String>) {
+        self.raw = raw.into();
+    }
+}
+
+impl Display for CstText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CstNode {
+    Element(CstElement),
+    /// A text, including the whitespaces between the elements.
+    Text(CstText),
+    /// A comment, with its `<!--` and `-->` delimiters.
+    Comment(CstText),
+    /// The declarations, processing instructions and cdata sections.
+    Other(CstText),
+}
+
+impl CstNode {
+    pub fn as_element(&self) -> Option<&CstElement> {
+        match self {
+            Self::Element(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_element_mut(&mut self) -> Option<&mut CstElement> {
+        match self {
+            Self::Element(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl Display for CstNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Element(inner) => inner.fmt(f),
+            Self::Text(inner) | Self::Comment(inner) | Self::Other(inner) => inner.fmt(f),
+        }
+    }
+}
+
+/// Concrete syntax tree of a template, see the
+/// [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cst {
+    pub children: Vec<CstNode>,
+}
+
+struct CstParser<'a> {
+    /// The original template, the tokenizer reads it with the placeholders
+    /// masked.
+    source: &'a str,
+    placeholders: &'a [std::ops::Range<usize>],
+    /// End of the last token read.
+    position: usize,
+    stack: Vec<CstElement>,
+    children: Vec<CstNode>,
+}
+
+fn qualified_name(prefix: &StrSpan<'_>, local: &StrSpan<'_>) -> String {
+    if prefix.is_empty() {
+        local.to_string()
+    } else {
+        format!("{}:{}", prefix.as_str(), local.as_str())
+    }
+}
+
+impl<'a> CstParser<'a> {
+    fn current_children(&mut self) -> &mut Vec<CstNode> {
+        match self.stack.last_mut() {
+            Some(element) => &mut element.children,
+            None => &mut self.children,
+        }
+    }
+
+    fn push_node(&mut self, node: CstNode) {
+        self.current_children().push(node);
+    }
+
+    /// Keeps the whitespaces the tokenizer skipped, outside of the root
+    /// element.
+    fn push_gap(&mut self, until: usize) {
+        if self.position < until {
+            let span = Span {
+                start: self.position,
+                end: until,
+            };
+            let raw = self.source[self.position..until].to_string();
+            self.push_node(CstNode::Text(CstText {
+                span: Some(span),
+                raw,
+            }));
+            self.position = until;
+        }
+    }
+
+    /// The source of the token, with the placeholders unmasked.
+    fn raw(&self, span: &StrSpan<'_>) -> String {
+        self.source[span.range()].to_string()
+    }
+
+    fn push_raw(&mut self, span: &StrSpan<'_>, build: fn(CstText) -> CstNode) {
+        self.push_gap(span.start());
+        self.push_node(build(CstText {
+            span: Some(Span::from(span)),
+            raw: self.raw(span),
+        }));
+        self.position = span.end();
+    }
+
+    fn unexpected(&self, span: &StrSpan<'_>) -> Error {
+        Error::UnexpectedToken {
+            origin: Origin::Root,
+            position: Span::from(span),
+        }
+    }
+
+    fn close_element(&mut self, end: usize) {
+        if let Some(mut element) = self.stack.pop() {
+            if let Some(span) = element.span.as_mut() {
+                span.end = end;
+            }
+            self.push_node(CstNode::Element(element));
+        }
+    }
+
+    fn read(&mut self, token: Token<'a>) -> Result<(), Error> {
+        match token {
+            Token::ElementStart {
+                prefix,
+                local,
+                span,
+            } => {
+                self.push_gap(span.start());
+                let tag = qualified_name(&prefix, &local);
+                self.stack.push(CstElement {
+                    span: Some(Span::from(&span)),
+                    start: self.raw(&span),
+                    attributes: Vec::new(),
+                    start_end: String::new(),
+                    children: Vec::new(),
+                    end: None,
+                    tag,
+                });
+                self.position = span.end();
+            }
+            Token::Attribute {
+                prefix,
+                local,
+                value,
+                span,
+            } => {
+                let attribute = if self
+                    .placeholders
+                    .iter()
+                    .any(|range| range.start == span.start())
+                {
+                    CstAttribute {
+                        prefix: self.source[self.position..span.end()].to_string(),
+                        name: self.raw(&span),
+                        value: String::new(),
+                        quote: None,
+                    }
+                } else {
+                    // the tokenizer only accepts quoted values
+                    let quote_position = value.start() - 1;
+                    CstAttribute {
+                        prefix: self.source[self.position..quote_position].to_string(),
+                        name: qualified_name(&prefix, &local),
+                        value: self.raw(&value),
+                        quote: Some(char::from(self.source.as_bytes()[quote_position])),
+                    }
+                };
+                match self.stack.last_mut() {
+                    Some(element) => element.attributes.push(attribute),
+                    None => return Err(self.unexpected(&span)),
+                }
+                self.position = span.end();
+            }
+            Token::ElementEnd { end, span } => {
+                let trivia = self.source[self.position..span.end()].to_string();
+                self.position = span.end();
+                match end {
+                    ElementEnd::Open => {
+                        let Some(element) = self.stack.last_mut() else {
+                            return Err(self.unexpected(&span));
+                        };
+                        element.start_end = trivia;
+                        if super::is_void_element(&element.tag) {
+                            self.close_element(span.end());
+                        }
+                    }
+                    ElementEnd::Empty => {
+                        let Some(element) = self.stack.last_mut() else {
+                            return Err(self.unexpected(&span));
+                        };
+                        element.start_end = trivia;
+                        self.close_element(span.end());
+                    }
+                    ElementEnd::Close(prefix, local) => {
+                        let tag = qualified_name(&prefix, &local);
+                        let Some(element) = self.stack.last_mut() else {
+                            return Err(self.unexpected(&span));
+                        };
+                        if element.tag != tag {
+                            return Err(self.unexpected(&span));
+                        }
+                        element.end = Some(trivia);
+                        self.close_element(span.end());
+                    }
+                }
+            }
+            Token::Text { text } => self.push_raw(&text, CstNode::Text),
+            Token::Comment { span, .. } => self.push_raw(&span, CstNode::Comment),
+            Token::Cdata { span, .. }
+            | Token::Declaration { span, .. }
+            | Token::ProcessingInstruction { span, .. }
+            | Token::DtdStart { span, .. }
+            | Token::EmptyDtd { span, .. }
+            | Token::EntityDeclaration { span, .. }
+            | Token::DtdEnd { span } => self.push_raw(&span, CstNode::Other),
+        }
+        Ok(())
+    }
+}
+
+impl Cst {
+    /// Parses the template without interpreting it, any well formed template
+    /// is accepted.
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        Self::parse_with_options(source, &ParserOptions::default())
+    }
+
+    /// Parses the template keeping the placeholders of the options as is. The
+    /// includes are not loaded, they stay as written in the template.
+    pub fn parse_with_options(source: &str, options: &ParserOptions) -> Result<Self, Error> {
+        let masked = MaskedSource::new(source, &options.placeholders);
+        let mut parser = CstParser {
+            source,
+            placeholders: masked.placeholders.as_slice(),
+            position: 0,
+            stack: Vec::new(),
+            children: Vec::new(),
+        };
+        for token in Tokenizer::from(masked.masked.as_ref()) {
+            let token = token.map_err(|source| Error::ParserError {
+                origin: Origin::Root,
+                source,
+            })?;
+            parser.read(token)?;
+        }
+        if !parser.stack.is_empty() {
+            return Err(Error::EndOfStream {
+                origin: Origin::Root,
+            });
+        }
+        parser.push_gap(source.len());
+        Ok(Self {
+            children: parser.children,
+        })
+    }
+
+    /// The first element of the document, usually `mjml`.
+    pub fn root(&self) -> Option<&CstElement> {
+        self.children.iter().find_map(CstNode::as_element)
+    }
+
+    pub fn root_mut(&mut self) -> Option<&mut CstElement> {
+        self.children.iter_mut().find_map(CstNode::as_element_mut)
+    }
+
+    /// Calls the function on all the elements, in document order.
+    pub fn for_each_element_mut<F: FnMut(&mut CstElement)>(&mut self, func: &mut F) {
+        for child in self.children.iter_mut() {
+            if let CstNode::Element(element) = child {
+                element.for_each_element_mut(func);
+            }
+        }
+    }
+
+    /// Parses the printed template into its components.
+    pub fn to_mjml(&self) -> Result<ParseOutput<Mjml>, Error> {
+        crate::parse(self.to_string())
+    }
+
+    /// Parses the printed template into its components with the given
+    /// options.
+    pub fn to_mjml_with_options(
+        &self,
+        options: &ParserOptions,
+    ) -> Result<ParseOutput<Mjml>, Error> {
+        crate::parse_with_options(self.to_string(), options)
+    }
+}
+
+impl Display for Cst {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for child in self.children.iter() {
+            child.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cst, CstElement, CstNode, CstText};
+    use crate::prelude::parser::{Delimiters, ParserOptions};
+
+    #[test]
+    fn should_print_sources_as_is() {
+        for (path, source) in crate::helper::compare::compared_templates() {
+            let cst = Cst::parse(&source).unwrap();
+            assert_eq!(cst.to_string(), source, "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn should_only_change_edited_nodes() {
+        let source = r#"<!-- newsletter -->
+<mjml>
+  <mj-body   background-color = '#fff' >
+    <mj-section><mj-column>
+      <mj-image src="a.png"/>
+      <mj-text>Hello&nbsp;<br>World</mj-text>
+      <mj-button href="/buy"/>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>
+"#;
+        let mut cst = Cst::parse(source).unwrap();
+        assert_eq!(cst.to_string(), source);
+
+        cst.for_each_element_mut(&mut |element| match element.tag() {
+            "mj-body" => {
+                element.set_attribute("background-color", "#000");
+                element.set_attribute("width", "600px");
+            }
+            "mj-image" => {
+                element.remove_attribute("src");
+            }
+            "mj-button" => element
+                .children_mut()
+                .push(CstNode::Text(CstText::new("Buy"))),
+            "mj-column" => element
+                .children_mut()
+                .push(CstNode::Element(CstElement::new_empty("mj-divider"))),
+            _ => {}
+        });
+        let expected = source
+            .replace("'#fff' >", r#"'#000' width="600px" >"#)
+            .replace(r#"<mj-image src="a.png"/>"#, "<mj-image/>")
+            .replace(
+                "<mj-button href=\"/buy\"/>\n    </mj-column>",
+                "<mj-button href=\"/buy\">Buy</mj-button>\n    <mj-divider /></mj-column>",
+            );
+        assert_eq!(cst.to_string(), expected);
+
+        let root = cst.root().unwrap();
+        assert_eq!(root.tag(), "mjml");
+        assert_eq!(root.span.unwrap().start, 20);
+        let body = root.elements().next().unwrap();
+        assert_eq!(body.attribute("width"), Some("600px"));
+        assert!(cst.to_mjml().is_ok());
+    }
+
+    #[test]
+    fn should_escape_the_quotes_of_the_values() {
+        let source = r#"<mj-button title="buy" alt='sale'>Buy</mj-button>"#;
+        let mut cst = Cst::parse(source).unwrap();
+        let button = cst.root_mut().unwrap();
+        button.set_attribute("title", r#"say "hi""#);
+        button.set_attribute("alt", "it's on sale");
+        button.set_attribute("css-class", r#"a"b"#);
+        assert_eq!(
+            cst.to_string(),
+            r#"<mj-button title="say &quot;hi&quot;" alt='it&apos;s on sale' css-class="a&quot;b">Buy</mj-button>"#
+        );
+    }
+
+    #[test]
+    fn should_keep_the_placeholders() {
+        let options = ParserOptions {
+            placeholders: vec![Delimiters::new("{{", "}}"), Delimiters::new("{%", "%}")],
+            ..Default::default()
+        };
+        let source = r#"<mjml>
+  <mj-body>
+    {% if show %}<mj-button {{ attrs }} href="{{ url }}">{{ name }} &amp; co</mj-button>{% endif %}
+  </mj-body>
+</mjml>"#;
+        assert!(Cst::parse(source).is_err());
+        let mut cst = Cst::parse_with_options(source, &options).unwrap();
+        assert_eq!(cst.to_string(), source);
+
+        cst.for_each_element_mut(&mut |element| {
+            if element.tag() == "mj-button" {
+                assert_eq!(element.attributes()[0].name(), "{{ attrs }}");
+                assert_eq!(element.attribute("href"), Some("{{ url }}"));
+                element.set_attribute("color", "{{ color }}");
+            }
+        });
+        assert_eq!(
+            cst.to_string(),
+            source.replace(r#"}}">"#, r#"}}" color="{{ color }}">"#)
+        );
+        let root = cst.to_mjml_with_options(&options).unwrap();
+        #[cfg(feature = "print")]
+        {
+            let printed = crate::prelude::print::Printable::print_dense(&root.element).unwrap();
+            assert!(printed.contains("{{ attrs }}"));
+        }
+        #[cfg(not(feature = "print"))]
+        assert!(root.element.body().is_some());
+    }
+
+    #[test]
+    fn should_reject_malformed_templates() {
+        assert!(Cst::parse("<mjml><mj-body></mjml>").is_err());
+        assert!(Cst::parse("<mjml><mj-body>").is_err());
+    }
+}
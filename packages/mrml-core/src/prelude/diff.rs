@@ -0,0 +1,706 @@
+//! Semantic diff between two templates.
+//!
+//! Instead of comparing the printed templates line by line, the components of
+//! both templates are matched together and the differences are reported as
+//! [`Change`]s: the components added, removed or moved, the attributes and the
+//! texts that changed. Each change comes with the path of the component, like
+//! `mj-body > mj-section[2] > mj-column[0] > mj-text`, where the index is the
+//! position of the component among its siblings with the same tag, only given
+//! when there are several of them.
+//!
+//! Both templates are [normalized](super::normalize) before being compared.
+//!
+//! ```rust
+//! let before = mrml::parse(r#"<mjml><mj-body><mj-text>Hello</mj-text><mj-button color="red">Go</mj-button></mj-body></mjml>"#).unwrap();
+//! let after = mrml::parse(r#"<mjml><mj-body><mj-text>Hi</mj-text><mj-button color="blue">Go</mj-button></mj-body></mjml>"#).unwrap();
+//! let changes = before.element.diff(&after.element);
+//! assert_eq!(
+//!     changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+//!     vec![
+//!         r#"mj-body > mj-text: text changed from "Hello" to "Hi""#,
+//!         r#"mj-body > mj-button: attribute "color" changed from "red" to "blue""#,
+//!     ]
+//! );
+//! ```
+
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+use super::hash::Map;
+use super::normalize::Normalize;
+use super::visitor::{Visitable, Visitor};
+use super::{Component, StaticTag};
+use crate::mj_accordion::MjAccordion;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::mj_attributes::MjAttributes;
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::{MjAttributesClass, MjAttributesClassAttributes};
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_breakpoint::{MjBreakpoint, MjBreakpointAttributes};
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_font::{MjFont, MjFontAttributes};
+use crate::mj_group::MjGroup;
+use crate::mj_head::MjHead;
+use crate::mj_hero::MjHero;
+use crate::mj_image::MjImage;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyAttributes};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadAttributes};
+use crate::mj_list::MjList;
+use crate::mj_list_item::MjListItem;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_preview::MjPreview;
+use crate::mj_raw::{MjRaw, MjRawAttributes, MjRawChild};
+use crate::mj_section::MjSection;
+use crate::mj_social::MjSocial;
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_style::{MjStyle, MjStyleAttributes};
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_title::MjTitle;
+use crate::mj_video::MjVideo;
+use crate::mj_wrapper::MjWrapper;
+use crate::mjml::{Mjml, MjmlAttributes};
+use crate::node::Node;
+use crate::text::Text;
+
+/// Difference between two templates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "json", serde(tag = "type", rename_all = "snake_case"))]
+pub enum Change {
+    /// The component only exists in the new template.
+    Added { path: String },
+    /// The component only exists in the old template.
+    Removed { path: String },
+    /// The component didn't change but its position did.
+    Moved { from: String, to: String },
+    /// The attribute has been added (no `before`), removed (no `after`) or
+    /// its value changed.
+    AttributeChanged {
+        path: String,
+        name: String,
+        before: Option<String>,
+        after: Option<String>,
+    },
+    /// The content of the component changed, like the html of an `mj-text`.
+    TextChanged {
+        path: String,
+        before: String,
+        after: String,
+    },
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added { path } => write!(f, "{path}: added"),
+            Self::Removed { path } => write!(f, "{path}: removed"),
+            Self::Moved { from, to } => write!(f, "{from}: moved to {to}"),
+            Self::AttributeChanged {
+                path,
+                name,
+                before: Some(before),
+                after: Some(after),
+            } => write!(
+                f,
+                "{path}: attribute {name:?} changed from {before:?} to {after:?}"
+            ),
+            Self::AttributeChanged {
+                path,
+                name,
+                before: None,
+                after: Some(after),
+            } => write!(f, "{path}: attribute {name:?} set to {after:?}"),
+            Self::AttributeChanged {
+                path,
+                name,
+                before: Some(before),
+                after: None,
+            } => write!(f, "{path}: attribute {name:?} removed, was {before:?}"),
+            Self::AttributeChanged { path, name, .. } => {
+                write!(f, "{path}: attribute {name:?} changed")
+            }
+            Self::TextChanged {
+                path,
+                before,
+                after,
+            } => write!(f, "{path}: text changed from {before:?} to {after:?}"),
+        }
+    }
+}
+
+/// Generic representation of a component, the one that gets compared.
+#[derive(Debug, Default, PartialEq)]
+struct Element {
+    tag: String,
+    attributes: Map<String, String>,
+    /// The html content of the components containing some, like `mj-text`.
+    content: Option<String>,
+    children: Vec<Element>,
+}
+
+trait DiffAttributes {
+    fn to_map(&self) -> Map<String, String>;
+}
+
+impl DiffAttributes for () {
+    fn to_map(&self) -> Map<String, String> {
+        Map::new()
+    }
+}
+
+impl DiffAttributes for Map<String, String> {
+    fn to_map(&self) -> Map<String, String> {
+        self.clone()
+    }
+}
+
+fn optional_attributes<'a, I>(items: I) -> Map<String, String>
+where
+    I: IntoIterator<Item = (&'a str, Option<&'a String>)>,
+{
+    items
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name.to_string(), value.clone())))
+        .collect()
+}
+
+impl DiffAttributes for MjmlAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        optional_attributes(vec![
+            ("owa", self.owa.as_ref()),
+            ("lang", self.lang.as_ref()),
+            ("dir", self.dir.as_ref()),
+        ])
+    }
+}
+
+impl DiffAttributes for MjRawAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        optional_attributes(vec![("position", self.position.as_ref())])
+    }
+}
+
+impl DiffAttributes for MjStyleAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        optional_attributes(vec![("inline", self.inline.as_ref())])
+    }
+}
+
+impl DiffAttributes for MjBreakpointAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        optional_attributes(vec![("width", Some(&self.width))])
+    }
+}
+
+impl DiffAttributes for MjFontAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        optional_attributes(vec![("name", Some(&self.name)), ("href", Some(&self.href))])
+    }
+}
+
+impl DiffAttributes for MjAttributesClassAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        let mut result = optional_attributes(vec![("name", Some(&self.name))]);
+        result.extend(self.others.iter().map(|(k, v)| (k.clone(), v.clone())));
+        result
+    }
+}
+
+impl DiffAttributes for MjIncludeBodyAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        let mut result = optional_attributes(vec![("path", Some(&self.path))]);
+        result.insert("type".into(), self.kind.as_ref().to_string());
+        result
+    }
+}
+
+impl DiffAttributes for MjIncludeHeadAttributes {
+    fn to_map(&self) -> Map<String, String> {
+        let mut result = optional_attributes(vec![("path", Some(&self.path))]);
+        result.insert("type".into(), self.kind.as_ref().to_string());
+        result
+    }
+}
+
+fn static_tag<T: StaticTag, A, C>(_element: &Component<PhantomData<T>, A, C>) -> &'static str {
+    T::static_tag()
+}
+
+/// [`Visitor`] building the [`Element`] tree of a template.
+#[derive(Default)]
+struct TreeBuilder {
+    stack: Vec<Element>,
+    /// For each html node entered, whether it was written in the content of
+    /// its parent.
+    inline_nodes: Vec<bool>,
+    root: Option<Element>,
+}
+
+impl TreeBuilder {
+    fn open(&mut self, tag: &str, attributes: Map<String, String>, content: Option<String>) {
+        self.stack.push(Element {
+            tag: tag.to_string(),
+            attributes,
+            content,
+            children: Vec::new(),
+        });
+    }
+
+    fn close(&mut self) {
+        if let Some(element) = self.stack.pop() {
+            match self.stack.last_mut() {
+                Some(parent) => parent.children.push(element),
+                None => self.root = Some(element),
+            }
+        }
+    }
+
+    fn content_mut(&mut self) -> Option<&mut String> {
+        self.stack
+            .last_mut()
+            .and_then(|element| element.content.as_mut())
+    }
+
+    fn enter_html<C>(&mut self, element: &Node<C>) {
+        if let Some(content) = self.content_mut() {
+            let _ = write!(content, "<{}", element.tag);
+            for (name, value) in element.attributes.iter() {
+                let _ = write!(content, " {name}=\"{value}\"");
+            }
+            content.push_str(if element.children.is_empty() {
+                " />"
+            } else {
+                ">"
+            });
+            self.inline_nodes.push(true);
+        } else {
+            self.open(
+                &element.tag,
+                element.attributes.clone(),
+                Some(String::new()),
+            );
+            self.inline_nodes.push(false);
+        }
+    }
+
+    fn leave_html<C>(&mut self, element: &Node<C>) {
+        match self.inline_nodes.pop() {
+            Some(true) if !element.children.is_empty() => {
+                if let Some(content) = self.content_mut() {
+                    let _ = write!(content, "</{}>", element.tag);
+                }
+            }
+            Some(false) => self.close(),
+            _ => {}
+        }
+    }
+}
+
+macro_rules! build_element {
+    ($kind:ident: $($enter:ident, $leave:ident($element:ty)),* $(,)?) => {
+        $(
+            fn $enter(&mut self, element: &$element) {
+                let content = build_element!(@content $kind, element);
+                self.open(static_tag(element), element.attributes.to_map(), content);
+            }
+
+            fn $leave(&mut self, _element: &$element) {
+                self.close();
+            }
+        )*
+    };
+    (@content layout, $element:ident) => { None };
+    (@content content, $element:ident) => { Some(String::new()) };
+    (@content text, $element:ident) => { Some($element.children.clone()) };
+}
+
+impl Visitor<'_> for TreeBuilder {
+    fn enter_text(&mut self, element: &Text) {
+        if let Some(content) = self.content_mut() {
+            content.push_str(element.inner_str());
+        }
+    }
+
+    fn enter_node(&mut self, element: &Node<MjBodyChild>) {
+        self.enter_html(element);
+    }
+
+    fn leave_node(&mut self, element: &Node<MjBodyChild>) {
+        self.leave_html(element);
+    }
+
+    fn enter_raw_node(&mut self, element: &Node<MjRawChild>) {
+        self.enter_html(element);
+    }
+
+    fn leave_raw_node(&mut self, element: &Node<MjRawChild>) {
+        self.leave_html(element);
+    }
+
+    fn enter_mj_attributes_element(&mut self, element: &MjAttributesElement) {
+        self.open(&element.name, element.attributes.clone(), None);
+    }
+
+    fn leave_mj_attributes_element(&mut self, _element: &MjAttributesElement) {
+        self.close();
+    }
+
+    fn enter_mj_include_body(&mut self, element: &MjIncludeBody) {
+        self.open(static_tag(&element.0), element.0.attributes.to_map(), None);
+    }
+
+    fn leave_mj_include_body(&mut self, _element: &MjIncludeBody) {
+        self.close();
+    }
+
+    fn enter_mj_include_head(&mut self, element: &MjIncludeHead) {
+        self.open(static_tag(&element.0), element.0.attributes.to_map(), None);
+    }
+
+    fn leave_mj_include_head(&mut self, _element: &MjIncludeHead) {
+        self.close();
+    }
+
+    build_element!(
+        layout: enter_mjml, leave_mjml(Mjml),
+        enter_mj_head, leave_mj_head(MjHead),
+        enter_mj_body, leave_mj_body(MjBody),
+        enter_mj_attributes, leave_mj_attributes(MjAttributes),
+        enter_mj_attributes_all, leave_mj_attributes_all(MjAttributesAll),
+        enter_mj_attributes_class, leave_mj_attributes_class(MjAttributesClass),
+        enter_mj_breakpoint, leave_mj_breakpoint(MjBreakpoint),
+        enter_mj_font, leave_mj_font(MjFont),
+        enter_mj_accordion, leave_mj_accordion(MjAccordion),
+        enter_mj_accordion_element, leave_mj_accordion_element(MjAccordionElement),
+        enter_mj_carousel, leave_mj_carousel(MjCarousel),
+        enter_mj_carousel_image, leave_mj_carousel_image(MjCarouselImage),
+        enter_mj_column, leave_mj_column(MjColumn),
+        enter_mj_divider, leave_mj_divider(MjDivider),
+        enter_mj_group, leave_mj_group(MjGroup),
+        enter_mj_hero, leave_mj_hero(MjHero),
+        enter_mj_image, leave_mj_image(MjImage),
+        enter_mj_list, leave_mj_list(MjList),
+        enter_mj_navbar, leave_mj_navbar(MjNavbar),
+        enter_mj_section, leave_mj_section(MjSection),
+        enter_mj_social, leave_mj_social(MjSocial),
+        enter_mj_spacer, leave_mj_spacer(MjSpacer),
+        enter_mj_video, leave_mj_video(MjVideo),
+        enter_mj_wrapper, leave_mj_wrapper(MjWrapper),
+    );
+
+    build_element!(
+        content: enter_mj_raw, leave_mj_raw(MjRaw),
+        enter_mj_accordion_text, leave_mj_accordion_text(MjAccordionText),
+        enter_mj_accordion_title, leave_mj_accordion_title(MjAccordionTitle),
+        enter_mj_button, leave_mj_button(MjButton),
+        enter_mj_list_item, leave_mj_list_item(MjListItem),
+        enter_mj_navbar_link, leave_mj_navbar_link(MjNavbarLink),
+        enter_mj_social_element, leave_mj_social_element(MjSocialElement),
+        enter_mj_table, leave_mj_table(MjTable),
+        enter_mj_text, leave_mj_text(MjText),
+    );
+
+    build_element!(
+        text: enter_mj_preview, leave_mj_preview(MjPreview),
+        enter_mj_style, leave_mj_style(MjStyle),
+        enter_mj_title, leave_mj_title(MjTitle),
+    );
+}
+
+fn build(root: &Mjml) -> Element {
+    let mut builder = TreeBuilder::default();
+    root.normalized().accept(&mut builder);
+    builder.root.unwrap_or_default()
+}
+
+/// Pairs of indexes of the longest common subsequence of the two lists.
+fn longest_common_subsequence<F>(old: &[usize], new: &[usize], equals: F) -> Vec<(usize, usize)>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if equals(old[i], new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if equals(old[i], new[j]) {
+            result.push((old[i], new[j]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+fn child_paths(parent: Option<&str>, children: &[Element]) -> Vec<String> {
+    let mut counts: Map<&str, usize> = Map::new();
+    for child in children.iter() {
+        *counts.entry(child.tag.as_str()).or_default() += 1;
+    }
+    let mut indexes: Map<&str, usize> = Map::new();
+    children
+        .iter()
+        .map(|child| {
+            let index = indexes.entry(child.tag.as_str()).or_default();
+            let segment = if counts.get(child.tag.as_str()).copied().unwrap_or(0) > 1 {
+                format!("{}[{index}]", child.tag)
+            } else {
+                child.tag.clone()
+            };
+            *index += 1;
+            match parent {
+                Some(parent) => format!("{parent} > {segment}"),
+                None => segment,
+            }
+        })
+        .collect()
+}
+
+enum Matching {
+    Unchanged,
+    Moved(usize),
+    Changed(usize),
+    Added,
+}
+
+fn diff_children(
+    old: &[Element],
+    old_parent: Option<&str>,
+    new: &[Element],
+    new_parent: Option<&str>,
+    output: &mut Vec<Change>,
+) {
+    let old_paths = child_paths(old_parent, old);
+    let new_paths = child_paths(new_parent, new);
+    let mut old_matched = vec![false; old.len()];
+    let mut new_matching = (0..new.len()).map(|_| Matching::Added).collect::<Vec<_>>();
+
+    let old_indexes = (0..old.len()).collect::<Vec<_>>();
+    let new_indexes = (0..new.len()).collect::<Vec<_>>();
+    for (i, j) in longest_common_subsequence(&old_indexes, &new_indexes, |i, j| old[i] == new[j]) {
+        old_matched[i] = true;
+        new_matching[j] = Matching::Unchanged;
+    }
+    for i in 0..old.len() {
+        if old_matched[i] {
+            continue;
+        }
+        let found = (0..new.len())
+            .find(|j| matches!(new_matching[*j], Matching::Added) && old[i] == new[*j]);
+        if let Some(j) = found {
+            old_matched[i] = true;
+            new_matching[j] = Matching::Moved(i);
+        }
+    }
+    let old_left = (0..old.len())
+        .filter(|i| !old_matched[*i])
+        .collect::<Vec<_>>();
+    let new_left = (0..new.len())
+        .filter(|j| matches!(new_matching[*j], Matching::Added))
+        .collect::<Vec<_>>();
+    for (i, j) in longest_common_subsequence(&old_left, &new_left, |i, j| old[i].tag == new[j].tag)
+    {
+        old_matched[i] = true;
+        new_matching[j] = Matching::Changed(i);
+    }
+
+    for (i, matched) in old_matched.iter().enumerate() {
+        if !matched {
+            output.push(Change::Removed {
+                path: old_paths[i].clone(),
+            });
+        }
+    }
+    for (j, matching) in new_matching.iter().enumerate() {
+        match matching {
+            Matching::Unchanged => {}
+            Matching::Moved(i) => output.push(Change::Moved {
+                from: old_paths[*i].clone(),
+                to: new_paths[j].clone(),
+            }),
+            Matching::Changed(i) => {
+                diff_element(&old[*i], &old_paths[*i], &new[j], &new_paths[j], output)
+            }
+            Matching::Added => output.push(Change::Added {
+                path: new_paths[j].clone(),
+            }),
+        }
+    }
+}
+
+fn diff_attributes(old: &Element, new: &Element, path: &str, output: &mut Vec<Change>) {
+    for (name, before) in old.attributes.iter() {
+        let after = new.attributes.get(name);
+        if after != Some(before) {
+            output.push(Change::AttributeChanged {
+                path: path.to_string(),
+                name: name.clone(),
+                before: Some(before.clone()),
+                after: after.cloned(),
+            });
+        }
+    }
+    for (name, after) in new.attributes.iter() {
+        if !old.attributes.contains_key(name) {
+            output.push(Change::AttributeChanged {
+                path: path.to_string(),
+                name: name.clone(),
+                before: None,
+                after: Some(after.clone()),
+            });
+        }
+    }
+}
+
+fn diff_element(
+    old: &Element,
+    old_path: &str,
+    new: &Element,
+    new_path: &str,
+    output: &mut Vec<Change>,
+) {
+    diff_attributes(old, new, new_path, output);
+    if old.content != new.content {
+        output.push(Change::TextChanged {
+            path: new_path.to_string(),
+            before: old.content.clone().unwrap_or_default(),
+            after: new.content.clone().unwrap_or_default(),
+        });
+    }
+    diff_children(
+        &old.children,
+        Some(old_path),
+        &new.children,
+        Some(new_path),
+        output,
+    );
+}
+
+impl Mjml {
+    /// Lists the [`Change`]s needed to go from this template to the other one.
+    pub fn diff(&self, other: &Mjml) -> Vec<Change> {
+        let old = build(self);
+        let new = build(other);
+        let mut output = Vec::new();
+        diff_attributes(&old, &new, &new.tag, &mut output);
+        // the path of the components starts from the head or the body
+        diff_children(&old.children, None, &new.children, None, &mut output);
+        output
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::Change;
+
+    fn diff(before: &str, after: &str) -> Vec<String> {
+        let before = crate::parse(before).unwrap();
+        let after = crate::parse(after).unwrap();
+        before
+            .element
+            .diff(&after.element)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn should_not_find_changes_in_formatting() {
+        let changes = diff(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text align="left" color="red">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+            r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-text color="red" align="left">Hello</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#,
+        );
+        assert_eq!(changes, Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_report_text_and_attribute_changes() {
+        let changes = diff(
+            r#"<mjml><mj-body>
+<mj-section><mj-column><mj-text>Header</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>Welcome</mj-text></mj-column><mj-column><mj-image src="a.png" /></mj-column></mj-section>
+<mj-section><mj-column><mj-button background-color="red">Buy</mj-button></mj-column></mj-section>
+</mj-body></mjml>"#,
+            r#"<mjml><mj-body>
+<mj-section><mj-column><mj-text>Header</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>Welcome <b>back</b></mj-text></mj-column><mj-column><mj-image src="a.png" alt="A" /></mj-column></mj-section>
+<mj-section><mj-column><mj-button background-color="blue">Buy</mj-button></mj-column></mj-section>
+</mj-body></mjml>"#,
+        );
+        assert_eq!(
+            changes,
+            vec![
+                r#"mj-body > mj-section[1] > mj-column[0] > mj-text: text changed from "Welcome" to "Welcome <b>back</b>""#,
+                r#"mj-body > mj-section[1] > mj-column[1] > mj-image: attribute "alt" set to "A""#,
+                r#"mj-body > mj-section[2] > mj-column > mj-button: attribute "background-color" changed from "red" to "blue""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_added_removed_and_moved() {
+        let before = crate::parse(
+            r#"<mjml><mj-body>
+<mj-section><mj-column><mj-text>First</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>Second</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>Third</mj-text></mj-column></mj-section>
+<mj-spacer />
+</mj-body></mjml>"#,
+        )
+        .unwrap();
+        let after = crate::parse(
+            r#"<mjml><mj-body>
+<mj-section><mj-column><mj-text>Third</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>First</mj-text></mj-column></mj-section>
+<mj-section><mj-column><mj-text>Second</mj-text></mj-column></mj-section>
+<mj-divider />
+</mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(
+            before.element.diff(&after.element),
+            vec![
+                Change::Removed {
+                    path: "mj-body > mj-spacer".into()
+                },
+                Change::Moved {
+                    from: "mj-body > mj-section[2]".into(),
+                    to: "mj-body > mj-section[0]".into()
+                },
+                Change::Added {
+                    path: "mj-body > mj-divider".into()
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,626 @@
+//! Configurable formatting of the templates, to enforce a consistent style.
+//!
+//! Only the structure of the template is reflowed: the content of the
+//! ending tags like `mj-text`, `mj-button` or `mj-raw`, and of the html
+//! elements, is printed as is so that the rendered html doesn't change. The
+//! text of the `mj-text` elements can be reflowed as well with
+//! [`TextReflow::Collapse`], which only changes whitespaces that don't show.
+//!
+//! ```rust
+//! use mrml::prelude::format::{AttributeOrder, FormatOptions};
+//! use mrml::prelude::print::Printable;
+//!
+//! let root = mrml::parse(r#"<mjml><mj-body><mj-text padding="0" align="center">Hello <b>World</b></mj-text></mj-body></mjml>"#).unwrap();
+//! let options = FormatOptions {
+//!     use_tabs: true,
+//!     attribute_order: AttributeOrder::Alphabetical,
+//!     ..Default::default()
+//! };
+//! assert_eq!(
+//!     root.element.print_formatted(&options).unwrap(),
+//!     "<mjml>\n\t<mj-body>\n\t\t<mj-text align=\"center\" padding=\"0\">Hello <b>World</b></mj-text>\n\t</mj-body>\n</mjml>\n",
+//! );
+//! ```
+
+use std::fmt::{Debug, Display};
+
+use super::is_void_element;
+use super::print::Printer;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AttributeOrder {
+    /// Keeps the attributes in the order of the template.
+    #[default]
+    Preserve,
+    /// Sorts the attributes by name. The elements containing placeholder
+    /// attributes are kept as is, their position might matter.
+    Alphabetical,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelfClosing {
+    /// Like `<mj-spacer />`.
+    #[default]
+    Spaced,
+    /// Like `<mj-spacer/>`.
+    Compact,
+    /// Like `<mj-spacer></mj-spacer>`, except for the void elements like
+    /// `<br />`.
+    Expanded,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextReflow {
+    /// Keeps the text of the `mj-text` elements as is.
+    #[default]
+    Preserve,
+    /// Collapses the whitespaces of the text of the `mj-text` elements into
+    /// a single space, wrapping the lines longer than the `max_width`. The
+    /// content of the `pre`, `textarea`, `script` and `style` elements is
+    /// kept as is, the whitespaces being significant there.
+    Collapse,
+}
+
+/// The html elements in which the whitespaces are significant.
+const PREFORMATTED_TAGS: &[&str] = &["pre", "script", "style", "textarea"];
+
+/// The elements whose content ends up in the html, printed as is.
+const ENDING_TAGS: &[&str] = &[
+    "mj-accordion-text",
+    "mj-accordion-title",
+    "mj-button",
+    "mj-list-item",
+    "mj-navbar-link",
+    "mj-raw",
+    "mj-social-element",
+    "mj-table",
+    "mj-text",
+];
+
+fn is_verbatim_element(tag: &str) -> bool {
+    !tag.starts_with("mj") || ENDING_TAGS.contains(&tag)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Number of spaces by level of indentation, also used as the width of
+    /// a tab.
+    pub indent_width: usize,
+    pub use_tabs: bool,
+    /// Width above which the attributes are printed one per line. `None`
+    /// doesn't limit the width.
+    pub max_width: Option<usize>,
+    pub attribute_order: AttributeOrder,
+    /// Style of the elements without children.
+    pub self_closing: SelfClosing,
+    pub text_reflow: TextReflow,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_width: 2,
+            use_tabs: false,
+            max_width: Some(80),
+            attribute_order: AttributeOrder::default(),
+            self_closing: SelfClosing::default(),
+            text_reflow: TextReflow::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PendingAttribute {
+    name: String,
+    content: String,
+}
+
+/// Start tag waiting for all its attributes to know how to print them.
+#[derive(Debug)]
+struct PendingTag {
+    name: String,
+    attributes: Vec<PendingAttribute>,
+    has_raw: bool,
+}
+
+/// Printer applying the [`FormatOptions`].
+///
+/// The output is stable: formatting a formatted template doesn't change it.
+#[derive(Debug)]
+pub struct Formatter {
+    options: FormatOptions,
+    level: usize,
+    buffer: String,
+    /// Nothing has been written since the last new line.
+    line_empty: bool,
+    /// An empty line has been requested and will be written before the next
+    /// content.
+    blank_line: bool,
+    /// Nothing has been written since the start tag of the element.
+    block_start: bool,
+    tag: Option<PendingTag>,
+    /// Number of open elements whose content is printed as is.
+    verbatim: usize,
+    /// The text of the current `mj-text` is reflowed.
+    reflow: bool,
+    /// Number of open elements of the `mj-text` in which the whitespaces are
+    /// significant.
+    preformatted: usize,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new(FormatOptions::default())
+    }
+}
+
+impl Formatter {
+    pub fn new(options: FormatOptions) -> Self {
+        Self {
+            options,
+            level: 0,
+            buffer: String::default(),
+            line_empty: true,
+            blank_line: false,
+            block_start: true,
+            tag: None,
+            verbatim: 0,
+            reflow: false,
+            preformatted: 0,
+        }
+    }
+
+    fn indent_columns(&self, level: usize) -> usize {
+        level * self.options.indent_width
+    }
+
+    fn write_indent(&mut self, level: usize) {
+        if self.options.use_tabs {
+            self.buffer.extend(std::iter::repeat_n('\t', level));
+        } else {
+            self.buffer
+                .extend(std::iter::repeat_n(' ', self.indent_columns(level)));
+        }
+    }
+
+    fn write(&mut self, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+        if self.line_empty {
+            if self.blank_line {
+                self.buffer.push('\n');
+            }
+            self.write_indent(self.level);
+        }
+        self.buffer.push_str(value);
+        self.line_empty = false;
+        self.blank_line = false;
+        self.block_start = false;
+    }
+
+    fn write_line(&mut self, value: &str) {
+        self.write(value);
+        self.push_new_line();
+    }
+
+    /// Number of characters of the current line.
+    fn column(&self) -> usize {
+        let start = self.buffer.rfind('\n').map_or(0, |index| index + 1);
+        self.buffer[start..].chars().count()
+    }
+
+    /// Writes the words of the text separated by a single space, or by a new
+    /// line when the line would be longer than the `max_width`.
+    fn write_reflowed(&mut self, value: &str) {
+        let continuation = self.indent_columns(self.level);
+        let mut space = value.starts_with(|c: char| c.is_ascii_whitespace());
+        for word in value.split_ascii_whitespace() {
+            let length = word.chars().count();
+            let column = self.column();
+            let wrap = space
+                && column > continuation
+                && self
+                    .options
+                    .max_width
+                    .is_some_and(|max| column + 1 + length > max);
+            if wrap {
+                if self.buffer.ends_with(' ') {
+                    self.buffer.pop();
+                }
+                self.buffer.push('\n');
+                self.write_indent(self.level);
+            } else if space && !self.buffer.ends_with(' ') {
+                self.buffer.push(' ');
+            }
+            self.buffer.push_str(word);
+            space = true;
+        }
+        if value.ends_with(|c: char| c.is_ascii_whitespace()) && !self.buffer.ends_with(' ') {
+            self.buffer.push(' ');
+        }
+        self.line_empty = false;
+        self.block_start = false;
+    }
+
+    fn take_tag(&mut self) -> Option<PendingTag> {
+        let mut tag = self.tag.take()?;
+        if self.options.attribute_order == AttributeOrder::Alphabetical && !tag.has_raw {
+            tag.attributes.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        Some(tag)
+    }
+
+    /// Writes the start tag on one line when it fits, or with one attribute
+    /// per line otherwise.
+    fn write_tag(&mut self, tag: &PendingTag, ending: &str) {
+        let width = self.indent_columns(self.level)
+            + 1
+            + tag.name.chars().count()
+            + tag
+                .attributes
+                .iter()
+                .map(|attr| 1 + attr.content.chars().count())
+                .sum::<usize>()
+            + ending.chars().count();
+        let wrap = self.verbatim == 0
+            && !tag.attributes.is_empty()
+            && self.options.max_width.is_some_and(|max| width > max);
+        self.write("<");
+        self.write(&tag.name);
+        if wrap {
+            for attr in tag.attributes.iter() {
+                self.buffer.push('\n');
+                self.write_indent(self.level + 1);
+                self.buffer.push_str(&attr.content);
+            }
+            self.buffer.push('\n');
+            self.write_indent(self.level);
+            self.buffer.push_str(ending.trim_start());
+        } else {
+            for attr in tag.attributes.iter() {
+                self.buffer.push(' ');
+                self.buffer.push_str(&attr.content);
+            }
+            self.buffer.push_str(ending);
+        }
+    }
+}
+
+impl Printer for Formatter {
+    #[inline]
+    fn push_new_line(&mut self) {
+        if self.verbatim > 0 {
+            return;
+        }
+        if !self.line_empty {
+            self.buffer.push('\n');
+            self.line_empty = true;
+        } else if !self.block_start {
+            self.blank_line = true;
+        }
+    }
+
+    /// The indentation is written with the next content, to avoid trailing
+    /// whitespaces.
+    #[inline]
+    fn push_indent(&mut self) {}
+
+    #[inline]
+    fn increase_indent(&mut self) {
+        self.level += 1;
+    }
+
+    #[inline]
+    fn decrease_indent(&mut self) {
+        self.level -= 1;
+    }
+
+    #[inline]
+    fn push(&mut self, value: char) {
+        self.write(value.encode_utf8(&mut [0; 4]));
+    }
+
+    #[inline]
+    fn push_str(&mut self, value: &str) {
+        self.write(value);
+    }
+
+    #[inline]
+    fn open_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        self.tag = Some(PendingTag {
+            name: name.to_string(),
+            attributes: Vec::new(),
+            has_raw: false,
+        });
+        Ok(())
+    }
+
+    fn close_tag(&mut self) {
+        if let Some(tag) = self.take_tag() {
+            self.write_tag(&tag, ">");
+            if self.verbatim == 0
+                && tag.name == "mj-text"
+                && self.options.text_reflow == TextReflow::Collapse
+            {
+                self.reflow = true;
+            } else if self.reflow && PREFORMATTED_TAGS.contains(&tag.name.as_str()) {
+                self.preformatted += 1;
+            }
+            if self.verbatim > 0 || is_verbatim_element(&tag.name) {
+                self.verbatim += 1;
+            }
+        }
+        self.block_start = true;
+    }
+
+    fn closed_tag(&mut self) {
+        if let Some(tag) = self.take_tag() {
+            match self.options.self_closing {
+                SelfClosing::Expanded if !is_void_element(&tag.name) => {
+                    self.write_tag(&tag, ">");
+                    self.buffer.push_str("</");
+                    self.buffer.push_str(&tag.name);
+                    self.buffer.push('>');
+                }
+                SelfClosing::Compact => self.write_tag(&tag, "/>"),
+                _ => self.write_tag(&tag, " />"),
+            }
+        }
+    }
+
+    #[inline]
+    fn end_tag<N: Display + ?Sized>(&mut self, name: &N) -> std::fmt::Result {
+        self.blank_line = false;
+        let name = name.to_string();
+        if self.reflow && PREFORMATTED_TAGS.contains(&name.as_str()) {
+            self.preformatted = self.preformatted.saturating_sub(1);
+        }
+        self.write(&format!("</{name}>"));
+        self.verbatim = self.verbatim.saturating_sub(1);
+        if self.verbatim == 0 {
+            self.reflow = false;
+        }
+        Ok(())
+    }
+
+    fn push_attribute<N: Display + ?Sized, V: Debug + ?Sized>(
+        &mut self,
+        name: &N,
+        value: &V,
+    ) -> std::fmt::Result {
+        if let Some(tag) = self.tag.as_mut() {
+            tag.attributes.push(PendingAttribute {
+                name: name.to_string(),
+                content: format!("{name}={value:?}"),
+            });
+        }
+        Ok(())
+    }
+
+    fn push_raw_attribute(&mut self, value: &str) {
+        if let Some(tag) = self.tag.as_mut() {
+            tag.has_raw = true;
+            tag.attributes.push(PendingAttribute {
+                name: value.to_string(),
+                content: value.to_string(),
+            });
+        }
+    }
+
+    /// The texts outside of the ending tags are only whitespaces between
+    /// the elements, they're replaced by the indentation.
+    fn push_text(&mut self, value: &str) {
+        if self.reflow && self.preformatted == 0 {
+            self.write_reflowed(value);
+            return;
+        }
+        if self.verbatim > 0 {
+            self.write(value);
+            return;
+        }
+        let value = value.trim();
+        if !value.is_empty() {
+            self.write_line(value);
+        }
+    }
+
+    /// The lines end up in the html, they're written as is.
+    fn push_lines(&mut self, value: &str) {
+        self.write(value);
+    }
+
+    #[inline]
+    fn inner(self) -> String {
+        self.buffer
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{AttributeOrder, FormatOptions, SelfClosing, TextReflow};
+    use crate::prelude::print::Printable;
+
+    fn format(template: &str, options: &FormatOptions) -> String {
+        let root = crate::parse(template).unwrap();
+        root.element.print_formatted(options).unwrap()
+    }
+
+    #[test]
+    fn should_be_stable() {
+        let options = [
+            FormatOptions::default(),
+            FormatOptions {
+                max_width: Some(40),
+                attribute_order: AttributeOrder::Alphabetical,
+                self_closing: SelfClosing::Expanded,
+                text_reflow: TextReflow::Collapse,
+                ..Default::default()
+            },
+            FormatOptions {
+                use_tabs: true,
+                max_width: None,
+                self_closing: SelfClosing::Compact,
+                ..Default::default()
+            },
+        ];
+        for (path, source) in crate::helper::compare::compared_templates() {
+            let root = crate::parse(&source).unwrap();
+            for opts in options.iter() {
+                let once = root.element.print_formatted(opts).unwrap();
+                assert_eq!(format(&once, opts), once, "{:?} with {:?}", path, opts);
+            }
+        }
+    }
+
+    #[test]
+    fn should_wrap_attributes() {
+        let template = r#"<mjml><mj-body><mj-section background-color="red" padding="10px 25px"><mj-column><mj-image src="https://example.com/image.png" alt="image" /></mj-column></mj-section></mj-body></mjml>"#;
+        let options = FormatOptions {
+            indent_width: 4,
+            max_width: Some(70),
+            ..Default::default()
+        };
+        assert_eq!(
+            format(template, &options),
+            r#"<mjml>
+    <mj-body>
+        <mj-section background-color="red" padding="10px 25px">
+            <mj-column>
+                <mj-image
+                    src="https://example.com/image.png"
+                    alt="image"
+                />
+            </mj-column>
+        </mj-section>
+    </mj-body>
+</mjml>
+"#
+        );
+    }
+
+    #[test]
+    fn should_apply_self_closing_style() {
+        let template =
+            "<mjml><mj-body><mj-spacer /><mj-raw><p>a<br>b</p></mj-raw></mj-body></mjml>";
+        let options = FormatOptions {
+            self_closing: SelfClosing::Expanded,
+            ..Default::default()
+        };
+        let output = format(template, &options);
+        assert!(output.contains("<mj-spacer></mj-spacer>"), "{}", output);
+        assert!(output.contains("<br />"), "{}", output);
+        let options = FormatOptions {
+            self_closing: SelfClosing::Compact,
+            ..Default::default()
+        };
+        let output = format(template, &options);
+        assert!(output.contains("<mj-spacer/>"), "{}", output);
+        assert!(output.contains("<br/>"), "{}", output);
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_render_the_same_html() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text>
+      Lorem ipsum <b>dolor</b>   sit amet,<br/>consectetur
+    </mj-text><mj-button href="/buy">  Buy <i>now</i></mj-button><mj-raw><pre>  a
+  b</pre><textarea>
+x</textarea></mj-raw></mj-column></mj-section><mj-wrapper><mj-section><mj-column><mj-divider /></mj-column></mj-section></mj-wrapper></mj-body></mjml>"#;
+        let opts = RenderOptions::default();
+        let root = crate::parse(template).unwrap();
+        let expected = root.element.render(&opts).unwrap();
+        let options = [
+            FormatOptions::default(),
+            FormatOptions {
+                max_width: Some(10),
+                attribute_order: AttributeOrder::Alphabetical,
+                self_closing: SelfClosing::Expanded,
+                ..Default::default()
+            },
+        ];
+        for options in options.iter() {
+            let formatted = format(template, options);
+            let result = crate::parse(&formatted)
+                .unwrap()
+                .element
+                .render(&opts)
+                .unwrap();
+            assert_eq!(result, expected, "{}", formatted);
+        }
+        let formatted = format(template, &FormatOptions::default());
+        assert!(
+            formatted.contains("  <mj-button href=\"/buy\">  Buy <i>now</i></mj-button>\n"),
+            "{}",
+            formatted
+        );
+        assert!(
+            formatted.contains("<mj-raw><pre>  a\n  b</pre><textarea>\nx</textarea></mj-raw>"),
+            "{}",
+            formatted
+        );
+    }
+
+    #[test]
+    fn should_reflow_the_text() {
+        let template = r#"<mjml><mj-body><mj-text>
+      Lorem   ipsum <b>dolor</b>   sit amet, consectetur adipiscing elit,
+   sed do eiusmod <pre>  a
+  b</pre> tempor</mj-text><mj-button>  Buy   now</mj-button></mj-body></mjml>"#;
+        let options = FormatOptions {
+            max_width: Some(40),
+            text_reflow: TextReflow::Collapse,
+            ..Default::default()
+        };
+        assert_eq!(
+            format(template, &options),
+            r#"<mjml>
+  <mj-body>
+    <mj-text> Lorem ipsum <b>dolor</b>
+      sit amet, consectetur adipiscing
+      elit, sed do eiusmod <pre>  a
+  b</pre> tempor</mj-text>
+    <mj-button>  Buy   now</mj-button>
+  </mj-body>
+</mjml>
+"#
+        );
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_render_the_same_html_as_the_sources() {
+        use crate::prelude::render::RenderOptions;
+
+        // the reflow only changes the whitespaces that don't show
+        fn collapse(html: &str) -> String {
+            html.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+        }
+
+        let opts = RenderOptions::default();
+        let reflow = FormatOptions {
+            text_reflow: TextReflow::Collapse,
+            ..Default::default()
+        };
+        for (path, source) in crate::helper::compare::compared_templates() {
+            let root = crate::parse(&source).unwrap();
+            let expected = root.element.render(&opts).unwrap();
+            let formatted = root
+                .element
+                .print_formatted(&FormatOptions::default())
+                .unwrap();
+            let result = crate::parse(&formatted).unwrap().element.render(&opts);
+            assert_eq!(result.unwrap(), expected, "{:?}", path);
+            let formatted = root.element.print_formatted(&reflow).unwrap();
+            let result = crate::parse(&formatted).unwrap().element.render(&opts);
+            assert_eq!(
+                collapse(&result.unwrap()),
+                collapse(&expected),
+                "{:?}",
+                path
+            );
+        }
+    }
+}
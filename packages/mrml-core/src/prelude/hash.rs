@@ -1,4 +1,4 @@
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 
@@ -69,6 +69,39 @@ where
     }
 }
 
+/// Unlike the one of [`IndexMap`], the comparison takes the order of the
+/// entries into account, like the printed elements would.
+impl<K, V> PartialEq for Map<K, V>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K, V> Eq for Map<K, V>
+where
+    K: Hash + Eq,
+    V: Eq,
+{
+}
+
+impl<K, V> Hash for Map<K, V>
+where
+    K: Hash + Eq,
+    V: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for (key, value) in self.iter() {
+            key.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
 pub type SetImpl<V> = IndexSet<V, HashImpl>;
 
 #[derive(Default, Debug, Clone)]
@@ -115,3 +148,26 @@ where
         Set(SetImpl::from_iter(iter))
     }
 }
+
+impl<V> PartialEq for Set<V>
+where
+    V: Hash + Eq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<V> Eq for Set<V> where V: Hash + Eq {}
+
+impl<V> Hash for Set<V>
+where
+    V: Hash + Eq,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
@@ -9,13 +9,24 @@ pub mod print;
 #[cfg(feature = "render")]
 pub mod render;
 
+#[cfg(any(feature = "parse", feature = "render"))]
+pub mod attributes;
+pub mod builder;
+#[cfg(feature = "parse")]
+pub mod cst;
+pub mod diff;
+#[cfg(feature = "print")]
+pub mod format;
 pub mod hash;
+pub mod normalize;
+pub mod select;
+pub mod visitor;
 
 pub trait StaticTag {
     fn static_tag() -> &'static str;
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Component<Tag, Attributes, Children> {
     pub tag: Tag,
     pub attributes: Attributes,
@@ -0,0 +1,279 @@
+//! Normalization of the component tree, to compare or hash templates
+//! regardless of their insignificant differences.
+//!
+//! The structural equality of the elements takes everything into account,
+//! including the order of the attributes and the whitespaces used to indent
+//! the template. Once normalized, the attributes are sorted by name and the
+//! whitespaces between the layout elements (sections, columns...) are removed.
+//! The content of the ending tags (`mj-text`, `mj-button`, `mj-raw`...) is
+//! kept as is, its whitespaces being rendered.
+//!
+//! ```rust
+//! use mrml::prelude::normalize::Normalize;
+//!
+//! let first = mrml::parse(r#"<mjml><mj-body><mj-section><mj-column><mj-text color="red" align="left">Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#).unwrap();
+//! let second = mrml::parse(r#"<mjml>
+//!   <mj-body>  <mj-section>  <mj-column>
+//!     <mj-text align="left" color="red">Hello</mj-text>
+//!   </mj-column>  </mj-section>  </mj-body>
+//! </mjml>"#).unwrap();
+//! assert!(first.element != second.element);
+//! assert!(first.element.normalized_eq(&second.element));
+//! ```
+
+use super::visitor::{VisitableMut, VisitorMut};
+use crate::mj_accordion::MjAccordion;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_group::MjGroup;
+use crate::mj_hero::MjHero;
+use crate::mj_image::MjImage;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadChild};
+use crate::mj_list::MjList;
+use crate::mj_list_item::MjListItem;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_raw::MjRawChild;
+use crate::mj_section::MjSection;
+use crate::mj_social::MjSocial;
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_video::MjVideo;
+use crate::mj_wrapper::MjWrapper;
+use crate::node::Node;
+use crate::text::Text;
+
+/// Children that can be a text.
+trait TextChild {
+    fn as_text(&self) -> Option<&Text>;
+}
+
+macro_rules! text_child {
+    ($($child:ty),*) => {
+        $(
+            impl TextChild for $child {
+                fn as_text(&self) -> Option<&Text> {
+                    match self {
+                        Self::Text(inner) => Some(inner),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+text_child!(MjBodyChild, MjIncludeBodyChild, MjIncludeHeadChild);
+
+/// Removes the texts made of whitespaces only, like the indentation between
+/// the sections of a body.
+fn normalize_layout<C: TextChild>(children: &mut Vec<C>) {
+    children.retain(|child| {
+        !child
+            .as_text()
+            .is_some_and(|text| text.inner_str().trim().is_empty())
+    });
+}
+
+/// [`VisitorMut`] normalizing the elements it visits.
+#[derive(Debug, Default)]
+pub struct Normalizer;
+
+macro_rules! normalize {
+    (attributes: $($leave:ident($element:ty)),* $(,)?) => {
+        $(
+            fn $leave(&mut self, element: &mut $element) {
+                element.attributes.sort_keys();
+            }
+        )*
+    };
+    (layout: $($leave:ident($element:ty)),* $(,)?) => {
+        $(
+            fn $leave(&mut self, element: &mut $element) {
+                element.attributes.sort_keys();
+                normalize_layout(&mut element.children);
+            }
+        )*
+    };
+}
+
+impl VisitorMut for Normalizer {
+    fn leave_mj_attributes_class(&mut self, element: &mut MjAttributesClass) {
+        element.attributes.others.sort_keys();
+    }
+
+    fn leave_mj_attributes_element(&mut self, element: &mut MjAttributesElement) {
+        element.attributes.sort_keys();
+    }
+
+    fn leave_mj_include_body(&mut self, element: &mut MjIncludeBody) {
+        normalize_layout(&mut element.0.children);
+    }
+
+    fn leave_mj_include_head(&mut self, element: &mut MjIncludeHead) {
+        normalize_layout(&mut element.0.children);
+    }
+
+    normalize!(
+        attributes: leave_mj_accordion(MjAccordion),
+        leave_mj_accordion_element(MjAccordionElement),
+        leave_mj_accordion_text(MjAccordionText),
+        leave_mj_accordion_title(MjAccordionTitle),
+        leave_mj_attributes_all(MjAttributesAll),
+        leave_mj_button(MjButton),
+        leave_mj_carousel(MjCarousel),
+        leave_mj_carousel_image(MjCarouselImage),
+        leave_mj_divider(MjDivider),
+        leave_mj_image(MjImage),
+        leave_mj_list(MjList),
+        leave_mj_list_item(MjListItem),
+        leave_mj_navbar(MjNavbar),
+        leave_mj_navbar_link(MjNavbarLink),
+        leave_mj_social(MjSocial),
+        leave_mj_social_element(MjSocialElement),
+        leave_mj_spacer(MjSpacer),
+        leave_mj_table(MjTable),
+        leave_mj_text(MjText),
+        leave_mj_video(MjVideo),
+        leave_node(Node<MjBodyChild>),
+        leave_raw_node(Node<MjRawChild>),
+    );
+
+    normalize!(
+        layout: leave_mj_body(MjBody),
+        leave_mj_column(MjColumn),
+        leave_mj_group(MjGroup),
+        leave_mj_hero(MjHero),
+        leave_mj_section(MjSection),
+        leave_mj_wrapper(MjWrapper),
+    );
+}
+
+/// Normalization of an element and its children, see the
+/// [module documentation](self).
+pub trait Normalize: VisitableMut + Clone + Sized {
+    fn normalize(&mut self) {
+        self.accept_mut(&mut Normalizer);
+    }
+
+    fn normalized(&self) -> Self {
+        let mut result = self.clone();
+        result.normalize();
+        result
+    }
+
+    /// Compares the elements once normalized.
+    fn normalized_eq(&self, other: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl<T: VisitableMut + Clone> Normalize for T {}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::Normalize;
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn should_compare_structurally() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><mj-text>Hello</mj-text></mj-column></mj-section></mj-body></mjml>"#;
+        let first = crate::parse(template).unwrap();
+        let second = crate::parse(template).unwrap();
+        assert_eq!(first.element, second.element);
+        assert_eq!(hash_of(&first.element), hash_of(&second.element));
+
+        let other = crate::parse(template.replace("Hello", "World")).unwrap();
+        assert_ne!(first.element, other.element);
+        assert_ne!(hash_of(&first.element), hash_of(&other.element));
+    }
+
+    #[test]
+    fn should_take_attribute_order_into_account() {
+        let first = crate::parse(
+            r#"<mjml><mj-body><mj-button href="/" color="red">Go</mj-button></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let second = crate::parse(
+            r#"<mjml><mj-body><mj-button color="red" href="/">Go</mj-button></mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert_ne!(first.element, second.element);
+        assert!(first.element.normalized_eq(&second.element));
+        assert_eq!(
+            hash_of(&first.element.normalized()),
+            hash_of(&second.element.normalized())
+        );
+    }
+
+    #[test]
+    fn should_ignore_whitespaces_between_layout_elements() {
+        let first = crate::parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text><b>Hello</b> World</mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        let second = crate::parse(
+            r#"<mjml>
+  <mj-body>  <mj-section>  <mj-column>
+        <mj-text><b>Hello</b> World</mj-text>
+  </mj-column>  </mj-section>  </mj-body>
+</mjml>"#,
+        )
+        .unwrap();
+        assert_ne!(first.element, second.element);
+        assert!(first.element.normalized_eq(&second.element));
+
+        // the whitespaces of the content are rendered
+        let third = crate::parse(
+            r#"<mjml><mj-body><mj-section><mj-column><mj-text> <b>Hello</b>  World </mj-text></mj-column></mj-section></mj-body></mjml>"#,
+        )
+        .unwrap();
+        assert!(!first.element.normalized_eq(&third.element));
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn should_keep_the_content_of_the_ending_tags() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column>
+<mj-text><span> Hello </span><b>  World  </b></mj-text>
+<mj-text><pre>
+  first
+    second
+</pre><textarea>  a
+  b</textarea></mj-text>
+<mj-raw><p>  raw  </p></mj-raw>
+</mj-column></mj-section></mj-body></mjml>"#;
+        let root = crate::parse(template).unwrap().element;
+        let normalized = root.normalized();
+        let opts = crate::prelude::render::RenderOptions::default();
+        let html = normalized.render(&opts).unwrap();
+        assert_eq!(html, root.render(&opts).unwrap());
+        assert!(html.contains("<span> Hello </span><b>  World  </b>"));
+        assert!(html.contains("<pre>\n  first\n    second\n</pre><textarea>  a\n  b</textarea>"));
+        assert!(html.contains("<p>  raw  </p>"));
+    }
+}
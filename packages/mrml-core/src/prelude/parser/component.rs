@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use xmlparser::StrSpan;
+
+use super::{Error, MrmlCursor};
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+
+/// Parse hook of a component that is not part of the mjml specification,
+/// registered in the [`ParserOptions`](super::ParserOptions) under its tag
+/// name.
+///
+/// The element is parsed like any html element, kept as a [`Node`] in the
+/// template, and then given to the hook, that can check it (a required
+/// attribute, the allowed children...) or complete it. How the element gets
+/// rendered is defined by the matching
+/// [`CustomComponent`](crate::prelude::render::CustomComponent).
+///
+/// ```rust
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::node::Node;
+/// use mrml::prelude::parser::{CustomParser, ParserOptions};
+///
+/// #[derive(Debug)]
+/// struct ProductCard;
+///
+/// impl CustomParser for ProductCard {
+///     fn parse(&self, element: Node<MjBodyChild>) -> Result<Node<MjBodyChild>, String> {
+///         if element.attributes.contains_key("sku") {
+///             Ok(element)
+///         } else {
+///             Err("a product card needs a sku".into())
+///         }
+///     }
+/// }
+///
+/// let mut opts = ParserOptions::default();
+/// opts.components.insert("acme-product-card".into(), Box::new(ProductCard));
+/// let template = r#"<mjml><mj-body><acme-product-card sku="42" /></mj-body></mjml>"#;
+/// assert!(mrml::parse_with_options(template, &opts).is_ok());
+/// let template = r#"<mjml><mj-body><acme-product-card /></mj-body></mjml>"#;
+/// assert!(mrml::parse_with_options(template, &opts).is_err());
+/// ```
+pub trait CustomParser: std::fmt::Debug + Send + Sync {
+    /// Checks or transforms the parsed element, the error being reported
+    /// with the position of the element.
+    fn parse(&self, element: Node<MjBodyChild>) -> Result<Node<MjBodyChild>, String>;
+}
+
+pub(crate) fn parse_custom_element<'a>(
+    components: &HashMap<String, Box<dyn CustomParser>>,
+    cursor: &MrmlCursor<'a>,
+    tag: StrSpan<'a>,
+    element: Node<MjBodyChild>,
+) -> Result<Node<MjBodyChild>, Error> {
+    match components.get(element.tag.as_str()) {
+        Some(component) => component
+            .parse(element)
+            .map_err(|reason| Error::InvalidCustomElement {
+                origin: cursor.origin(),
+                position: tag.into(),
+                reason,
+            }),
+        None => Ok(element),
+    }
+}
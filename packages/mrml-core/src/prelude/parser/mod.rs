@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use xmlparser::{StrSpan, Tokenizer};
 
@@ -14,10 +16,16 @@ pub mod memory_loader;
 pub mod multi_loader;
 pub mod noop_loader;
 
+mod component;
 mod output;
+mod placeholder;
 mod token;
 
+pub(crate) use component::parse_custom_element;
+pub use component::CustomParser;
 pub use output::*;
+pub use placeholder::Delimiters;
+pub(crate) use placeholder::MaskedSource;
 pub use token::*;
 
 #[derive(Clone, Debug)]
@@ -49,6 +57,13 @@ pub enum Error {
     },
     #[error("invalid attribute in {origin} at position {position}")]
     InvalidAttribute { origin: Origin, position: Span },
+    #[error("invalid value for attribute {:?} in {origin} at position {position}", source.name)]
+    InvalidAttributeValue {
+        origin: Origin,
+        position: Span,
+        #[source]
+        source: Box<super::attributes::AttributeError>,
+    },
     #[error("invalid format in {origin} at position {position}")]
     InvalidFormat { origin: Origin, position: Span },
     #[error("unexpected end of stream in {origin}")]
@@ -73,11 +88,27 @@ pub enum Error {
         #[source]
         source: IncludeLoaderError,
     },
+    /// Errors returned by a [`CustomParser`].
+    #[error("invalid custom element in {origin} at position {position}: {reason}")]
+    InvalidCustomElement {
+        origin: Origin,
+        position: Span,
+        reason: String,
+    },
 }
 
 #[derive(Debug)]
 pub struct ParserOptions {
     pub include_loader: Box<dyn loader::IncludeLoader>,
+    /// Delimiters of the templating language placeholders to keep as is in
+    /// the parsed template.
+    pub placeholders: Vec<Delimiters>,
+    /// Validates the values of the attributes having a typed representation,
+    /// see [`attributes`](crate::prelude::attributes). The values containing
+    /// a placeholder are not validated.
+    pub strict_attributes: bool,
+    /// Parse hooks of the custom components, by tag name.
+    pub components: HashMap<String, Box<dyn CustomParser>>,
 }
 
 #[allow(clippy::box_default)]
@@ -85,6 +116,9 @@ impl Default for ParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            placeholders: Vec::new(),
+            strict_attributes: false,
+            components: HashMap::new(),
         }
     }
 }
@@ -93,6 +127,15 @@ impl Default for ParserOptions {
 #[derive(Debug)]
 pub struct AsyncParserOptions {
     pub include_loader: Box<dyn loader::AsyncIncludeLoader + Send + Sync>,
+    /// Delimiters of the templating language placeholders to keep as is in
+    /// the parsed template.
+    pub placeholders: Vec<Delimiters>,
+    /// Validates the values of the attributes having a typed representation,
+    /// see [`attributes`](crate::prelude::attributes). The values containing
+    /// a placeholder are not validated.
+    pub strict_attributes: bool,
+    /// Parse hooks of the custom components, by tag name.
+    pub components: HashMap<String, Box<dyn CustomParser>>,
 }
 
 #[cfg(feature = "async")]
@@ -101,6 +144,9 @@ impl Default for AsyncParserOptions {
     fn default() -> Self {
         Self {
             include_loader: Box::new(noop_loader::NoopIncludeLoader),
+            placeholders: Vec::new(),
+            strict_attributes: false,
+            components: HashMap::new(),
         }
     }
 }
@@ -140,6 +186,9 @@ pub struct MrmlCursor<'a> {
     buffer: Vec<MrmlToken<'a>>,
     origin: Origin,
     warnings: Vec<Warning>,
+    source: &'a str,
+    masked: &'a str,
+    placeholders: &'a [Range<usize>],
 }
 
 impl<'a> MrmlCursor<'a> {
@@ -149,21 +198,34 @@ impl<'a> MrmlCursor<'a> {
             buffer: Default::default(),
             origin: Origin::Root,
             warnings: Default::default(),
+            source,
+            masked: source,
+            placeholders: &[],
+        }
+    }
+
+    pub(crate) fn from_masked(source: &'a MaskedSource<'_>) -> Self {
+        Self {
+            tokenizer: Tokenizer::from(source.masked.as_ref()),
+            buffer: Default::default(),
+            origin: Origin::Root,
+            warnings: Default::default(),
+            source: source.source,
+            masked: source.masked.as_ref(),
+            placeholders: source.placeholders.as_slice(),
         }
     }
 
     pub(crate) fn new_child<'b, O: Into<String>>(
         &self,
         origin: O,
-        source: &'b str,
+        source: &'b MaskedSource<'_>,
     ) -> MrmlCursor<'b> {
         MrmlCursor {
-            tokenizer: Tokenizer::from(source),
-            buffer: Default::default(),
             origin: Origin::Include {
                 path: origin.into(),
             },
-            warnings: Default::default(),
+            ..MrmlCursor::from_masked(source)
         }
     }
 
@@ -219,9 +281,13 @@ impl<'opts> ParseAttributes<Map<String, String>> for MrmlParser<'opts> {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<Map<String, String>, Error> {
-        parse_attributes_map(cursor)
+        if self.options.strict_attributes {
+            parse_validated_attributes_map(cursor, tag.as_str(), &self.options.placeholders)
+        } else {
+            parse_attributes_map(cursor)
+        }
     }
 }
 
@@ -296,9 +362,13 @@ impl ParseAttributes<Map<String, String>> for AsyncMrmlParser {
     fn parse_attributes(
         &self,
         cursor: &mut MrmlCursor<'_>,
-        _tag: &StrSpan<'_>,
+        tag: &StrSpan<'_>,
     ) -> Result<Map<String, String>, Error> {
-        parse_attributes_map(cursor)
+        if self.options.strict_attributes {
+            parse_validated_attributes_map(cursor, tag.as_str(), &self.options.placeholders)
+        } else {
+            parse_attributes_map(cursor)
+        }
     }
 }
 
@@ -330,7 +400,32 @@ pub(crate) fn parse_attributes_map(
 ) -> Result<Map<String, String>, Error> {
     let mut result = Map::new();
     while let Some(attr) = cursor.next_attribute()? {
-        result.insert(attr.local.to_string(), attr.value.to_string());
+        result.insert(attr.name(), attr.value.to_string());
+    }
+    Ok(result)
+}
+
+/// Parses the attributes and validates the ones having a typed representation.
+/// The values containing a placeholder are only known once the template is
+/// filled, so they're not validated.
+pub(crate) fn parse_validated_attributes_map(
+    cursor: &mut MrmlCursor<'_>,
+    tag: &str,
+    placeholders: &[Delimiters],
+) -> Result<Map<String, String>, Error> {
+    let mut result = Map::new();
+    while let Some(attr) = cursor.next_attribute()? {
+        let value = attr.value.as_str();
+        if !placeholders.iter().any(|item| item.is_in(value)) {
+            super::attributes::validate_attribute(tag, attr.local.as_str(), value).map_err(
+                |source| Error::InvalidAttributeValue {
+                    origin: cursor.origin(),
+                    position: attr.span.into(),
+                    source: Box::new(source),
+                },
+            )?;
+        }
+        result.insert(attr.name(), attr.value.to_string());
     }
     Ok(result)
 }
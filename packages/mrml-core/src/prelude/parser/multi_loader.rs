@@ -32,6 +32,7 @@ use crate::prelude::parser::loader::IncludeLoader;
 ///     .with_any(Box::<NoopIncludeLoader>::default());
 /// let opts = ParserOptions {
 ///     include_loader: Box::new(resolver),
+///     ..Default::default()
 /// };
 /// let json = r#"<mjml>
 ///   <mj-body>
@@ -62,6 +63,7 @@ use crate::prelude::parser::loader::IncludeLoader;
 ///     .with_any(Box::<NoopIncludeLoader>::default());
 /// let opts = AsyncParserOptions {
 ///     include_loader: Box::new(resolver),
+///     ..Default::default()
 /// };
 /// let json = r#"<mjml>
 ///   <mj-body>
@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Delimiters of the blocks of a templating language (Handlebars, Jinja,
+/// Liquid, etc) that should be kept as is when parsing a template.
+///
+/// ```rust
+/// use mrml::prelude::parser::{Delimiters, ParserOptions};
+/// use mrml::prelude::print::Printable;
+///
+/// let options = ParserOptions {
+///     placeholders: vec![Delimiters::new("{{", "}}"), Delimiters::new("{%", "%}")],
+///     ..Default::default()
+/// };
+/// let template = r#"<mjml><mj-body><mj-section>{% for item in items %}<mj-column><mj-button href="{{ item.url }}" {{ item.attrs }}>{{ item.name }}</mj-button></mj-column>{% endfor %}</mj-section></mj-body></mjml>"#;
+/// let root = mrml::parse_with_options(template, &options).expect("parse template");
+/// assert_eq!(root.element.print_dense().unwrap(), template);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delimiters {
+    pub open: Cow<'static, str>,
+    pub close: Cow<'static, str>,
+}
+
+impl Delimiters {
+    pub fn new<O, C>(open: O, close: C) -> Self
+    where
+        O: Into<Cow<'static, str>>,
+        C: Into<Cow<'static, str>>,
+    {
+        Self {
+            open: open.into(),
+            close: close.into(),
+        }
+    }
+}
+
+impl Delimiters {
+    /// Whether the value contains a placeholder with those delimiters.
+    pub(crate) fn is_in(&self, value: &str) -> bool {
+        value
+            .find(self.open.as_ref())
+            .is_some_and(|start| value[start + self.open.len()..].contains(self.close.as_ref()))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Content,
+    Tag,
+    Quoted(u8),
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if needle.is_empty() || from > haystack.len() {
+        return None;
+    }
+    haystack[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|index| index + from)
+}
+
+/// Template where the placeholders are replaced by some content of the same
+/// length that the tokenizer accepts: an empty attribute when the placeholder
+/// is in an element, some text otherwise. The positions of the placeholders
+/// are kept so that the cursor can read them back from the original template.
+pub(crate) struct MaskedSource<'a> {
+    pub(crate) source: &'a str,
+    pub(crate) masked: Cow<'a, str>,
+    pub(crate) placeholders: Vec<Range<usize>>,
+}
+
+impl<'a> MaskedSource<'a> {
+    pub(crate) fn new(source: &'a str, delimiters: &[Delimiters]) -> Self {
+        if delimiters.is_empty() {
+            return Self {
+                source,
+                masked: Cow::Borrowed(source),
+                placeholders: Vec::new(),
+            };
+        }
+
+        let bytes = source.as_bytes();
+        let mut masked = bytes.to_vec();
+        let mut placeholders = Vec::new();
+        let mut state = State::Content;
+        let mut index = 0;
+        while index < bytes.len() {
+            let rest = &bytes[index..];
+            match state {
+                State::Content if rest.starts_with(b"<!--") => {
+                    index = find(bytes, b"-->", index + 4).map_or(bytes.len(), |end| end + 3);
+                    continue;
+                }
+                State::Content if rest.starts_with(b"<![CDATA[") => {
+                    index = find(bytes, b"]]>", index + 9).map_or(bytes.len(), |end| end + 3);
+                    continue;
+                }
+                State::Content if rest[0] == b'<' => {
+                    state = State::Tag;
+                }
+                State::Tag if rest[0] == b'"' || rest[0] == b'\'' => {
+                    state = State::Quoted(rest[0]);
+                }
+                State::Tag if rest[0] == b'>' => {
+                    state = State::Content;
+                }
+                State::Quoted(quote) if rest[0] == quote => {
+                    state = State::Tag;
+                }
+                State::Content | State::Tag => {
+                    let found = delimiters
+                        .iter()
+                        .filter(|item| rest.starts_with(item.open.as_bytes()))
+                        .find_map(|item| {
+                            find(bytes, item.close.as_bytes(), index + item.open.len())
+                                .map(|end| index..end + item.close.len())
+                        });
+                    if let Some(range) = found {
+                        let target = &mut masked[range.clone()];
+                        if matches!(state, State::Content) {
+                            target.fill(b'_');
+                        } else if target.len() >= 4 {
+                            // written as an empty attribute `x--=""`
+                            let size = target.len();
+                            target.fill(b'-');
+                            target[0] = b'x';
+                            target[size - 3..].copy_from_slice(b"=\"\"");
+                        } else {
+                            index += 1;
+                            continue;
+                        }
+                        index = range.end;
+                        placeholders.push(range);
+                        continue;
+                    }
+                }
+                State::Quoted(_) => {}
+            }
+            index += 1;
+        }
+
+        // only ascii characters replace whole placeholders, so the result stays
+        // valid utf8
+        let masked = String::from_utf8(masked)
+            .map(Cow::Owned)
+            .unwrap_or(Cow::Borrowed(source));
+        Self {
+            source,
+            masked,
+            placeholders,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Delimiters, MaskedSource};
+
+    fn delimiters() -> Vec<Delimiters> {
+        vec![Delimiters::new("{{", "}}"), Delimiters::new("{%", "%}")]
+    }
+
+    #[test]
+    fn should_not_change_without_delimiters() {
+        let source = "<mj-text {{ attrs }}>{{ name }}</mj-text>";
+        let result = MaskedSource::new(source, &[]);
+        assert_eq!(result.masked, source);
+        assert!(result.placeholders.is_empty());
+    }
+
+    #[test]
+    fn should_mask_placeholders() {
+        let source = r#"<mj-text {{ attrs }} href="{{ url }}">{% if a < b %}{{ name }}<!-- {{ comment }} --></mj-text>"#;
+        let result = MaskedSource::new(source, &delimiters());
+        assert_eq!(
+            result.masked,
+            r#"<mj-text x-------="" href="{{ url }}">________________________<!-- {{ comment }} --></mj-text>"#
+        );
+        let found: Vec<_> = result
+            .placeholders
+            .iter()
+            .map(|range| &source[range.clone()])
+            .collect();
+        assert_eq!(found, vec!["{{ attrs }}", "{% if a < b %}", "{{ name }}"]);
+    }
+
+    #[test]
+    fn should_find_placeholders_in_values() {
+        let delimiters = Delimiters::new("{{", "}}");
+        assert!(delimiters.is_in("{{ size }}px"));
+        assert!(!delimiters.is_in("}} 12px {{"));
+        assert!(!delimiters.is_in("12px"));
+    }
+
+    #[test]
+    fn should_ignore_unclosed_placeholder() {
+        let source = "<mj-text>{{ name</mj-text>";
+        let result = MaskedSource::new(source, &delimiters());
+        assert_eq!(result.masked, source);
+        assert!(result.placeholders.is_empty());
+    }
+}
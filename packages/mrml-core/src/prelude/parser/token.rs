@@ -1,10 +1,12 @@
 use std::fmt::Display;
+use std::ops::Range;
 
-use xmlparser::{StrSpan, Token};
+use xmlparser::{StrSpan, Token, Tokenizer};
 
 use super::MrmlCursor;
+use crate::placeholder::{placeholder_attribute_name, PLACEHOLDER_ATTRIBUTE};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -60,6 +62,7 @@ pub(crate) enum MrmlToken<'a> {
     ElementClose(ElementClose<'a>),
     ElementEnd(ElementEnd<'a>),
     ElementStart(ElementStart<'a>),
+    Placeholder(Placeholder<'a>),
     Text(Text<'a>),
 }
 
@@ -118,14 +121,14 @@ impl<'a> MrmlToken<'a> {
 impl<'a> MrmlToken<'a> {
     pub fn span(&self) -> Span {
         match self {
-            Self::Attribute(item) => item.span,
-            Self::Comment(item) => item.span,
-            Self::ElementClose(item) => item.span,
-            Self::ElementEnd(item) => item.span,
-            Self::ElementStart(item) => item.span,
-            Self::Text(item) => item.text,
+            Self::Attribute(item) => item.span.into(),
+            Self::Comment(item) => item.span.into(),
+            Self::ElementClose(item) => item.span.into(),
+            Self::ElementEnd(item) => item.span.into(),
+            Self::ElementStart(item) => item.span.into(),
+            Self::Placeholder(item) => item.span,
+            Self::Text(item) => item.text.into(),
         }
-        .into()
     }
 }
 
@@ -138,6 +141,18 @@ pub(crate) struct Attribute<'a> {
     pub span: StrSpan<'a>,
 }
 
+impl<'a> Attribute<'a> {
+    /// The name of the attribute, the placeholders in place of an attribute
+    /// being named after their position.
+    pub fn name(&self) -> String {
+        if self.local.as_str() == PLACEHOLDER_ATTRIBUTE {
+            placeholder_attribute_name(self.span.start())
+        } else {
+            self.local.to_string()
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Comment<'a> {
     pub span: StrSpan<'a>,
@@ -166,11 +181,21 @@ pub(crate) struct ElementEnd<'a> {
     pub empty: bool,
 }
 
+#[derive(Debug)]
+pub(crate) struct Placeholder<'a> {
+    pub text: &'a str,
+    pub span: Span,
+}
+
 #[derive(Debug)]
 pub(crate) struct Text<'a> {
     pub text: StrSpan<'a>,
 }
 
+fn is_skipped_text(text: &str) -> bool {
+    text.starts_with('\n') && text.trim().is_empty()
+}
+
 impl<'a> super::MrmlCursor<'a> {
     fn read_next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         self.tokenizer
@@ -181,17 +206,88 @@ impl<'a> super::MrmlCursor<'a> {
                     source,
                 })
                 .and_then(|token| MrmlToken::parse(self, token))
+                .map(|token| self.unmask(token))
             })
             .and_then(|token| match token {
-                Ok(MrmlToken::Text(inner))
-                    if inner.text.starts_with('\n') && inner.text.trim().is_empty() =>
-                {
+                Ok(MrmlToken::Text(inner)) if is_skipped_text(&inner.text) => {
                     self.read_next_token()
                 }
                 other => Some(other),
             })
     }
 
+    fn placeholder_at(&self, start: usize) -> Option<Range<usize>> {
+        self.placeholders
+            .iter()
+            .find(|range| range.start == start)
+            .cloned()
+    }
+
+    /// Reads back the placeholders from the original template, replacing the
+    /// content the tokenizer got in place of them.
+    fn unmask(&mut self, token: MrmlToken<'a>) -> MrmlToken<'a> {
+        if self.placeholders.is_empty() {
+            return token;
+        }
+        match token {
+            MrmlToken::Attribute(inner) => match self.placeholder_at(inner.span.start()) {
+                Some(range) => MrmlToken::Attribute(Attribute {
+                    prefix: inner.prefix,
+                    local: StrSpan::from(PLACEHOLDER_ATTRIBUTE),
+                    value: StrSpan::from(&self.source[range]),
+                    span: inner.span,
+                }),
+                None => MrmlToken::Attribute(inner),
+            },
+            MrmlToken::Text(inner) => {
+                let text = inner.text.range();
+                let mut tokens = Vec::new();
+                let mut index = text.start;
+                for range in self
+                    .placeholders
+                    .iter()
+                    .filter(|range| range.start >= text.start && range.end <= text.end)
+                {
+                    if index < range.start {
+                        tokens.push(self.text_token(index..range.start));
+                    }
+                    tokens.push(MrmlToken::Placeholder(Placeholder {
+                        text: &self.source[range.clone()],
+                        span: Span {
+                            start: range.start,
+                            end: range.end,
+                        },
+                    }));
+                    index = range.end;
+                }
+                if tokens.is_empty() {
+                    return MrmlToken::Text(inner);
+                }
+                if index < text.end {
+                    tokens.push(self.text_token(index..text.end));
+                }
+                tokens.retain(|token| match token {
+                    MrmlToken::Text(inner) => !is_skipped_text(&inner.text),
+                    _ => true,
+                });
+                let first = tokens.remove(0);
+                self.buffer.extend(tokens.into_iter().rev());
+                first
+            }
+            other => other,
+        }
+    }
+
+    fn text_token(&self, range: Range<usize>) -> MrmlToken<'a> {
+        // the text around the placeholders is not masked, reading it again gives
+        // a text token with its position in the template
+        let text = match Tokenizer::from_fragment(self.masked, range.clone()).next() {
+            Some(Ok(Token::Text { text })) => text,
+            _ => StrSpan::from(&self.source[range]),
+        };
+        MrmlToken::Text(Text { text })
+    }
+
     pub(crate) fn next_token(&mut self) -> Option<Result<MrmlToken<'a>, super::Error>> {
         if let Some(item) = self.buffer.pop() {
             Some(Ok(item))
@@ -200,6 +296,20 @@ impl<'a> super::MrmlCursor<'a> {
         }
     }
 
+    fn is_text_next(&mut self) -> bool {
+        if self.placeholders.is_empty() {
+            return false;
+        }
+        match self.next_token() {
+            Some(Ok(token)) => {
+                let result = matches!(token, MrmlToken::Text(_) | MrmlToken::Placeholder(_));
+                self.rewind(token);
+                result
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn rewind(&mut self, token: MrmlToken<'a>) {
         self.buffer.push(token);
     }
@@ -273,7 +383,19 @@ impl<'a> super::MrmlCursor<'a> {
 
     pub(crate) fn next_text(&mut self) -> Result<Option<Text<'a>>, super::Error> {
         match self.next_token() {
-            Some(Ok(MrmlToken::Text(inner))) => Ok(Some(inner)),
+            Some(Ok(MrmlToken::Text(inner))) if !self.is_text_next() => Ok(Some(inner)),
+            Some(Ok(token @ (MrmlToken::Text(_) | MrmlToken::Placeholder(_)))) => {
+                // the text got split around the placeholders, so it's read as a
+                // whole from the original template
+                let start = token.span().start;
+                let mut end = token.span().end;
+                while self.is_text_next() {
+                    end = self.assert_next()?.span().end;
+                }
+                Ok(Some(Text {
+                    text: StrSpan::from(&self.source[start..end]),
+                }))
+            }
             Some(Ok(other)) => {
                 self.rewind(other);
                 Ok(None)
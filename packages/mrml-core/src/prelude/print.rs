@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display, Write};
 use std::marker::PhantomData;
 
+use crate::placeholder::is_placeholder_attribute;
 use crate::prelude::hash::Map;
 
 pub trait PrintableAttributes {
@@ -16,7 +17,11 @@ impl PrintableAttributes for () {
 impl PrintableAttributes for Map<String, String> {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
         for (name, value) in self.iter() {
-            printer.push_attribute(name.as_str(), value.as_str())?;
+            if is_placeholder_attribute(name) {
+                printer.push_raw_attribute(value);
+            } else {
+                printer.push_attribute(name.as_str(), value.as_str())?;
+            }
         }
         Ok(())
     }
@@ -69,6 +74,7 @@ impl<T: StaticTag, A: PrintableAttributes, C: PrintableChildren> PrintableElemen
     }
 }
 
+use super::format::{FormatOptions, Formatter};
 use super::StaticTag;
 use crate::comment::Comment;
 use crate::mj_accordion::{MjAccordion, MjAccordionChild};
@@ -91,6 +97,8 @@ use crate::mj_hero::MjHero;
 use crate::mj_image::MjImage;
 use crate::mj_include::body::MjIncludeBody;
 use crate::mj_include::head::MjIncludeHead;
+use crate::mj_list::{MjList, MjListChild};
+use crate::mj_list_item::MjListItem;
 use crate::mj_navbar::{MjNavbar, MjNavbarChild};
 use crate::mj_navbar_link::MjNavbarLink;
 use crate::mj_preview::MjPreview;
@@ -103,8 +111,10 @@ use crate::mj_style::MjStyle;
 use crate::mj_table::MjTable;
 use crate::mj_text::MjText;
 use crate::mj_title::MjTitle;
+use crate::mj_video::MjVideo;
 use crate::mj_wrapper::MjWrapper;
 use crate::node::Node;
+use crate::placeholder::Placeholder;
 use crate::text::Text;
 
 #[enum_dispatch::enum_dispatch(
@@ -113,6 +123,7 @@ use crate::text::Text;
     MjBodyChild,
     MjCarouselChild,
     MjHeadChild,
+    MjListChild,
     MjNavbarChild,
     MjRawChild,
     MjSocialChild
@@ -131,6 +142,12 @@ pub trait Printable {
         self.print(&mut p)?;
         Ok(p.inner())
     }
+
+    fn print_formatted(&self, options: &FormatOptions) -> Result<String, std::fmt::Error> {
+        let mut p = Formatter::new(options.clone());
+        self.print(&mut p)?;
+        Ok(p.inner())
+    }
 }
 
 pub trait PrintableElement {
@@ -190,6 +207,31 @@ pub trait Printer {
         name: &N,
         value: &V,
     ) -> std::fmt::Result;
+    /// Attribute printed as is, like a templating placeholder.
+    fn push_raw_attribute(&mut self, value: &str) {
+        self.push(' ');
+        self.push_str(value);
+    }
+
+    /// Text node, on its own line.
+    fn push_text(&mut self, value: &str) {
+        self.push_indent();
+        self.push_str(value);
+        self.push_new_line();
+    }
+
+    /// Content of an element split in lines, like the css of a `mj-style`.
+    fn push_lines(&mut self, value: &str) {
+        self.push_new_line();
+        self.increase_indent();
+        for line in value.split('\n') {
+            self.push_indent();
+            self.push_str(line.trim());
+            self.push_new_line();
+        }
+        self.decrease_indent();
+        self.push_indent();
+    }
 
     fn inner(self) -> String;
 }
@@ -1,8 +1,16 @@
 use std::fmt::{Debug, Display, Write};
+use std::sync::Arc;
+
+use super::{DataScope, DataSource, RenderHook};
 
 #[derive(Debug, Default)]
 pub struct RenderBuffer {
     inner: String,
+    hook: Option<Arc<dyn RenderHook>>,
+    // the components being rendered, only tracked when there is a hook
+    components: Vec<String>,
+    // the data interpolated in the texts and the attributes when written
+    pub(crate) data: DataScope,
 }
 
 impl std::fmt::Write for RenderBuffer {
@@ -23,8 +31,61 @@ impl std::fmt::Write for RenderBuffer {
 }
 
 impl RenderBuffer {
+    pub fn with_hook(hook: Option<Arc<dyn RenderHook>>) -> Self {
+        Self {
+            inner: String::new(),
+            hook,
+            components: Vec::new(),
+            data: DataScope::default(),
+        }
+    }
+
+    pub(crate) fn with_data(mut self, data: Option<Arc<dyn DataSource>>) -> Self {
+        self.data = DataScope::new(data);
+        self
+    }
+
+    pub fn hook(&self) -> Option<&Arc<dyn RenderHook>> {
+        self.hook.as_ref()
+    }
+
+    /// Name of the component being rendered, only known when there is a hook.
+    pub fn component(&self) -> Option<&str> {
+        self.components.last().map(String::as_str)
+    }
+
+    pub(crate) fn enter_component(&mut self, name: &str) {
+        if self.hook.is_some() {
+            self.components.push(name.to_string());
+        }
+    }
+
+    pub(crate) fn leave_component(&mut self) {
+        if self.hook.is_some() {
+            self.components.pop();
+        }
+    }
+
+    /// Takes the content of the buffer, leaving an empty one with the same
+    /// hook and data in its place.
+    pub(crate) fn take(&mut self) -> Self {
+        let mut empty = Self::with_hook(self.hook.clone());
+        empty.data = self.data.clone();
+        std::mem::replace(self, empty)
+    }
+
+    /// Writes the value, with the bound data interpolated.
     #[inline]
     pub fn push_str(&mut self, value: &str) {
+        match self.data.interpolate(value) {
+            Some(result) => self.inner.push_str(&result),
+            None => self.inner.push_str(value),
+        }
+    }
+
+    /// Writes the value as is, like a comment or a templating placeholder.
+    #[inline]
+    pub(crate) fn push_verbatim(&mut self, value: &str) {
         self.inner.push_str(value);
     }
 
@@ -39,6 +100,13 @@ impl RenderBuffer {
         key: &K,
         value: &V,
     ) -> std::fmt::Result {
+        if self.data.is_enabled() {
+            let value = format!("{value:?}");
+            if let Some(result) = self.data.interpolate(&value) {
+                return write!(&mut self.inner, " {key}={result}");
+            }
+            return write!(&mut self.inner, " {key}={value}");
+        }
         write!(&mut self.inner, " {key}={value:?}")
     }
 
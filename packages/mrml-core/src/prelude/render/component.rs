@@ -0,0 +1,318 @@
+use super::{Error, Render, RenderContext, RenderCursor, Renderable, Renderer};
+use crate::helper::size::Pixel;
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+
+/// A component that is not part of the mjml specification, registered in the
+/// [`RenderOptions`](super::RenderOptions) under its tag name.
+///
+/// The element is kept as a [`Node`] in the template, so it can be printed or
+/// serialized like any other element, and gets expanded into regular body
+/// elements (`mj-*` components or raw html) when rendered. The element can be
+/// checked when parsing the template with a
+/// [`CustomParser`](crate::prelude::parser::CustomParser).
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use mrml::mj_body::MjBodyChild;
+/// use mrml::prelude::render::{CustomComponent, CustomElement, Error, RenderOptions};
+///
+/// #[derive(Debug)]
+/// struct ProductCard;
+///
+/// impl CustomComponent for ProductCard {
+///     fn default_attribute(&self, key: &str) -> Option<&'static str> {
+///         match key {
+///             "name" => Some("Unknown product"),
+///             _ => None,
+///         }
+///     }
+///
+///     fn expand(&self, element: &CustomElement<'_, '_>) -> Result<Vec<MjBodyChild>, Error> {
+///         let name = element.attribute("name").unwrap_or_default();
+///         let template = format!("<mjml><mj-body><mj-text>{name}</mj-text></mj-body></mjml>");
+///         let root = mrml::parse(template).map_err(|err| Error::Custom(err.to_string()))?;
+///         Ok(root.element.body().map(|body| body.children.clone()).unwrap_or_default())
+///     }
+/// }
+///
+/// let mut opts = RenderOptions::default();
+/// opts.components.insert("acme-product-card".into(), Box::new(ProductCard));
+/// let root = mrml::parse(r#"<mjml><mj-body><mj-section><mj-column>
+///   <acme-product-card name="Hammer" />
+/// </mj-column></mj-section></mj-body></mjml>"#).expect("parse template");
+/// let html = root.element.render(&opts).expect("render template");
+/// assert!(html.contains("Hammer"));
+/// assert!(!html.contains("acme-product-card"));
+/// # }
+/// ```
+pub trait CustomComponent: std::fmt::Debug + Send + Sync {
+    fn default_attribute(&self, _key: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// Builds the elements that get rendered in place of the custom element.
+    fn expand(&self, element: &CustomElement<'_, '_>) -> Result<Vec<MjBodyChild>, Error>;
+}
+
+/// The custom element being expanded, with its attributes resolved like any
+/// other component: `mj-class`, `mj-attributes` and the component defaults.
+pub struct CustomElement<'a, 'root> {
+    renderer: &'a dyn Render<'root>,
+    element: &'root Node<MjBodyChild>,
+    container_width: Option<Pixel>,
+}
+
+impl<'a, 'root> CustomElement<'a, 'root> {
+    pub fn tag(&self) -> &'root str {
+        self.element.tag.as_str()
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.renderer.attribute(key)
+    }
+
+    pub fn children(&self) -> &'root [MjBodyChild] {
+        &self.element.children
+    }
+
+    /// Width, in pixels, of the element containing the custom element.
+    pub fn container_width(&self) -> Option<f32> {
+        self.container_width.map(|width| width.value())
+    }
+}
+
+pub(crate) type CustomRenderer<'root> =
+    Renderer<'root, Node<MjBodyChild>, &'root dyn CustomComponent>;
+
+/// Renderer of an html element of the body, expanded when its tag is the one
+/// of a custom component.
+pub(crate) fn node_renderer<'render, 'root: 'render>(
+    context: &'root RenderContext<'root>,
+    element: &'root Node<MjBodyChild>,
+) -> Box<dyn Render<'root> + 'render> {
+    match context.options.components.get(element.tag.as_str()) {
+        Some(component) => Box::new(CustomRenderer::new(context, element, component.as_ref())),
+        None => element.renderer(context),
+    }
+}
+
+impl<'root> Render<'root> for CustomRenderer<'root> {
+    fn tag(&self) -> Option<&str> {
+        Some(self.element.tag.as_str())
+    }
+
+    fn raw_attribute(&self, key: &str) -> Option<&'root str> {
+        self.element.attributes.get(key).map(|v| v.as_str())
+    }
+
+    fn default_attribute(&self, key: &str) -> Option<&'static str> {
+        self.extra.default_attribute(key)
+    }
+
+    fn context(&self) -> &'root RenderContext<'root> {
+        self.context
+    }
+
+    fn set_container_width(&mut self, width: Option<Pixel>) {
+        self.container_width = width;
+    }
+
+    fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
+    fn set_siblings(&mut self, count: usize) {
+        self.siblings = count;
+    }
+
+    fn set_raw_siblings(&mut self, count: usize) {
+        self.raw_siblings = count;
+    }
+
+    fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        self.warn_attributes(cursor);
+        let children = self.extra.expand(&CustomElement {
+            renderer: self,
+            element: self.element,
+            container_width: self.container_width,
+        })?;
+        // the expanded elements take the place of the custom element among
+        // its siblings
+        let siblings = (self.siblings + children.len()).saturating_sub(1);
+        let raw_siblings = self.raw_siblings + children.iter().filter(|c| c.is_raw()).count();
+        for (index, child) in children.iter().enumerate() {
+            let mut renderer = child.renderer(self.context);
+            renderer.set_mj_class_scope(self.children_mj_class_scope());
+            renderer.set_container_width(self.container_width);
+            renderer.set_index(self.index + index);
+            renderer.set_siblings(siblings);
+            renderer.set_raw_siblings(raw_siblings);
+            renderer.render_component(cursor)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{CustomComponent, CustomElement, Error};
+    use crate::mj_body::MjBodyChild;
+    use crate::mjml::Mjml;
+    use crate::node::Node;
+    use crate::prelude::render::RenderOptions;
+
+    #[derive(Debug)]
+    struct Greeting;
+
+    impl CustomComponent for Greeting {
+        fn default_attribute(&self, key: &str) -> Option<&'static str> {
+            match key {
+                "name" => Some("world"),
+                "color" => Some("black"),
+                _ => None,
+            }
+        }
+
+        fn expand(&self, element: &CustomElement<'_, '_>) -> Result<Vec<MjBodyChild>, Error> {
+            let template = format!(
+                r#"<mjml><mj-body><mj-text color="{}">Hello {}!</mj-text></mj-body></mjml>"#,
+                element.attribute("color").unwrap_or_default(),
+                element.attribute("name").unwrap_or_default(),
+            );
+            let root = Mjml::parse(template).map_err(|err| Error::Custom(err.to_string()))?;
+            Ok(root.element.children.body.unwrap().children)
+        }
+    }
+
+    fn options() -> RenderOptions {
+        let mut opts = RenderOptions::default();
+        opts.components
+            .insert("acme-greeting".into(), Box::new(Greeting));
+        opts
+    }
+
+    #[test]
+    fn should_expand_with_defaults() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><acme-greeting /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&options()).unwrap();
+        assert!(result.contains("Hello world!"));
+        assert!(result.contains("color:black"));
+        assert!(!result.contains("acme-greeting"));
+    }
+
+    #[test]
+    fn should_expand_with_attributes() {
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-attributes>
+      <acme-greeting name="Jane" />
+      <mj-class name="red" color="red" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body>
+    <mj-section><mj-column><acme-greeting mj-class="red" /></mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&options()).unwrap();
+        assert!(result.contains("Hello Jane!"));
+        assert!(result.contains("color:red"));
+    }
+
+    #[test]
+    fn should_render_as_node_when_not_registered() {
+        let template = r#"<mjml><mj-body><mj-section><mj-column><acme-greeting /></mj-column></mj-section></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.contains("<acme-greeting>"));
+    }
+
+    #[test]
+    fn should_expand_in_included_template() {
+        use crate::prelude::parser::memory_loader::MemoryIncludeLoader;
+        use crate::prelude::parser::ParserOptions;
+
+        let resolver = MemoryIncludeLoader::from(vec![(
+            "greeting.mjml",
+            r#"<mj-section><mj-column><acme-greeting name="Jane" /></mj-column></mj-section>"#,
+        )]);
+        let opts = ParserOptions {
+            include_loader: Box::new(resolver),
+            ..Default::default()
+        };
+        let template = r#"<mjml><mj-body><mj-include path="greeting.mjml" /></mj-body></mjml>"#;
+        let root = Mjml::parse_with_options(template, &opts).unwrap();
+        let result = root.element.render(&options()).unwrap();
+        assert!(result.contains("Hello Jane!"));
+        assert!(!result.contains("acme-greeting"));
+    }
+
+    #[derive(Debug)]
+    struct GreetingParser;
+
+    impl crate::prelude::parser::CustomParser for GreetingParser {
+        fn parse(&self, mut element: Node<MjBodyChild>) -> Result<Node<MjBodyChild>, String> {
+            if element.attributes.contains_key("color") {
+                return Err("the color comes from the mj-class".into());
+            }
+            element
+                .attributes
+                .entry("mj-class".into())
+                .or_insert_with(|| "greeting".into());
+            Ok(element)
+        }
+    }
+
+    #[test]
+    fn should_call_the_parse_hook() {
+        use crate::prelude::parser::{Error as ParserError, ParserOptions};
+
+        let mut opts = ParserOptions::default();
+        opts.components
+            .insert("acme-greeting".into(), Box::new(GreetingParser));
+        let template = r#"<mjml>
+  <mj-head><mj-attributes><mj-class name="greeting" color="blue" /></mj-attributes></mj-head>
+  <mj-body><mj-section><mj-column><acme-greeting /></mj-column></mj-section></mj-body>
+</mjml>"#;
+        let root = Mjml::parse_with_options(template, &opts).unwrap();
+        let result = root.element.render(&options()).unwrap();
+        assert!(result.contains("color:blue"));
+
+        let template = r#"<mjml><mj-body><acme-greeting color="red" /></mj-body></mjml>"#;
+        let Err(error) = Mjml::parse_with_options(template, &opts) else {
+            panic!("the parse hook should reject the element");
+        };
+        assert!(matches!(error, ParserError::InvalidCustomElement { .. }));
+        assert_eq!(
+            error.to_string(),
+            "invalid custom element in root template at position 16:29: the color comes from the mj-class"
+        );
+    }
+
+    #[cfg(feature = "print")]
+    #[test]
+    fn should_print_custom_element() {
+        use crate::prelude::print::Printable;
+
+        let template = r#"<mjml><mj-body><acme-greeting name="Jane" /></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let printed = root.element.print_dense().unwrap();
+        assert!(printed.contains(r#"<acme-greeting name="Jane"#));
+        assert!(Mjml::parse(printed).is_ok());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn should_serialize_custom_element() {
+        let template = r#"<mjml><mj-body><acme-greeting name="Jane" /></mj-body></mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let json = serde_json::to_string(&root.element).unwrap();
+        assert!(json.contains(r#"{"type":"acme-greeting","attributes":{"name":"Jane"}"#));
+        let parsed: Mjml = serde_json::from_str(&json).unwrap();
+        let result = parsed.render(&options()).unwrap();
+        assert!(result.contains("Hello Jane!"));
+    }
+}
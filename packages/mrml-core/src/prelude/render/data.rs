@@ -0,0 +1,550 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use super::RenderBuffer;
+use crate::mj_body::MjBodyChild;
+use crate::node::Node;
+use crate::prelude::hash::Map;
+
+/// Tag of the element rendering its children only when the value at the path
+/// given by its `condition` attribute is truthy.
+pub const MJ_IF: &str = "mj-if";
+/// Tag of the element rendering its children once for each item of the list
+/// at the path given by its `items` attribute, the current item being
+/// available under the name given by its `as` attribute (`item` by default).
+pub const MJ_EACH: &str = "mj-each";
+
+/// Value provided by a [`DataSource`].
+#[derive(Clone, Debug)]
+pub enum DataValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    List(Vec<DataValue>),
+    Object(Map<String, DataValue>),
+}
+
+impl DataValue {
+    /// Whether the value enables the content of an `mj-if`: everything but
+    /// `null`, `false`, `0`, the empty strings and the empty lists.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Self::Null => false,
+            Self::Bool(value) => *value,
+            Self::Number(value) => *value != 0.0,
+            Self::String(value) => !value.is_empty(),
+            Self::List(items) => !items.is_empty(),
+            Self::Object(_) => true,
+        }
+    }
+
+    /// Text written in place of an interpolated value, empty for `null`, the
+    /// lists and the objects.
+    pub fn as_text(&self) -> Cow<'_, str> {
+        match self {
+            Self::Null | Self::List(_) | Self::Object(_) => Cow::Borrowed(""),
+            Self::Bool(value) => Cow::Borrowed(if *value { "true" } else { "false" }),
+            Self::Number(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                Cow::Owned(format!("{}", *value as i64))
+            }
+            Self::Number(value) => Cow::Owned(value.to_string()),
+            Self::String(value) => Cow::Borrowed(value.as_str()),
+        }
+    }
+
+    fn lookup(&self, path: &str) -> Option<&DataValue> {
+        path.split('.')
+            .filter(|key| !key.is_empty())
+            .try_fold(self, |value, key| match value {
+                Self::Object(entries) => entries.get(key),
+                Self::List(items) => key.parse::<usize>().ok().and_then(|index| items.get(index)),
+                _ => None,
+            })
+    }
+}
+
+impl From<&str> for DataValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for DataValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<bool> for DataValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for DataValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl<V: Into<DataValue>> From<Vec<V>> for DataValue {
+    fn from(value: Vec<V>) -> Self {
+        Self::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<&serde_json::Value> for DataValue {
+    fn from(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Self::Null,
+            serde_json::Value::Bool(value) => Self::Bool(*value),
+            serde_json::Value::Number(value) => Self::Number(value.as_f64().unwrap_or_default()),
+            serde_json::Value::String(value) => Self::String(value.clone()),
+            serde_json::Value::Array(items) => Self::List(items.iter().map(Self::from).collect()),
+            serde_json::Value::Object(entries) => Self::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Data bound to a template when rendering it, set in the
+/// [`RenderOptions`](super::RenderOptions).
+///
+/// The values are interpolated in the texts, the attribute values, the
+/// `mj-title` and the `mj-preview` with `{{ path }}`, escaping the html
+/// characters, or with `{{{ path }}}` to keep them as is. The `mj-if` and
+/// `mj-each` elements control which content gets rendered.
+///
+/// ```rust
+/// # #[cfg(all(feature = "parse", feature = "json"))]
+/// # {
+/// use std::sync::Arc;
+/// use mrml::prelude::render::RenderOptions;
+///
+/// let template = r#"<mjml><mj-body><mj-section><mj-column>
+///   <mj-text>Hello {{ user.name }}!</mj-text>
+///   <mj-if condition="user.premium"><mj-text>Thanks for your support</mj-text></mj-if>
+///   <mj-each items="products" as="product">
+///     <mj-button href="{{ product.url }}">{{ product.name }}</mj-button>
+///   </mj-each>
+/// </mj-column></mj-section></mj-body></mjml>"#;
+/// let root = mrml::parse(template).expect("parse template");
+/// let opts = RenderOptions {
+///     data: Some(Arc::new(serde_json::json!({
+///         "user": { "name": "<Jane>", "premium": false },
+///         "products": [{ "name": "Hammer", "url": "https://example.com/hammer" }],
+///     }))),
+///     ..Default::default()
+/// };
+/// let html = root.element.render(&opts).expect("render template");
+/// assert!(html.contains("Hello &lt;Jane&gt;!"));
+/// assert!(!html.contains("Thanks for your support"));
+/// assert!(html.contains("https://example.com/hammer"));
+/// # }
+/// ```
+pub trait DataSource: std::fmt::Debug + Send + Sync {
+    /// Value at the given dotted path, like `user.name` or `products.0.url`.
+    fn get(&self, path: &str) -> Option<DataValue>;
+}
+
+impl DataSource for DataValue {
+    fn get(&self, path: &str) -> Option<DataValue> {
+        self.lookup(path).cloned()
+    }
+}
+
+#[cfg(feature = "json")]
+impl DataSource for serde_json::Value {
+    fn get(&self, path: &str) -> Option<DataValue> {
+        path.split('.')
+            .filter(|key| !key.is_empty())
+            .try_fold(self, |value, key| match value {
+                serde_json::Value::Object(entries) => entries.get(key),
+                serde_json::Value::Array(items) => {
+                    key.parse::<usize>().ok().and_then(|index| items.get(index))
+                }
+                _ => None,
+            })
+            .map(DataValue::from)
+    }
+}
+
+/// Data bound while rendering: the data source of the options and the items of
+/// the `mj-each` elements being rendered, the closest last.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DataScope {
+    data: Option<Arc<dyn DataSource>>,
+    items: Vec<(String, DataValue)>,
+}
+
+impl DataScope {
+    pub(crate) fn new(data: Option<Arc<dyn DataSource>>) -> Self {
+        Self {
+            data,
+            items: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.data.is_some()
+    }
+
+    fn get(&self, path: &str) -> Option<DataValue> {
+        let path = path.trim();
+        for (name, value) in self.items.iter().rev() {
+            match path.strip_prefix(name.as_str()) {
+                Some("") => return Some(value.clone()),
+                Some(rest) if rest.starts_with('.') => return value.lookup(rest).cloned(),
+                _ => {}
+            }
+        }
+        self.data.as_ref().and_then(|data| data.get(path))
+    }
+
+    /// Replaces the `{{ path }}` and `{{{ path }}}` of the input by their value,
+    /// `None` when there is nothing to replace.
+    pub(crate) fn interpolate(&self, input: &str) -> Option<String> {
+        if !self.is_enabled() || !input.contains("{{") {
+            return None;
+        }
+        let mut result = String::with_capacity(input.len());
+        let mut rest = input;
+        while let Some(start) = rest.find("{{") {
+            result.push_str(&rest[..start]);
+            let (raw, open, close) = if rest[start..].starts_with("{{{") {
+                (true, 3, "}}}")
+            } else {
+                (false, 2, "}}")
+            };
+            let Some(end) = rest[start + open..].find(close) else {
+                rest = &rest[start..];
+                break;
+            };
+            let path = &rest[start + open..start + open + end];
+            if let Some(value) = self.get(path) {
+                if raw {
+                    result.push_str(&value.as_text());
+                } else {
+                    push_escaped(&mut result, &value.as_text());
+                }
+            }
+            rest = &rest[start + open + end + close.len()..];
+        }
+        result.push_str(rest);
+        Some(result)
+    }
+
+    /// Items to render the children of an `mj-if` or `mj-each` element with,
+    /// once per entry, `None` when the element is none of them. Without data,
+    /// the condition is never met and there is no item to go through.
+    pub(crate) fn iterations<T>(
+        &self,
+        element: &Node<T>,
+    ) -> Option<Vec<Option<(String, DataValue)>>> {
+        match element.tag.as_str() {
+            MJ_IF => {
+                let enabled = element
+                    .attributes
+                    .get("condition")
+                    .and_then(|path| self.get(path))
+                    .is_some_and(|value| value.is_truthy());
+                Some(if enabled { vec![None] } else { Vec::new() })
+            }
+            MJ_EACH => {
+                let name = element
+                    .attributes
+                    .get("as")
+                    .map(|name| name.as_str())
+                    .unwrap_or("item");
+                let items = match element
+                    .attributes
+                    .get("items")
+                    .and_then(|path| self.get(path))
+                {
+                    Some(DataValue::List(items)) => items,
+                    _ => Vec::new(),
+                };
+                Some(
+                    items
+                        .into_iter()
+                        .map(|item| Some((name.to_string(), item)))
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn enter(&mut self, items: impl IntoIterator<Item = (String, DataValue)>) -> usize {
+        let mark = self.items.len();
+        self.items.extend(items);
+        mark
+    }
+
+    pub(crate) fn leave(&mut self, mark: usize) {
+        self.items.truncate(mark);
+    }
+
+    fn bind_children_into<'root>(
+        &mut self,
+        children: &'root [MjBodyChild],
+        base: usize,
+        output: &mut Vec<BoundChild<'root>>,
+    ) {
+        for element in children {
+            let flow = match element {
+                MjBodyChild::Node(node) => self.iterations(node).map(|flow| (node, flow)),
+                _ => None,
+            };
+            match flow {
+                Some((node, iterations)) => {
+                    for item in iterations {
+                        let mark = self.enter(item);
+                        self.bind_children_into(&node.children, base, output);
+                        self.leave(mark);
+                    }
+                }
+                None => output.push(BoundChild {
+                    element,
+                    items: self.items[base..].to_vec(),
+                }),
+            }
+        }
+    }
+}
+
+fn push_escaped(buffer: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => buffer.push_str("&amp;"),
+            '<' => buffer.push_str("&lt;"),
+            '>' => buffer.push_str("&gt;"),
+            '"' => buffer.push_str("&quot;"),
+            '\'' => buffer.push_str("&#39;"),
+            other => buffer.push(other),
+        }
+    }
+}
+
+/// Child of a layout element as it gets rendered: the `mj-if` and `mj-each`
+/// elements are replaced by their content, the items of the `mj-each` elements
+/// being kept along.
+pub(crate) struct BoundChild<'root> {
+    pub element: &'root MjBodyChild,
+    items: Vec<(String, DataValue)>,
+}
+
+impl RenderBuffer {
+    /// Children of a layout element, which needs to know how many children it
+    /// renders before rendering them.
+    pub(crate) fn bind_children<'root>(
+        &mut self,
+        children: &'root [MjBodyChild],
+    ) -> Vec<BoundChild<'root>> {
+        let mut output = Vec::with_capacity(children.len());
+        let base = self.data.items.len();
+        self.data.bind_children_into(children, base, &mut output);
+        output
+    }
+
+    /// Binds the items of the child until [`leave_child`](Self::leave_child)
+    /// is called with the returned mark.
+    pub(crate) fn enter_child(&mut self, child: &BoundChild<'_>) -> usize {
+        self.data.enter(child.items.iter().cloned())
+    }
+
+    pub(crate) fn leave_child(&mut self, mark: usize) {
+        self.data.leave(mark);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{DataScope, DataValue};
+
+    #[test]
+    fn should_interpolate_and_escape() {
+        let data = DataValue::Object(
+            vec![
+                ("name", DataValue::from("<b>Jane</b> & co")),
+                ("count", DataValue::from(3.0)),
+                ("ratio", DataValue::from(0.5)),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        );
+        let scope = DataScope::new(Some(Arc::new(data)));
+        assert_eq!(scope.interpolate("no value"), None);
+        assert_eq!(
+            scope.interpolate("{{ name }} / {{{name}}}").unwrap(),
+            "&lt;b&gt;Jane&lt;/b&gt; &amp; co / <b>Jane</b> & co"
+        );
+        assert_eq!(
+            scope
+                .interpolate("{{count}} {{ratio}} [{{ missing }}]")
+                .unwrap(),
+            "3 0.5 []"
+        );
+        assert_eq!(
+            scope.interpolate("{{ name }} {{ unclosed").unwrap(),
+            "&lt;b&gt;Jane&lt;/b&gt; &amp; co {{ unclosed"
+        );
+        assert_eq!(DataScope::default().interpolate("{{ name }}"), None);
+    }
+
+    #[test]
+    fn should_look_up_loop_items_first() {
+        let data = DataValue::Object(
+            vec![("item".to_string(), DataValue::from("global"))]
+                .into_iter()
+                .collect(),
+        );
+        let mut scope = DataScope::new(Some(Arc::new(data)));
+        let mark = scope.enter(Some(("item".to_string(), DataValue::from(vec!["a", "b"]))));
+        assert_eq!(scope.get("item.1").unwrap().as_text(), "b");
+        assert!(scope.get("itemize").is_none());
+        scope.leave(mark);
+        assert_eq!(scope.get("item").unwrap().as_text(), "global");
+    }
+
+    #[cfg(all(feature = "parse", feature = "json"))]
+    #[test]
+    fn should_bind_data_when_rendering() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-head><mj-title>Hi {{ user.name }}</mj-title><mj-preview>{{ count }} items</mj-preview></mj-head>
+  <mj-body>
+    <mj-section><mj-column>
+      <mj-text color="{{ color }}">Hello {{ user.name }}</mj-text>
+      <mj-if condition="user.premium"><mj-text>premium</mj-text></mj-if>
+      <mj-if condition="user.name"><mj-text>named</mj-text></mj-if>
+      <mj-each items="items"><mj-button href="{{ item.url }}">{{ item.label }}</mj-button></mj-each>
+      <mj-text><mj-each items="items"><a href="{{ item.url }}">{{ item.label }}</a></mj-each></mj-text>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap().element;
+        let data = serde_json::json!({
+            "user": { "name": "Jane & John", "premium": false },
+            "color": "red",
+            "count": 2,
+            "items": [
+                { "url": "https://example.com/a", "label": "first" },
+                { "url": "https://example.com/b", "label": "second" },
+            ],
+        });
+        let opts = RenderOptions {
+            data: Some(Arc::new(data)),
+            ..Default::default()
+        };
+        let html = root.render(&opts).unwrap();
+        assert!(html.contains("<title>Hi Jane &amp; John</title>"));
+        assert!(html.contains(">2 items</div>"));
+        assert!(html.contains("Hello Jane &amp; John"));
+        assert!(html.contains("color:red"));
+        assert!(!html.contains("premium"));
+        assert!(html.contains("named"));
+        assert!(html.contains(
+            r#"<a href="https://example.com/a">first</a><a href="https://example.com/b">second</a>"#
+        ));
+        assert_eq!(html.matches("https://example.com/b").count(), 2);
+        assert!(!html.contains("mj-each"));
+        assert!(!html.contains("mj-if"));
+        assert!(!html.contains("{{"));
+        // the buttons are rendered like the siblings of the texts
+        assert_eq!(html.matches("word-break:break-word").count(), 5);
+
+        let compiled = root.compile();
+        assert_eq!(compiled.render(&opts).unwrap(), html);
+    }
+
+    #[cfg(all(feature = "parse", feature = "json"))]
+    #[test]
+    fn should_interpolate_the_data_only_once() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-title>T {{ t }}</mj-title>
+    <mj-raw><meta content="{{ t }}"></mj-raw>
+  </mj-head>
+  <mj-body>
+    <mj-raw position="file-start"><!-- {{ t }} --></mj-raw>
+    <mj-text>B {{ t }}</mj-text>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap().element;
+        let opts = RenderOptions {
+            data: Some(Arc::new(serde_json::json!({
+                "t": "{{{evil}}}",
+                "evil": "<script>x</script>",
+            }))),
+            ..Default::default()
+        };
+        let html = root.render(&opts).unwrap();
+        assert!(!html.contains("<script>"), "{}", html);
+        assert!(html.contains("<title>T {{{evil}}}</title>"), "{}", html);
+        assert!(html.contains(r#"content="{{{evil}}}""#), "{}", html);
+        assert!(html.contains("B {{{evil}}}"), "{}", html);
+        assert_eq!(root.compile().render(&opts).unwrap(), html);
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn should_drop_the_control_flow_without_data() {
+        use crate::prelude::render::RenderOptions;
+
+        let template = r#"<mjml>
+  <mj-body>
+    <mj-section><mj-column>
+      <mj-if condition="x"><mj-text>conditional</mj-text></mj-if>
+      <mj-each items="items"><mj-text>repeated</mj-text></mj-each>
+      <mj-text><mj-if condition="x"><b>nested</b></mj-if>kept</mj-text>
+    </mj-column></mj-section>
+  </mj-body>
+</mjml>"#;
+        let root = crate::mjml::Mjml::parse(template).unwrap().element;
+        let html = root.render(&RenderOptions::default()).unwrap();
+        assert!(!html.contains("mj-if"), "{}", html);
+        assert!(!html.contains("mj-each"), "{}", html);
+        assert!(!html.contains("conditional"));
+        assert!(!html.contains("repeated"));
+        assert!(!html.contains("nested"));
+        assert!(html.contains("kept"));
+        assert_eq!(
+            root.compile().render(&RenderOptions::default()).unwrap(),
+            html
+        );
+    }
+
+    #[cfg(all(feature = "parse", feature = "json"))]
+    #[test]
+    fn should_render_the_same_without_anything_to_bind() {
+        use crate::prelude::render::RenderOptions;
+
+        let opts = RenderOptions {
+            data: Some(Arc::new(serde_json::json!({}))),
+            ..Default::default()
+        };
+        for (path, template) in crate::helper::compare::compared_templates() {
+            let root = crate::mjml::Mjml::parse(template).unwrap().element;
+            assert_eq!(
+                root.render(&opts).unwrap(),
+                root.render(&RenderOptions::default()).unwrap(),
+                "{:?}",
+                path
+            );
+        }
+    }
+}
@@ -10,6 +10,9 @@ pub struct VariableHeader {
     used_font_families: Set<String>,
     media_queries: Map<String, Size>,
     styles: Set<Cow<'static, str>>,
+    dark_styles: Map<String, (&'static str, String)>,
+    dark_images: bool,
+    file_start: String,
 }
 
 impl Default for VariableHeader {
@@ -18,6 +21,9 @@ impl Default for VariableHeader {
             used_font_families: Default::default(),
             media_queries: Map::new(),
             styles: Set::new(),
+            dark_styles: Map::new(),
+            dark_images: false,
+            file_start: String::new(),
         }
     }
 }
@@ -69,12 +75,73 @@ impl VariableHeader {
             self.add_style(value);
         }
     }
+
+    /// Rules applied in dark mode, by class name, with the css property and
+    /// the value they set.
+    pub fn dark_styles(&self) -> &Map<String, (&'static str, String)> {
+        &self.dark_styles
+    }
+
+    /// Registers a rule setting the property to the value in dark mode and
+    /// returns the class name the element should get.
+    pub fn add_dark_style(&mut self, property: &'static str, value: &str) -> String {
+        let suffix = value
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect::<String>();
+        let classname = format!("mj-dark-{property}-{}", suffix.trim_matches('-'));
+        self.dark_styles
+            .entry(classname.clone())
+            .or_insert_with(|| (property, value.to_string()));
+        classname
+    }
+
+    /// Whether some images have a `dark-src` variant, swapped with the
+    /// `mj-dark-img` and `mj-light-img` classes.
+    pub fn has_dark_images(&self) -> bool {
+        self.dark_images
+    }
+
+    pub fn add_dark_image(&mut self) {
+        self.dark_images = true;
+    }
+
+    /// Whether the dark mode rules and meta tags should be written in the head.
+    pub fn has_dark_mode(&self) -> bool {
+        self.dark_images || !self.dark_styles.is_empty()
+    }
+
+    /// Content of the `mj-raw` elements with `position="file-start"`, to be
+    /// written before the doctype.
+    pub fn file_start(&self) -> &str {
+        &self.file_start
+    }
+
+    pub fn add_file_start(&mut self, value: &str) {
+        self.file_start.push_str(value);
+    }
+
+    pub(crate) fn take_file_start(&mut self) -> String {
+        std::mem::take(&mut self.file_start)
+    }
 }
 
+#[derive(Clone, Debug)]
 pub struct Header<'h> {
     attributes_all: Map<&'h str, &'h str>,
     attributes_class: Map<&'h str, Map<&'h str, &'h str>>,
+    // defaults of the elements inside the ones using a class, by class then tag
+    attributes_class_element: Map<&'h str, Map<&'h str, Map<&'h str, &'h str>>>,
     attributes_element: Map<&'h str, Map<&'h str, &'h str>>,
+    // attributes of each element merged with the ones of mj-all, so that a
+    // single lookup is needed when resolving an attribute
+    attributes_cascade: Map<&'h str, Map<&'h str, &'h str>>,
     breakpoint: Pixel,
     font_families: Map<&'h str, &'h str>,
     title: Option<&'h str>,
@@ -84,19 +151,34 @@ pub struct Header<'h> {
 
 impl<'h> Header<'h> {
     pub fn new(head: Option<&'h MjHead>, lang: Option<&'h str>) -> Self {
+        let attributes_all: Map<&'h str, &'h str> = head
+            .as_ref()
+            .map(|h| h.build_attributes_all())
+            .unwrap_or_default();
+        let attributes_element: Map<&'h str, Map<&'h str, &'h str>> = head
+            .as_ref()
+            .map(|h| h.build_attributes_element())
+            .unwrap_or_default();
+        let attributes_cascade = attributes_element
+            .iter()
+            .map(|(name, attributes)| {
+                let mut merged = attributes_all.clone();
+                merged.extend(attributes.iter().map(|(key, value)| (*key, *value)));
+                (*name, merged)
+            })
+            .collect();
         Self {
-            attributes_all: head
-                .as_ref()
-                .map(|h| h.build_attributes_all())
-                .unwrap_or_default(),
+            attributes_all,
             attributes_class: head
                 .as_ref()
                 .map(|h| h.build_attributes_class())
                 .unwrap_or_default(),
-            attributes_element: head
+            attributes_class_element: head
                 .as_ref()
-                .map(|h| h.build_attributes_element())
+                .map(|h| h.build_attributes_class_element())
                 .unwrap_or_default(),
+            attributes_element,
+            attributes_cascade,
             breakpoint: head
                 .as_ref()
                 .and_then(|h| h.breakpoint())
@@ -123,6 +205,20 @@ impl<'h> Header<'h> {
             .copied()
     }
 
+    pub fn has_attribute_class(&self, name: &str) -> bool {
+        self.attributes_class.contains_key(name) || self.attributes_class_element.contains_key(name)
+    }
+
+    /// Default defined by the `mj-class` for the elements of the given tag
+    /// inside the ones using it.
+    pub fn attribute_class_element(&self, name: &str, tag: &str, key: &str) -> Option<&str> {
+        self.attributes_class_element
+            .get(name)
+            .and_then(|class_map| class_map.get(tag))
+            .and_then(|elt| elt.get(key))
+            .copied()
+    }
+
     pub fn attribute_element(&self, name: &str, key: &str) -> Option<&str> {
         self.attributes_element
             .get(name)
@@ -130,6 +226,37 @@ impl<'h> Header<'h> {
             .copied()
     }
 
+    /// Resolves an attribute defined in the head for an element, looking first
+    /// at the `mj-attributes` defined for the element then at `mj-all`.
+    pub fn attribute_cascade(&self, tag: Option<&str>, key: &str) -> Option<&str> {
+        tag.and_then(|tag| self.attributes_cascade.get(tag))
+            .unwrap_or(&self.attributes_all)
+            .get(key)
+            .copied()
+    }
+
+    pub(crate) fn attributes_class_map(&self, name: &str) -> Option<&Map<&'h str, &'h str>> {
+        self.attributes_class.get(name)
+    }
+
+    pub(crate) fn attributes_class_element_map(
+        &self,
+        name: &str,
+        tag: &str,
+    ) -> Option<&Map<&'h str, &'h str>> {
+        self.attributes_class_element
+            .get(name)
+            .and_then(|class_map| class_map.get(tag))
+    }
+
+    /// All the attributes [`attribute_cascade`](Header::attribute_cascade)
+    /// resolves for the given tag.
+    pub(crate) fn attributes_cascade_map(&self, tag: &str) -> &Map<&'h str, &'h str> {
+        self.attributes_cascade
+            .get(tag)
+            .unwrap_or(&self.attributes_all)
+    }
+
     pub fn breakpoint(&self) -> &Pixel {
         &self.breakpoint
     }
@@ -0,0 +1,146 @@
+use super::Tag;
+
+/// Hook called on every tag before it gets written, to transform the output
+/// without having to change the components: rewriting links for click
+/// tracking, proxying images through a CDN, etc.
+///
+/// ```rust
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use std::sync::Arc;
+/// use mrml::prelude::render::{RenderHook, RenderOptions, Tag};
+///
+/// #[derive(Debug)]
+/// struct Tracking;
+///
+/// impl RenderHook for Tracking {
+///     fn on_tag(&self, component: Option<&str>, tag: &mut Tag<'_>) {
+///         if component == Some("mj-button") && tag.name() == "a" {
+///             if let Some(href) = tag.attribute("href") {
+///                 let href = format!("https://track.example.com/?url={href}");
+///                 tag.set_attribute("href", href);
+///             }
+///         }
+///     }
+/// }
+///
+/// let opts = RenderOptions {
+///     hook: Some(Arc::new(Tracking)),
+///     ..Default::default()
+/// };
+/// let root = mrml::parse(r#"<mjml><mj-body><mj-button href="https://example.com">Go</mj-button></mj-body></mjml>"#).expect("parse template");
+/// let html = root.element.render(&opts).expect("render template");
+/// assert!(html.contains("https://track.example.com/?url=https://example.com"));
+/// # }
+/// ```
+pub trait RenderHook: std::fmt::Debug + Send + Sync {
+    /// Called with the name of the component being rendered (`mj-button`,
+    /// `mj-image`, `mj-head`, etc) and the tag it is about to write. The html
+    /// elements of an `mj-text` or an `mj-raw` report the component
+    /// containing them.
+    fn on_tag(&self, component: Option<&str>, tag: &mut Tag<'_>);
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::RenderHook;
+    use crate::mjml::Mjml;
+    use crate::prelude::render::{RenderOptions, Tag};
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<(Option<String>, String)>>);
+
+    impl RenderHook for Recorder {
+        fn on_tag(&self, component: Option<&str>, tag: &mut Tag<'_>) {
+            if tag.attribute("href").is_some() || tag.attribute("src").is_some() {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((component.map(String::from), tag.name().to_string()));
+            }
+            if tag.name() == "a" {
+                tag.set_attribute("rel", "noopener");
+            }
+            if let Some(src) = tag.attribute("src") {
+                let src = src.replace("https://example.com/", "https://cdn.example.com/");
+                tag.set_attribute("src", src);
+            }
+        }
+    }
+
+    const TEMPLATE: &str = r#"<mjml>
+  <mj-body>
+    <mj-section>
+      <mj-column>
+        <mj-image src="https://example.com/logo.png" />
+        <mj-button href="https://example.com/buy">Buy</mj-button>
+        <mj-text><a href="https://example.com/about">About</a></mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"#;
+
+    #[test]
+    fn should_call_hook_with_component() {
+        let hook = Arc::new(Recorder::default());
+        let opts = RenderOptions {
+            hook: Some(hook.clone()),
+            ..Default::default()
+        };
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert!(result.contains("https://cdn.example.com/logo.png"));
+        assert!(!result.contains("https://example.com/logo.png"));
+        assert!(result.contains(r#"<a href="https://example.com/buy""#));
+        assert!(result.contains(r#"<a href="https://example.com/about" rel="noopener">"#));
+        let calls = hook.0.lock().unwrap();
+        assert_eq!(
+            calls.as_slice(),
+            &[
+                (Some("mj-image".to_string()), "img".to_string()),
+                (Some("mj-button".to_string()), "a".to_string()),
+                (Some("mj-text".to_string()), "a".to_string()),
+                // the font used by the text, the head being rendered last
+                (Some("mj-head".to_string()), "link".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_call_hook_with_head_tags() {
+        let hook = Arc::new(Recorder::default());
+        let opts = RenderOptions {
+            hook: Some(hook.clone()),
+            ..Default::default()
+        };
+        let template = r#"<mjml>
+  <mj-head>
+    <mj-font name="Raleway" href="https://example.com/raleway.css" />
+    <mj-raw><link href="https://example.com/extra.css" rel="stylesheet" /></mj-raw>
+  </mj-head>
+  <mj-body><mj-text font-family="Raleway">Hello</mj-text></mj-body>
+</mjml>"#;
+        let root = Mjml::parse(template).unwrap();
+        let expected = root.element.render(&RenderOptions::default()).unwrap();
+        let result = root.element.render(&opts).unwrap();
+        assert_eq!(result, expected);
+        let calls = hook.0.lock().unwrap();
+        assert_eq!(
+            calls.as_slice(),
+            &[
+                (Some("mj-head".to_string()), "link".to_string()),
+                (Some("mj-raw".to_string()), "link".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_change_output_without_hook() {
+        let root = Mjml::parse(TEMPLATE).unwrap();
+        let result = root.element.render(&RenderOptions::default()).unwrap();
+        assert!(result.contains("https://example.com/logo.png"));
+        assert!(!result.contains("noopener"));
+    }
+}
@@ -1,34 +1,95 @@
 use std::convert::TryFrom;
-use std::sync::atomic::{AtomicU16, Ordering};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rustc_hash::FxHasher;
 
 use crate::helper::size::{Pixel, Size};
 use crate::helper::spacing::Spacing;
+use crate::prelude::attributes::ParsedAttributes;
 
 mod buffer;
+mod component;
+mod data;
 mod header;
+mod hook;
 mod options;
+mod output;
+mod resolved;
 mod tag;
 
 pub use buffer::*;
+pub use component::*;
+pub use data::*;
 pub use header::*;
+pub use hook::*;
 pub use options::*;
+pub use output::*;
+pub use resolved::ResolvedAttributes;
 pub use tag::*;
 
+// attributes that are expected to contain a single pixel or percentage size,
+// checked when collecting warnings
+const UNIT_ATTRIBUTES: [&str; 2] = ["width", "height"];
+
+fn is_valid_unit(value: &str) -> bool {
+    value == "auto" || Size::try_from(value).is_ok()
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("unknown fragment {0}")]
     UnknownFragment(String),
     #[error("unable to format {0}")]
     Format(#[from] std::fmt::Error),
+    #[error("unable to write {0}")]
+    Io(#[from] std::io::Error),
+    /// Errors returned by a [`CustomComponent`].
+    #[error("unable to render custom component: {0}")]
+    Custom(String),
 }
 
 #[derive(Debug, Default)]
-pub struct Generator(AtomicU16);
+pub struct Generator {
+    counter: AtomicU64,
+    strategy: IdStrategy,
+}
+
+// see https://prng.di.unimi.it/splitmix64.c
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
 
 impl Generator {
+    pub fn new(strategy: IdStrategy) -> Self {
+        Self {
+            counter: AtomicU64::default(),
+            strategy,
+        }
+    }
+
     pub fn next_id(&self) -> String {
-        let id = self.0.fetch_add(1, Ordering::SeqCst);
-        format!("{id:0>8}")
+        self.next_id_for(&())
+    }
+
+    /// Generates the next id, the element being only used by the
+    /// [`IdStrategy::ContentHash`] strategy, through its [`Hash`]
+    /// implementation.
+    pub fn next_id_for<T: Hash + ?Sized>(&self, element: &T) -> String {
+        let id = self.counter.fetch_add(1, Ordering::SeqCst);
+        match self.strategy {
+            IdStrategy::Counter => format!("{id:0>8}"),
+            IdStrategy::Prefixed(ref prefix) => format!("{prefix}{id:0>8}"),
+            IdStrategy::Seeded(seed) => format!("{:016x}", splitmix64(seed ^ splitmix64(id))),
+            IdStrategy::ContentHash => {
+                let mut hasher = FxHasher::default();
+                element.hash(&mut hasher);
+                format!("{:016x}", splitmix64(hasher.finish() ^ splitmix64(id)))
+            }
+        }
     }
 }
 
@@ -37,24 +98,45 @@ pub type Options = RenderOptions;
 
 pub struct RenderContext<'h> {
     pub options: &'h RenderOptions,
-    pub header: Header<'h>,
+    pub header: &'h Header<'h>,
+    /// Attributes resolved when compiling the template, see
+    /// [`CompiledMjml`](crate::mjml::CompiledMjml).
+    pub resolved: Option<&'h ResolvedAttributes<'h>>,
     pub generator: Generator,
 }
 
 impl<'h> RenderContext<'h> {
-    pub fn new(options: &'h RenderOptions, header: Header<'h>) -> Self {
+    /// The header is borrowed, so that a compiled template can share it between
+    /// its renders.
+    pub fn new(options: &'h RenderOptions, header: &'h Header<'h>) -> Self {
         Self {
             options,
             header,
-            generator: Generator::default(),
+            resolved: None,
+            generator: Generator::new(options.id_strategy.clone()),
         }
     }
+
+    pub fn with_resolved(mut self, resolved: &'h ResolvedAttributes<'h>) -> Self {
+        self.resolved = Some(resolved);
+        self
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct RenderCursor {
     pub buffer: RenderBuffer,
     pub header: VariableHeader,
+    pub warnings: Vec<Warning>,
+}
+
+impl RenderCursor {
+    pub fn new(options: &RenderOptions) -> Self {
+        Self {
+            buffer: RenderBuffer::with_hook(options.hook.clone()).with_data(options.data.clone()),
+            ..Default::default()
+        }
+    }
 }
 
 pub(crate) struct Renderer<'root, Element, Extra> {
@@ -64,6 +146,7 @@ pub(crate) struct Renderer<'root, Element, Extra> {
     pub siblings: usize,
     pub raw_siblings: usize,
     pub index: usize,
+    pub mj_class_scope: Vec<&'root str>,
     pub extra: Extra,
 }
 
@@ -81,12 +164,31 @@ impl<'root, Element, Extra> Renderer<'root, Element, Extra> {
             siblings: 1,
             raw_siblings: 0,
             index: 0,
+            mj_class_scope: Vec::new(),
             extra,
         }
     }
 }
 
-pub trait Render<'root> {
+/// State shared by all the renderers, whatever the element they render.
+pub trait RenderScope<'root> {
+    /// Classes of the ancestors, the closest first, whose `mj-class` children
+    /// defaults apply to the element.
+    fn mj_class_scope(&self) -> &[&'root str];
+    fn set_mj_class_scope(&mut self, scope: Vec<&'root str>);
+}
+
+impl<'root, Element, Extra> RenderScope<'root> for Renderer<'root, Element, Extra> {
+    fn mj_class_scope(&self) -> &[&'root str] {
+        &self.mj_class_scope
+    }
+
+    fn set_mj_class_scope(&mut self, scope: Vec<&'root str>) {
+        self.mj_class_scope = scope;
+    }
+}
+
+pub trait Render<'root>: RenderScope<'root> {
     fn context(&self) -> &'root RenderContext<'root>;
 
     fn tag(&self) -> Option<&str> {
@@ -101,19 +203,38 @@ pub trait Render<'root> {
         None
     }
 
+    /// The attributes of the element parsed once, for the elements having
+    /// typed attributes. They have the priority over everything else, like
+    /// the [`raw_attribute`](Self::raw_attribute) they come from.
+    fn parsed_attributes(&self) -> Option<&dyn ParsedAttributes> {
+        None
+    }
+
     fn attribute_as_pixel(&self, name: &str) -> Option<Pixel> {
-        self.attribute(name)
-            .and_then(|value| Pixel::try_from(value).ok())
+        self.parsed_attributes()
+            .and_then(|parsed| parsed.pixel(name))
+            .or_else(|| {
+                self.attribute(name)
+                    .and_then(|value| Pixel::try_from(value).ok())
+            })
     }
 
     fn attribute_as_size(&self, name: &str) -> Option<Size> {
-        self.attribute(name)
-            .and_then(|value| Size::try_from(value).ok())
+        self.parsed_attributes()
+            .and_then(|parsed| parsed.size(name))
+            .or_else(|| {
+                self.attribute(name)
+                    .and_then(|value| Size::try_from(value).ok())
+            })
     }
 
     fn attribute_as_spacing(&self, name: &str) -> Option<Spacing> {
-        self.attribute(name)
-            .and_then(|value| Spacing::try_from(value).ok())
+        self.parsed_attributes()
+            .and_then(|parsed| parsed.spacing(name))
+            .or_else(|| {
+                self.attribute(name)
+                    .and_then(|value| Spacing::try_from(value).ok())
+            })
     }
 
     fn attribute_equals(&self, key: &str, value: &str) -> bool {
@@ -197,6 +318,16 @@ pub trait Render<'root> {
     }
 
     fn attribute<'a>(&'a self, key: &str) -> Option<&'a str>
+    where
+        'root: 'a,
+    {
+        self.explicit_attribute(key)
+            .or_else(|| self.default_attribute(key))
+    }
+
+    /// Value given to the attribute by the template, on the element or in the
+    /// head, ignoring the default value of the element.
+    fn explicit_attribute<'a>(&'a self, key: &str) -> Option<&'a str>
     where
         'root: 'a,
     {
@@ -206,6 +337,13 @@ pub trait Render<'root> {
         if let Some(value) = self.raw_extra_attribute(key) {
             return Some(value);
         }
+        if let Some(attributes) = self.tag().and_then(|tag| {
+            self.context().resolved.and_then(|resolved| {
+                resolved.get(tag, self.raw_attribute("mj-class"), self.mj_class_scope())
+            })
+        }) {
+            return attributes.get(key).copied();
+        }
         if let Some(value) = self.raw_attribute("mj-class").and_then(|mj_classes| {
             mj_classes
                 .split(' ')
@@ -216,14 +354,15 @@ pub trait Render<'root> {
             return Some(value);
         }
         if let Some(tag) = self.tag() {
-            if let Some(value) = self.context().header.attribute_element(tag, key) {
+            if let Some(value) = self.mj_class_scope().iter().find_map(|mj_class| {
+                self.context()
+                    .header
+                    .attribute_class_element(mj_class, tag, key)
+            }) {
                 return Some(value);
             }
         }
-        if let Some(value) = self.context().header.attribute_all(key) {
-            return Some(value);
-        }
-        self.default_attribute(key)
+        self.context().header.attribute_cascade(self.tag(), key)
     }
 
     fn attribute_size(&self, key: &str) -> Option<Size> {
@@ -244,6 +383,23 @@ pub trait Render<'root> {
         tag
     }
 
+    /// Scope to give to the children: the classes of the element followed by
+    /// the ones it inherited.
+    fn children_mj_class_scope(&self) -> Vec<&'root str> {
+        let mut scope: Vec<&'root str> = self
+            .raw_attribute("mj-class")
+            .map(|mj_classes| {
+                mj_classes
+                    .split(' ')
+                    .map(|mj_class| mj_class.trim())
+                    .filter(|mj_class| !mj_class.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        scope.extend_from_slice(self.mj_class_scope());
+        scope
+    }
+
     fn set_container_width(&mut self, _width: Option<Pixel>) {}
     fn set_index(&mut self, _index: usize) {}
     fn set_siblings(&mut self, _count: usize) {}
@@ -256,6 +412,46 @@ pub trait Render<'root> {
         }
     }
 
+    /// Collects the warnings related to the attributes of the element: unknown
+    /// `mj-class` names and values that cannot be parsed as a size.
+    fn warn_attributes(&self, cursor: &mut RenderCursor) {
+        if let Some(mj_classes) = self.raw_attribute("mj-class") {
+            for mj_class in mj_classes
+                .split(' ')
+                .map(|mj_class| mj_class.trim())
+                .filter(|mj_class| !mj_class.is_empty())
+            {
+                if !self.context().header.has_attribute_class(mj_class) {
+                    cursor.add_warning(WarningKind::UnknownMjClass, self.tag(), mj_class);
+                }
+            }
+        }
+        for key in UNIT_ATTRIBUTES {
+            if let Some(value) = self.attribute(key) {
+                if !is_valid_unit(value) {
+                    cursor.add_warning(
+                        WarningKind::UnparsableUnit,
+                        self.tag(),
+                        format!("{key}={value}"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Class applying the value of a dark mode attribute (`dark-color`,
+    /// `dark-background-color`, etc) to the given css property, registered in
+    /// the header so that the head writes the matching rules.
+    fn dark_class(
+        &self,
+        cursor: &mut RenderCursor,
+        key: &str,
+        property: &'static str,
+    ) -> Option<String> {
+        self.attribute(key)
+            .map(|value| cursor.header.add_dark_style(property, value))
+    }
+
     fn render_fragment(&self, name: &str, cursor: &mut RenderCursor) -> Result<(), Error> {
         match name {
             "main" => self.render(cursor),
@@ -264,6 +460,25 @@ pub trait Render<'root> {
     }
 
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error>;
+
+    /// Name of the component reported to the [`RenderHook`] for the tags
+    /// written while rendering the element, `None` to keep the one of the
+    /// parent, like the html elements of an `mj-text` do.
+    fn component_name(&self) -> Option<&str> {
+        self.tag()
+    }
+
+    /// Renders the element, keeping track of the component being rendered for
+    /// the [`RenderHook`].
+    fn render_component(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
+        let Some(name) = self.component_name() else {
+            return self.render(cursor);
+        };
+        cursor.buffer.enter_component(name);
+        let result = self.render(cursor);
+        cursor.buffer.leave_component();
+        result
+    }
 }
 
 pub trait Renderable<'render, 'root: 'render> {
@@ -308,11 +523,91 @@ macro_rules! should_render {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn valid_units() {
+        assert!(super::is_valid_unit("auto"));
+        assert!(super::is_valid_unit("10px"));
+        assert!(super::is_valid_unit("50%"));
+        assert!(!super::is_valid_unit("10em"));
+        assert!(!super::is_valid_unit("10px 0"));
+        assert!(!super::is_valid_unit("wide"));
+    }
+
+    use super::{Generator, IdStrategy};
+
     #[test]
     fn header_should_increase() {
-        let gen = super::Generator::default();
+        let gen = Generator::default();
         assert_eq!(gen.next_id(), "00000000");
         assert_eq!(gen.next_id(), "00000001");
         assert_eq!(gen.next_id(), "00000002");
     }
+
+    #[test]
+    fn header_should_not_wrap() {
+        let gen = Generator::default();
+        for _ in 0..70_000 {
+            gen.next_id();
+        }
+        assert_eq!(gen.next_id(), "00070000");
+    }
+
+    #[test]
+    fn header_with_prefix() {
+        let gen = Generator::new(IdStrategy::Prefixed("foo-".into()));
+        assert_eq!(gen.next_id(), "foo-00000000");
+        assert_eq!(gen.next_id(), "foo-00000001");
+    }
+
+    #[test]
+    fn header_with_seed() {
+        let first = Generator::new(IdStrategy::Seeded(42));
+        let second = Generator::new(IdStrategy::Seeded(42));
+        let other = Generator::new(IdStrategy::Seeded(43));
+        let id = first.next_id();
+        assert_eq!(id.len(), 16);
+        assert_eq!(id, second.next_id());
+        assert_ne!(id, other.next_id());
+        assert_ne!(first.next_id(), id);
+    }
+
+    #[test]
+    fn header_with_content_hash() {
+        let first = Generator::new(IdStrategy::ContentHash);
+        let second = Generator::new(IdStrategy::ContentHash);
+        assert_eq!(first.next_id_for("foo"), second.next_id_for("foo"));
+        assert_ne!(first.next_id_for("foo"), second.next_id_for("bar"));
+    }
+
+    #[cfg(feature = "parse")]
+    #[test]
+    fn header_with_content_hash_of_elements() {
+        use crate::mj_body::MjBodyChild;
+
+        let navbar = |template: &str| -> crate::mj_navbar::MjNavbar {
+            let root = crate::parse(template).unwrap();
+            match root.element.body().unwrap().children[0] {
+                MjBodyChild::MjNavbar(ref navbar) => navbar.clone(),
+                _ => panic!("expected a navbar"),
+            }
+        };
+        let first = navbar(
+            r#"<mjml><mj-body><mj-navbar><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar></mj-body></mjml>"#,
+        );
+        let same = navbar(
+            r#"<mjml><mj-body><mj-navbar><mj-navbar-link href="/a">A</mj-navbar-link></mj-navbar></mj-body></mjml>"#,
+        );
+        let other = navbar(
+            r#"<mjml><mj-body><mj-navbar><mj-navbar-link href="/b">A</mj-navbar-link></mj-navbar></mj-body></mjml>"#,
+        );
+        let id = Generator::new(IdStrategy::ContentHash).next_id_for(&first);
+        assert_eq!(
+            id,
+            Generator::new(IdStrategy::ContentHash).next_id_for(&same)
+        );
+        assert_ne!(
+            id,
+            Generator::new(IdStrategy::ContentHash).next_id_for(&other)
+        );
+    }
 }
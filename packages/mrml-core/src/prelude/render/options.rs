@@ -1,5 +1,8 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{CustomComponent, DataSource, RenderHook};
 
 pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     HashMap::from([
@@ -26,11 +29,43 @@ pub fn default_fonts() -> HashMap<String, Cow<'static, str>> {
     ])
 }
 
+/// How the ids used by the interactive components (`mj-carousel`,
+/// `mj-navbar`) are generated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// Sequential ids, formatted as 8 zero padded digits (`00000000`,
+    /// `00000001`, etc).
+    #[default]
+    Counter,
+    /// Sequential ids prefixed with a namespace, to avoid collisions when
+    /// several rendered templates end up in the same page.
+    Prefixed(Cow<'static, str>),
+    /// Pseudo random ids, the same seed always giving the same ids.
+    Seeded(u64),
+    /// Ids derived from the content of the element, so that different
+    /// templates get different ids while a template always gets the same ones.
+    /// The content is hashed through the [`Hash`](std::hash::Hash)
+    /// implementation of the element, the ids only depend on its attributes
+    /// and children.
+    ContentHash,
+}
+
 #[derive(Debug)]
 pub struct RenderOptions {
     pub disable_comments: bool,
     pub social_icon_origin: Option<Cow<'static, str>>,
     pub fonts: HashMap<String, Cow<'static, str>>,
+    pub id_strategy: IdStrategy,
+    /// Pattern building the address of the high density variant of the images
+    /// without a `srcset`, like `{name}@2x.{extension}`, where `{name}` is the
+    /// address of the image without its extension.
+    pub retina_pattern: Option<Cow<'static, str>>,
+    /// Custom components, by tag name.
+    pub components: HashMap<String, Box<dyn CustomComponent>>,
+    /// Hook called on every tag before it gets written.
+    pub hook: Option<Arc<dyn RenderHook>>,
+    /// Data bound to the template before rendering it.
+    pub data: Option<Arc<dyn DataSource>>,
 }
 
 impl Default for RenderOptions {
@@ -39,6 +74,11 @@ impl Default for RenderOptions {
             disable_comments: false,
             social_icon_origin: None,
             fonts: default_fonts(),
+            id_strategy: IdStrategy::default(),
+            retina_pattern: None,
+            components: HashMap::new(),
+            hook: None,
+            data: None,
         }
     }
 }
@@ -0,0 +1,88 @@
+pub struct RenderOutput {
+    pub content: String,
+    pub warnings: Vec<Warning>,
+}
+
+/// The result of rendering a single element out of a template.
+pub struct RenderFragment {
+    /// The html of the element.
+    pub content: String,
+    /// The styles to put in the document head for the element to display
+    /// properly (fonts, media queries, etc).
+    pub head: String,
+    pub warnings: Vec<Warning>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    UnknownFontFamily,
+    UnparsableUnit,
+    UnknownMjClass,
+    UnknownSocialNetwork,
+    ColumnWidthOverflow,
+    UnknownListStyle,
+}
+
+impl WarningKind {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnknownFontFamily => "unknown-font-family",
+            Self::UnparsableUnit => "unparsable-unit",
+            Self::UnknownMjClass => "unknown-mj-class",
+            Self::UnknownSocialNetwork => "unknown-social-network",
+            Self::ColumnWidthOverflow => "column-width-overflow",
+            Self::UnknownListStyle => "unknown-list-style",
+        }
+    }
+}
+
+impl std::fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFontFamily => f.write_str("unknown font family"),
+            Self::UnparsableUnit => f.write_str("unparsable unit"),
+            Self::UnknownMjClass => f.write_str("unknown mj-class"),
+            Self::UnknownSocialNetwork => f.write_str("unknown social network"),
+            Self::ColumnWidthOverflow => f.write_str("column widths exceeding 100%"),
+            Self::UnknownListStyle => f.write_str("unknown list style"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// Name of the element that triggered the warning, when known.
+    pub element: Option<String>,
+    /// The offending value.
+    pub value: String,
+}
+
+impl super::RenderCursor {
+    pub fn add_warning<V: Into<String>>(
+        &mut self,
+        kind: WarningKind,
+        element: Option<&str>,
+        value: V,
+    ) {
+        self.warnings.push(Warning {
+            kind,
+            element: element.map(String::from),
+            value: value.into(),
+        });
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ref element) = self.element {
+            write!(f, "{} {:?} in {element}", self.kind, self.value)
+        } else {
+            write!(f, "{} {:?}", self.kind, self.value)
+        }
+    }
+}
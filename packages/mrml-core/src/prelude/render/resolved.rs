@@ -0,0 +1,160 @@
+use super::Header;
+use crate::prelude::hash::Map;
+use crate::prelude::select::Element;
+use crate::prelude::visitor::{Visitable, Visitor};
+
+/// What the attributes an element gets from the head depend on: its tag, its
+/// `mj-class` and the classes of its ancestors.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct ResolvedKey<'a, S> {
+    tag: &'a str,
+    mj_class: Option<&'a str>,
+    scope: S,
+}
+
+impl<'a, 'h> indexmap::Equivalent<ResolvedKey<'h, Vec<&'h str>>>
+    for ResolvedKey<'a, &'a [&'a str]>
+{
+    fn equivalent(&self, key: &ResolvedKey<'h, Vec<&'h str>>) -> bool {
+        self.tag == key.tag && self.mj_class == key.mj_class && self.scope == key.scope
+    }
+}
+
+/// Attributes coming from the head resolved once, when compiling a template,
+/// for the elements of the body using an `mj-class` or inside an element using
+/// one. The other elements only depend on `mj-attributes`, which the
+/// [`Header`] already merges by tag.
+///
+/// The elements sharing their tag, `mj-class` and ancestor classes share their
+/// attributes, numbered in the order the walk through the template finds them.
+#[derive(Debug)]
+pub struct ResolvedAttributes<'h> {
+    indexes: Map<ResolvedKey<'h, Vec<&'h str>>, usize>,
+    /// Attributes merged by priority: the `mj-class` of the element, the
+    /// defaults given by the `mj-class` of its ancestors then `mj-attributes`.
+    attributes: Vec<Map<&'h str, &'h str>>,
+}
+
+impl<'h> Default for ResolvedAttributes<'h> {
+    fn default() -> Self {
+        Self {
+            indexes: Map::new(),
+            attributes: Vec::new(),
+        }
+    }
+}
+
+impl<'h> ResolvedAttributes<'h> {
+    pub fn new<V: Visitable + ?Sized>(root: &'h V, header: &Header<'h>) -> Self {
+        let mut builder = ResolvedAttributesBuilder {
+            header,
+            in_body: false,
+            scopes: Vec::new(),
+            result: Self::default(),
+        };
+        root.accept(&mut builder);
+        builder.result
+    }
+
+    /// Number of distinct sets of attributes that have been resolved.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// The resolved attributes of the elements with the given tag, `mj-class`
+    /// attribute and `mj-class` scope, if the template contains some.
+    pub fn get(
+        &self,
+        tag: &str,
+        mj_class: Option<&str>,
+        scope: &[&str],
+    ) -> Option<&Map<&'h str, &'h str>> {
+        let key = ResolvedKey {
+            tag,
+            mj_class,
+            scope,
+        };
+        self.indexes.get(&key).map(|index| &self.attributes[*index])
+    }
+}
+
+fn split_mj_classes(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(' ')
+        .map(|mj_class| mj_class.trim())
+        .filter(|mj_class| !mj_class.is_empty())
+}
+
+/// [`Visitor`] following the `mj-class` scope the way the renderers do.
+struct ResolvedAttributesBuilder<'b, 'h> {
+    header: &'b Header<'h>,
+    in_body: bool,
+    scopes: Vec<Vec<&'h str>>,
+    result: ResolvedAttributes<'h>,
+}
+
+impl<'b, 'h> ResolvedAttributesBuilder<'b, 'h> {
+    fn resolve(
+        &self,
+        tag: &'h str,
+        mj_classes: &[&'h str],
+        scope: &[&'h str],
+    ) -> Map<&'h str, &'h str> {
+        // from the lowest priority to the highest, the first class winning
+        let mut attributes = self.header.attributes_cascade_map(tag).clone();
+        for mj_class in scope.iter().rev() {
+            if let Some(defaults) = self.header.attributes_class_element_map(mj_class, tag) {
+                attributes.extend(defaults.iter().map(|(key, value)| (*key, *value)));
+            }
+        }
+        for mj_class in mj_classes.iter().rev() {
+            if let Some(defaults) = self.header.attributes_class_map(mj_class) {
+                attributes.extend(defaults.iter().map(|(key, value)| (*key, *value)));
+            }
+        }
+        attributes
+    }
+}
+
+impl<'b, 'h> Visitor<'h> for ResolvedAttributesBuilder<'b, 'h> {
+    fn enter_mj_body(&mut self, _element: &'h crate::mj_body::MjBody) {
+        self.in_body = true;
+    }
+
+    fn leave_mj_body(&mut self, _element: &'h crate::mj_body::MjBody) {
+        self.in_body = false;
+    }
+
+    fn enter_element(&mut self, element: &'h dyn Element) {
+        let inherited = self.scopes.last().cloned().unwrap_or_default();
+        let mj_classes = element
+            .attribute("mj-class")
+            .map(|value| split_mj_classes(value).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if self.in_body && !(mj_classes.is_empty() && inherited.is_empty()) {
+            let key = ResolvedKey {
+                tag: element.tag(),
+                mj_class: element.attribute("mj-class"),
+                scope: inherited.clone(),
+            };
+            if !self.result.indexes.contains_key(&key) {
+                let attributes = self.resolve(key.tag, &mj_classes, &inherited);
+                self.result
+                    .indexes
+                    .insert(key, self.result.attributes.len());
+                self.result.attributes.push(attributes);
+            }
+        }
+        let mut scope = mj_classes;
+        scope.extend(inherited);
+        self.scopes.push(scope);
+    }
+
+    fn leave_element(&mut self, _element: &'h dyn Element) {
+        self.scopes.pop();
+    }
+}
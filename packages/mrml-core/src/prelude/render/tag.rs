@@ -2,9 +2,10 @@ use std::borrow::Cow;
 use std::fmt::Write;
 
 use super::RenderBuffer;
+use crate::placeholder::is_placeholder_attribute;
 use crate::prelude::hash::{Map, Set};
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct Styles<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
 
 impl<'a> std::fmt::Debug for Styles<'a> {
@@ -17,7 +18,7 @@ impl<'a> std::fmt::Debug for Styles<'a> {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct Classes<'a>(Set<Cow<'a, str>>);
 
 impl<'a> std::fmt::Debug for Classes<'a> {
@@ -33,6 +34,7 @@ impl<'a> std::fmt::Debug for Classes<'a> {
     }
 }
 
+#[derive(Clone)]
 pub struct Tag<'a> {
     name: Cow<'a, str>,
     attributes: Map<Cow<'a, str>, Cow<'a, str>>,
@@ -76,6 +78,26 @@ impl<'a> Tag<'a> {
         }
     }
 
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(|value| value.as_ref())
+    }
+
+    pub fn set_attribute<K: Into<Cow<'a, str>>, V: Into<Cow<'a, str>>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) {
+        self.attributes.insert(name.into(), value.into());
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) -> Option<Cow<'a, str>> {
+        self.attributes.remove(name)
+    }
+
     pub fn add_class<C: Into<Cow<'a, str>>>(mut self, value: C) -> Self {
         self.classes.0.insert(value.into());
         self
@@ -146,10 +168,25 @@ impl<'a> Tag<'a> {
 
 impl<'a> Tag<'a> {
     fn render_opening(&self, b: &mut RenderBuffer) -> std::fmt::Result {
+        if let Some(hook) = b.hook().cloned() {
+            let mut tag = self.clone();
+            hook.on_tag(b.component(), &mut tag);
+            tag.write_opening(b)
+        } else {
+            self.write_opening(b)
+        }
+    }
+
+    fn write_opening(&self, b: &mut RenderBuffer) -> std::fmt::Result {
         b.push('<');
         b.push_str(&self.name);
         for (key, value) in self.attributes.iter() {
-            b.push_attribute(key, value)?;
+            if is_placeholder_attribute(key) {
+                b.push(' ');
+                b.push_verbatim(value);
+            } else {
+                b.push_attribute(key, value)?;
+            }
         }
         if !self.classes.0.is_empty() {
             b.push_attribute("class", &self.classes)?;
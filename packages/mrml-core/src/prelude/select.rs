@@ -0,0 +1,814 @@
+//! Query of the elements of a template with css selectors.
+//!
+//! The selectors support the tags (`mj-button`, `a` or `*`), the css classes
+//! defined with the `css-class` attribute (`.footer`), the attribute
+//! predicates (`[href]`, `[mj-class~=cta]`, `[href^="https://"]`...), the
+//! descendant (` `) and child (`>`) combinators and the lists of selectors
+//! (`mj-text, mj-button`). The elements of the head can be selected too, the
+//! defaults of `mj-attributes` having the tag of the element they apply to.
+//!
+//! ```rust
+//! # #[cfg(feature = "parse")]
+//! # {
+//! let mut root = mrml::parse(r#"<mjml><mj-body>
+//!   <mj-section><mj-column>
+//!     <mj-button mj-class="cta" href="https://example.com">Buy</mj-button>
+//!     <mj-button>Cancel</mj-button>
+//!   </mj-column></mj-section>
+//! </mj-body></mjml>"#).unwrap();
+//!
+//! let found = root.element.select("mj-section > mj-column mj-button[href]").unwrap();
+//! assert_eq!(found.len(), 1);
+//!
+//! root.element.select_mut("mj-button[mj-class~=cta]", |button| {
+//!     button.set_attribute("background-color", "#ff0000");
+//! }).unwrap();
+//! let found = root.element.select("[background-color]").unwrap();
+//! assert_eq!(found[0].attribute("href"), Some("https://example.com"));
+//! # }
+//! ```
+
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::hash::Map;
+use super::visitor::{Visitable, VisitableMut, Visitor, VisitorMut};
+use crate::mj_accordion::MjAccordion;
+use crate::mj_accordion_element::MjAccordionElement;
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::mj_attributes::MjAttributes;
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_breakpoint::MjBreakpoint;
+use crate::mj_button::MjButton;
+use crate::mj_carousel::MjCarousel;
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_font::MjFont;
+use crate::mj_group::MjGroup;
+use crate::mj_head::MjHead;
+use crate::mj_hero::MjHero;
+use crate::mj_image::MjImage;
+use crate::mj_include::body::MjIncludeBody;
+use crate::mj_include::head::MjIncludeHead;
+use crate::mj_list::MjList;
+use crate::mj_list_item::MjListItem;
+use crate::mj_navbar::MjNavbar;
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_preview::MjPreview;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_section::MjSection;
+use crate::mj_social::MjSocial;
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_style::MjStyle;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_title::MjTitle;
+use crate::mj_video::MjVideo;
+use crate::mj_wrapper::MjWrapper;
+use crate::mjml::Mjml;
+use crate::node::Node;
+
+/// Element of the template having a tag, that can be matched by a selector.
+/// The [`Visitor`] gives access to all of them, in the head and in the body.
+pub trait Element: Any {
+    fn tag(&self) -> &str;
+    /// `None` for the elements whose attributes are not a map, like `mjml`
+    /// or `mj-font`.
+    fn attributes(&self) -> Option<&Map<String, String>>;
+    fn attributes_mut(&mut self) -> Option<&mut Map<String, String>>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl dyn Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes()
+            .and_then(|attributes| attributes.get(name))
+            .map(String::as_str)
+    }
+
+    /// Sets the value of an attribute, returns `false` if the element
+    /// doesn't support it.
+    pub fn set_attribute(&mut self, name: impl Into<String>, value: impl Into<String>) -> bool {
+        if let Some(attributes) = self.attributes_mut() {
+            attributes.insert(name.into(), value.into());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn downcast_ref<T: Element>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    pub fn downcast_mut<T: Element>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
+}
+
+/// Implements [`Element`] for the components storing their attributes in a
+/// [`Map`], or without attributes that can be selected.
+macro_rules! element {
+    ($($element:ty => $tag:expr),* $(,)?) => {
+        $(
+            element!(@impl $element, |_element| $tag, |element| Some(&element.attributes), |element| Some(&mut element.attributes));
+        )*
+    };
+    (without attributes: $($element:ty => $tag:expr),* $(,)?) => {
+        $(
+            element!(@impl $element, |_element| $tag, |_element| None, |_element| None);
+        )*
+    };
+    (@impl $element:ty, |$tag_value:ident| $tag:expr, |$value:ident| $attributes:expr, |$value_mut:ident| $attributes_mut:expr) => {
+        impl Element for $element {
+            fn tag(&self) -> &str {
+                let $tag_value = self;
+                $tag
+            }
+
+            fn attributes(&self) -> Option<&Map<String, String>> {
+                let $value = self;
+                $attributes
+            }
+
+            fn attributes_mut(&mut self) -> Option<&mut Map<String, String>> {
+                let $value_mut = self;
+                $attributes_mut
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+        }
+    };
+}
+
+element!(
+    MjAccordion => crate::mj_accordion::NAME,
+    MjAccordionElement => crate::mj_accordion_element::NAME,
+    MjAccordionText => crate::mj_accordion_text::NAME,
+    MjAccordionTitle => crate::mj_accordion_title::NAME,
+    MjAttributesAll => crate::mj_attributes_all::NAME,
+    MjBody => crate::mj_body::NAME,
+    MjButton => crate::mj_button::NAME,
+    MjCarousel => crate::mj_carousel::NAME,
+    MjCarouselImage => crate::mj_carousel_image::NAME,
+    MjColumn => crate::mj_column::NAME,
+    MjDivider => crate::mj_divider::NAME,
+    MjGroup => crate::mj_group::NAME,
+    MjHero => crate::mj_hero::NAME,
+    MjImage => crate::mj_image::NAME,
+    MjList => crate::mj_list::NAME,
+    MjListItem => crate::mj_list_item::NAME,
+    MjNavbar => crate::mj_navbar::NAME,
+    MjNavbarLink => crate::mj_navbar_link::NAME,
+    MjSection => crate::mj_section::NAME,
+    MjSocial => crate::mj_social::NAME,
+    MjSocialElement => crate::mj_social_element::NAME,
+    MjSpacer => crate::mj_spacer::NAME,
+    MjTable => crate::mj_table::NAME,
+    MjText => crate::mj_text::NAME,
+    MjVideo => crate::mj_video::NAME,
+    MjWrapper => crate::mj_wrapper::NAME,
+);
+
+element!(
+    without attributes:
+    Mjml => crate::mjml::NAME,
+    MjAttributes => crate::mj_attributes::NAME,
+    MjBreakpoint => crate::mj_breakpoint::NAME,
+    MjFont => crate::mj_font::NAME,
+    MjHead => crate::mj_head::NAME,
+    MjIncludeBody => crate::mj_include::NAME,
+    MjIncludeHead => crate::mj_include::NAME,
+    MjPreview => crate::mj_preview::NAME,
+    MjRaw => crate::mj_raw::NAME,
+    MjStyle => crate::mj_style::NAME,
+    MjTitle => crate::mj_title::NAME,
+);
+
+// the name of the class is not part of the attributes it defines
+element!(
+    @impl MjAttributesClass,
+    |_element| crate::mj_attributes_class::NAME,
+    |element| Some(&element.attributes.others),
+    |element| Some(&mut element.attributes.others)
+);
+
+// the defaults of an element in `mj-attributes` have the tag of the element
+element!(
+    @impl MjAttributesElement,
+    |element| element.name.as_str(),
+    |element| Some(&element.attributes),
+    |element| Some(&mut element.attributes)
+);
+
+element!(
+    @impl Node<MjBodyChild>,
+    |element| element.tag.as_str(),
+    |element| Some(&element.attributes),
+    |element| Some(&mut element.attributes)
+);
+
+element!(
+    @impl Node<MjRawChild>,
+    |element| element.tag.as_str(),
+    |element| Some(&element.attributes),
+    |element| Some(&mut element.attributes)
+);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectorError {
+    pub position: usize,
+    pub reason: &'static str,
+}
+
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid selector at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    /// `[name=value]`
+    Equal,
+    /// `[name~=value]`, one of the whitespace separated words is the value
+    Includes,
+    /// `[name^=value]`
+    Prefix,
+    /// `[name$=value]`
+    Suffix,
+    /// `[name*=value]`
+    Contains,
+}
+
+impl Operator {
+    fn matches(&self, attribute: &str, value: &str) -> bool {
+        match self {
+            Self::Equal => attribute == value,
+            Self::Includes => attribute.split_whitespace().any(|item| item == value),
+            Self::Prefix => attribute.starts_with(value),
+            Self::Suffix => attribute.ends_with(value),
+            Self::Contains => attribute.contains(value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct AttributePredicate {
+    name: String,
+    condition: Option<(Operator, String)>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Compound {
+    tag: Option<String>,
+    classes: Vec<String>,
+    attributes: Vec<AttributePredicate>,
+}
+
+impl Compound {
+    fn matches(&self, element: &dyn Element) -> bool {
+        if self.tag.as_deref().is_some_and(|tag| tag != element.tag()) {
+            return false;
+        }
+        let classes_match = self.classes.iter().all(|class| {
+            element
+                .attribute("css-class")
+                .is_some_and(|value| Operator::Includes.matches(value, class))
+        });
+        classes_match
+            && self.attributes.iter().all(|predicate| {
+                match (element.attribute(&predicate.name), &predicate.condition) {
+                    (Some(value), Some((operator, expected))) => operator.matches(value, expected),
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                }
+            })
+    }
+}
+
+/// The compounds of a complex selector, each one with the combinator linking
+/// it to the previous one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Complex(Vec<(Combinator, Compound)>);
+
+impl Complex {
+    fn matches(&self, element: &dyn Element, ancestors: &[&dyn Element]) -> bool {
+        Self::matches_parts(&self.0, element, ancestors)
+    }
+
+    fn matches_parts(
+        parts: &[(Combinator, Compound)],
+        element: &dyn Element,
+        ancestors: &[&dyn Element],
+    ) -> bool {
+        let Some(((combinator, compound), rest)) = parts.split_last() else {
+            return true;
+        };
+        if !compound.matches(element) {
+            return false;
+        }
+        if rest.is_empty() {
+            return true;
+        }
+        match combinator {
+            Combinator::Child => ancestors
+                .split_last()
+                .is_some_and(|(parent, others)| Self::matches_parts(rest, *parent, others)),
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|index| Self::matches_parts(rest, ancestors[index], &ancestors[..index])),
+        }
+    }
+}
+
+struct SelectorParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+impl<'a> SelectorParser<'a> {
+    fn error(&self, reason: &'static str) -> SelectorError {
+        SelectorError {
+            position: self.position,
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespaces(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.position > start
+    }
+
+    fn expect(&mut self, expected: char, reason: &'static str) -> Result<(), SelectorError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, SelectorError> {
+        let start = self.position;
+        while self.peek().is_some_and(is_ident_char) {
+            self.bump();
+        }
+        if self.position == start {
+            Err(self.error("expected a name"))
+        } else {
+            Ok(self.input[start..self.position].to_string())
+        }
+    }
+
+    fn value(&mut self) -> Result<String, SelectorError> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                let start = self.position;
+                while self.peek().is_some_and(|c| c != quote) {
+                    self.bump();
+                }
+                let value = self.input[start..self.position].to_string();
+                self.expect(quote, "unterminated string")?;
+                Ok(value)
+            }
+            _ => self.ident(),
+        }
+    }
+
+    fn attribute(&mut self) -> Result<AttributePredicate, SelectorError> {
+        self.skip_whitespaces();
+        let name = self.ident()?;
+        self.skip_whitespaces();
+        let operator = match self.peek() {
+            Some(']') => None,
+            Some('=') => Some(Operator::Equal),
+            Some('~') => Some(Operator::Includes),
+            Some('^') => Some(Operator::Prefix),
+            Some('$') => Some(Operator::Suffix),
+            Some('*') => Some(Operator::Contains),
+            _ => return Err(self.error("expected an attribute operator")),
+        };
+        let condition = match operator {
+            Some(operator) => {
+                if operator != Operator::Equal {
+                    self.bump();
+                }
+                self.expect('=', "expected an attribute operator")?;
+                self.skip_whitespaces();
+                let value = self.value()?;
+                self.skip_whitespaces();
+                Some((operator, value))
+            }
+            None => None,
+        };
+        self.expect(']', "expected the end of the attribute")?;
+        Ok(AttributePredicate { name, condition })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let start = self.position;
+        let mut result = Compound::default();
+        match self.peek() {
+            Some('*') => self.bump(),
+            Some(c) if is_ident_char(c) => result.tag = Some(self.ident()?),
+            _ => {}
+        }
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.bump();
+                    result.classes.push(self.ident()?);
+                }
+                Some('[') => {
+                    self.bump();
+                    result.attributes.push(self.attribute()?);
+                }
+                _ => break,
+            }
+        }
+        if self.position == start {
+            Err(self.error("expected a selector"))
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespaces();
+        let mut parts = vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let spaced = self.skip_whitespaces();
+            let combinator = match self.peek() {
+                Some('>') => {
+                    self.bump();
+                    self.skip_whitespaces();
+                    Combinator::Child
+                }
+                Some(',') | None => break,
+                _ if spaced => Combinator::Descendant,
+                _ => return Err(self.error("unexpected character")),
+            };
+            parts.push((combinator, self.compound()?));
+        }
+        Ok(Complex(parts))
+    }
+
+    fn selector(mut self) -> Result<Selector, SelectorError> {
+        let mut result = vec![self.complex()?];
+        while self.peek() == Some(',') {
+            self.bump();
+            result.push(self.complex()?);
+        }
+        Ok(Selector(result))
+    }
+}
+
+/// A list of css selectors, see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector(Vec<Complex>);
+
+impl Selector {
+    pub fn parse(input: &str) -> Result<Self, SelectorError> {
+        SelectorParser { input, position: 0 }.selector()
+    }
+
+    /// Checks if the element matches, given its ancestors from the root to
+    /// its parent.
+    pub fn matches(&self, element: &dyn Element, ancestors: &[&dyn Element]) -> bool {
+        self.0
+            .iter()
+            .any(|complex| complex.matches(element, ancestors))
+    }
+
+    /// Finds the matching elements, the root included, in document order.
+    /// Like `querySelectorAll`, an element matching inside another matching
+    /// element is returned too.
+    pub fn select<'a, V: Visitable + ?Sized>(&self, root: &'a V) -> Vec<&'a dyn Element> {
+        let mut selection = Selection {
+            selector: self,
+            ancestors: Vec::new(),
+            result: Vec::new(),
+        };
+        root.accept(&mut selection);
+        selection.result
+    }
+
+    /// Calls the function with each matching element, in document order,
+    /// and returns the number of matches. An element and the elements it
+    /// contains can't be borrowed mutably at the same time, so the matches
+    /// are given one at a time instead of being returned. The elements are
+    /// matched against the template as it was before calling the function.
+    pub fn select_mut<V, F>(&self, root: &mut V, func: F) -> usize
+    where
+        V: VisitableMut + ?Sized,
+        F: FnMut(&mut dyn Element),
+    {
+        let mut selection = SelectionMut {
+            selector: self,
+            ancestors: Vec::new(),
+            func,
+            count: 0,
+        };
+        root.accept_mut(&mut selection);
+        selection.count
+    }
+}
+
+/// [`Visitor`] collecting the matching elements.
+struct Selection<'s, 'a> {
+    selector: &'s Selector,
+    ancestors: Vec<&'a dyn Element>,
+    result: Vec<&'a dyn Element>,
+}
+
+impl<'s, 'a> Visitor<'a> for Selection<'s, 'a> {
+    fn enter_element(&mut self, element: &'a dyn Element) {
+        if self.selector.matches(element, &self.ancestors) {
+            self.result.push(element);
+        }
+        self.ancestors.push(element);
+    }
+
+    fn leave_element(&mut self, _element: &'a dyn Element) {
+        self.ancestors.pop();
+    }
+}
+
+/// Copy of an ancestor, the ancestors being borrowed mutably while their
+/// children are visited.
+struct Ancestor {
+    tag: String,
+    attributes: Option<Map<String, String>>,
+}
+
+impl Element for Ancestor {
+    fn tag(&self) -> &str {
+        self.tag.as_str()
+    }
+
+    fn attributes(&self) -> Option<&Map<String, String>> {
+        self.attributes.as_ref()
+    }
+
+    fn attributes_mut(&mut self) -> Option<&mut Map<String, String>> {
+        self.attributes.as_mut()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// [`VisitorMut`] calling a function with the matching elements.
+struct SelectionMut<'s, F> {
+    selector: &'s Selector,
+    ancestors: Vec<Ancestor>,
+    func: F,
+    count: usize,
+}
+
+impl<'s, F: FnMut(&mut dyn Element)> VisitorMut for SelectionMut<'s, F> {
+    fn enter_element(&mut self, element: &mut dyn Element) {
+        let ancestor = Ancestor {
+            tag: element.tag().to_string(),
+            attributes: element.attributes().cloned(),
+        };
+        let ancestors = self
+            .ancestors
+            .iter()
+            .map(|item| item as &dyn Element)
+            .collect::<Vec<_>>();
+        if self.selector.matches(element, &ancestors) {
+            (self.func)(element);
+            self.count += 1;
+        }
+        self.ancestors.push(ancestor);
+    }
+
+    fn leave_element(&mut self, _element: &mut dyn Element) {
+        self.ancestors.pop();
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl Mjml {
+    /// Finds the elements of the template matching the css selector, see the
+    /// [module documentation](self).
+    pub fn select(&self, selector: &str) -> Result<Vec<&dyn Element>, SelectorError> {
+        Ok(Selector::parse(selector)?.select(self))
+    }
+
+    /// Calls the function with the elements of the template matching the css
+    /// selector to update them, see [`Selector::select_mut`].
+    pub fn select_mut<F>(&mut self, selector: &str, func: F) -> Result<usize, SelectorError>
+    where
+        F: FnMut(&mut dyn Element),
+    {
+        Ok(Selector::parse(selector)?.select_mut(self, func))
+    }
+}
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{Selector, SelectorError};
+    use crate::mj_button::MjButton;
+    use crate::mjml::Mjml;
+
+    const TEMPLATE: &str = r#"<mjml>
+  <mj-body>
+    <mj-section css-class="header">
+      <mj-column>
+        <mj-text>Hello <a href="https://example.com/a">here</a></mj-text>
+        <mj-button mj-class="cta primary" href="https://example.com/buy">Buy</mj-button>
+      </mj-column>
+    </mj-section>
+    <mj-wrapper>
+      <mj-section>
+        <mj-group>
+          <mj-column>
+            <mj-button href="/relative">Read</mj-button>
+            <mj-button>Nothing</mj-button>
+          </mj-column>
+        </mj-group>
+      </mj-section>
+    </mj-wrapper>
+  </mj-body>
+</mjml>"#;
+
+    fn count_in(root: &Mjml, selector: &str) -> usize {
+        root.select(selector).unwrap().len()
+    }
+
+    fn count(selector: &str) -> usize {
+        count_in(&crate::parse(TEMPLATE).unwrap().element, selector)
+    }
+
+    #[test]
+    fn should_match_tags_and_combinators() {
+        assert_eq!(count("mj-button"), 3);
+        assert_eq!(count("mj-button[href]"), 2);
+        assert_eq!(count("mj-section > mj-column mj-button[href]"), 1);
+        assert_eq!(count("mj-section mj-column > mj-button"), 3);
+        assert_eq!(count("mj-wrapper mj-button"), 2);
+        assert_eq!(count("mj-text a"), 1);
+        assert_eq!(count("mjml > mj-body > *"), 2);
+        assert_eq!(count("mj-text, mj-button"), 4);
+    }
+
+    #[test]
+    fn should_match_classes_and_attributes() {
+        assert_eq!(count(".header mj-button"), 1);
+        assert_eq!(count("mj-button[mj-class~=cta]"), 1);
+        assert_eq!(count("mj-button[mj-class~=primary]"), 1);
+        assert_eq!(count("mj-button[mj-class=cta]"), 0);
+        assert_eq!(count(r#"[href^="https://"]"#), 2);
+        assert_eq!(count("[href$='/buy']"), 1);
+        assert_eq!(count("[href*=example]"), 2);
+    }
+
+    #[test]
+    fn should_update_selected_elements() {
+        let mut root = crate::parse(TEMPLATE).unwrap();
+        let count = root
+            .element
+            .select_mut("mj-button[href]", |button| {
+                button.set_attribute("background-color", "#ff0000");
+                let button = button.downcast_mut::<MjButton>().unwrap();
+                button.children.clear();
+            })
+            .unwrap();
+        assert_eq!(count, 2);
+        let found = root.element.select("[background-color]").unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|item| item
+            .downcast_ref::<MjButton>()
+            .unwrap()
+            .children
+            .is_empty()));
+    }
+
+    #[test]
+    fn should_select_nested_matches() {
+        let mut root = crate::parse(TEMPLATE).unwrap();
+        let tags = root
+            .element
+            .select("mj-section, mj-column")
+            .unwrap()
+            .into_iter()
+            .map(|item| item.tag().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            vec!["mj-section", "mj-column", "mj-section", "mj-column"]
+        );
+
+        let mut tags = Vec::new();
+        let count = root
+            .element
+            .select_mut("mj-section, mj-column", |item| {
+                tags.push(item.tag().to_string());
+                item.set_attribute("css-class", "selected");
+            })
+            .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(
+            tags,
+            vec!["mj-section", "mj-column", "mj-section", "mj-column"]
+        );
+        // matched against the template as it was before the changes
+        assert_eq!(count_in(&root.element, ".selected"), 4);
+    }
+
+    #[test]
+    fn should_select_head_elements() {
+        let root = crate::parse(
+            r#"<mjml>
+  <mj-head>
+    <mj-title>Hello</mj-title>
+    <mj-attributes>
+      <mj-class name="cta" color="red" />
+      <mj-button background-color="blue" />
+    </mj-attributes>
+  </mj-head>
+  <mj-body><mj-button>Go</mj-button></mj-body>
+</mjml>"#,
+        )
+        .unwrap();
+        assert_eq!(count_in(&root.element, "mj-head mj-title"), 1);
+        assert_eq!(count_in(&root.element, "mj-class[color=red]"), 1);
+        assert_eq!(count_in(&root.element, "mj-attributes > mj-button"), 1);
+        assert_eq!(count_in(&root.element, "mj-button"), 2);
+        assert_eq!(count_in(&root.element, "mj-body mj-button"), 1);
+    }
+
+    #[test]
+    fn should_reject_invalid_selectors() {
+        assert_eq!(
+            Selector::parse("mj-button[href").unwrap_err(),
+            SelectorError {
+                position: 14,
+                reason: "expected an attribute operator"
+            }
+        );
+        assert!(Selector::parse("").is_err());
+        assert!(Selector::parse("mj-button >").is_err());
+        assert!(Selector::parse("mj-button[href=]").is_err());
+        assert!(Selector::parse("mj-button!").is_err());
+    }
+}
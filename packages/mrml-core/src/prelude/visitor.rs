@@ -0,0 +1,623 @@
+//! Traversal of the component tree.
+//!
+//! A [`Visitor`] gets called when entering and leaving each element of the
+//! tree, in document order, and a [`VisitorMut`] does the same with a mutable
+//! access to the elements, to transform them in place. Every callback does
+//! nothing by default, so an implementation only overrides the ones it cares
+//! about. Besides the callbacks dedicated to each type of element,
+//! `enter_element` and `leave_element` are called for all the elements having
+//! a tag, whatever their type, through the [`Element`] trait.
+//!
+//! ```rust
+//! use mrml::mj_button::MjButton;
+//! use mrml::prelude::visitor::{Visitable, Visitor};
+//!
+//! #[derive(Default)]
+//! struct Links(Vec<String>);
+//!
+//! impl Visitor<'_> for Links {
+//!     fn enter_mj_button(&mut self, element: &MjButton) {
+//!         self.0.extend(element.attributes.get("href").cloned());
+//!     }
+//! }
+//!
+//! let root = mrml::parse(r#"<mjml><mj-body><mj-button href="https://example.com" /></mj-body></mjml>"#).unwrap();
+//! let mut links = Links::default();
+//! root.element.accept(&mut links);
+//! assert_eq!(links.0, vec!["https://example.com".to_string()]);
+//! ```
+
+use crate::comment::Comment;
+use crate::mj_accordion::{MjAccordion, MjAccordionChild};
+use crate::mj_accordion_element::{MjAccordionElement, MjAccordionElementChildren};
+use crate::mj_accordion_text::MjAccordionText;
+use crate::mj_accordion_title::MjAccordionTitle;
+use crate::mj_attributes::{MjAttributes, MjAttributesChild};
+use crate::mj_attributes_all::MjAttributesAll;
+use crate::mj_attributes_class::MjAttributesClass;
+use crate::mj_attributes_element::MjAttributesElement;
+use crate::mj_body::{MjBody, MjBodyChild};
+use crate::mj_breakpoint::MjBreakpoint;
+use crate::mj_button::MjButton;
+use crate::mj_carousel::{MjCarousel, MjCarouselChild};
+use crate::mj_carousel_image::MjCarouselImage;
+use crate::mj_column::MjColumn;
+use crate::mj_divider::MjDivider;
+use crate::mj_font::MjFont;
+use crate::mj_group::MjGroup;
+use crate::mj_head::{MjHead, MjHeadChild};
+use crate::mj_hero::MjHero;
+use crate::mj_image::MjImage;
+use crate::mj_include::body::{MjIncludeBody, MjIncludeBodyChild};
+use crate::mj_include::head::{MjIncludeHead, MjIncludeHeadChild};
+use crate::mj_list::{MjList, MjListChild};
+use crate::mj_list_item::MjListItem;
+use crate::mj_navbar::{MjNavbar, MjNavbarChild};
+use crate::mj_navbar_link::MjNavbarLink;
+use crate::mj_preview::MjPreview;
+use crate::mj_raw::{MjRaw, MjRawChild};
+use crate::mj_section::MjSection;
+use crate::mj_social::{MjSocial, MjSocialChild};
+use crate::mj_social_element::MjSocialElement;
+use crate::mj_spacer::MjSpacer;
+use crate::mj_style::MjStyle;
+use crate::mj_table::MjTable;
+use crate::mj_text::MjText;
+use crate::mj_title::MjTitle;
+use crate::mj_video::MjVideo;
+use crate::mj_wrapper::MjWrapper;
+use crate::mjml::{Mjml, MjmlChildren};
+use crate::node::Node;
+use crate::placeholder::Placeholder;
+use crate::prelude::select::Element;
+use crate::text::Text;
+
+macro_rules! visitor_traits {
+    ($(($element:ty, $enter:ident, $leave:ident)),* $(,)?) => {
+        /// Callbacks called while walking through the tree with
+        /// [`Visitable::accept`]. The elements are borrowed for the lifetime
+        /// of the tree, so a visitor can keep references to them.
+        pub trait Visitor<'a> {
+            fn enter_element(&mut self, _element: &'a dyn Element) {}
+            fn leave_element(&mut self, _element: &'a dyn Element) {}
+            $(
+                fn $enter(&mut self, _element: &'a $element) {}
+                fn $leave(&mut self, _element: &'a $element) {}
+            )*
+        }
+
+        /// Callbacks called while walking through the tree with
+        /// [`VisitableMut::accept_mut`]. The children are visited after
+        /// `enter`, so the changes made to them there are taken into account.
+        pub trait VisitorMut {
+            fn enter_element(&mut self, _element: &mut dyn Element) {}
+            fn leave_element(&mut self, _element: &mut dyn Element) {}
+            $(
+                fn $enter(&mut self, _element: &mut $element) {}
+                fn $leave(&mut self, _element: &mut $element) {}
+            )*
+        }
+    };
+}
+
+visitor_traits!(
+    (Mjml, enter_mjml, leave_mjml),
+    (MjHead, enter_mj_head, leave_mj_head),
+    (MjBody, enter_mj_body, leave_mj_body),
+    (Comment, enter_comment, leave_comment),
+    (Placeholder, enter_placeholder, leave_placeholder),
+    (Text, enter_text, leave_text),
+    (Node<MjBodyChild>, enter_node, leave_node),
+    (Node<MjRawChild>, enter_raw_node, leave_raw_node),
+    (MjAttributes, enter_mj_attributes, leave_mj_attributes),
+    (
+        MjAttributesAll,
+        enter_mj_attributes_all,
+        leave_mj_attributes_all
+    ),
+    (
+        MjAttributesClass,
+        enter_mj_attributes_class,
+        leave_mj_attributes_class
+    ),
+    (
+        MjAttributesElement,
+        enter_mj_attributes_element,
+        leave_mj_attributes_element
+    ),
+    (MjBreakpoint, enter_mj_breakpoint, leave_mj_breakpoint),
+    (MjFont, enter_mj_font, leave_mj_font),
+    (MjIncludeHead, enter_mj_include_head, leave_mj_include_head),
+    (MjPreview, enter_mj_preview, leave_mj_preview),
+    (MjRaw, enter_mj_raw, leave_mj_raw),
+    (MjStyle, enter_mj_style, leave_mj_style),
+    (MjTitle, enter_mj_title, leave_mj_title),
+    (MjAccordion, enter_mj_accordion, leave_mj_accordion),
+    (
+        MjAccordionElement,
+        enter_mj_accordion_element,
+        leave_mj_accordion_element
+    ),
+    (
+        MjAccordionText,
+        enter_mj_accordion_text,
+        leave_mj_accordion_text
+    ),
+    (
+        MjAccordionTitle,
+        enter_mj_accordion_title,
+        leave_mj_accordion_title
+    ),
+    (MjButton, enter_mj_button, leave_mj_button),
+    (MjCarousel, enter_mj_carousel, leave_mj_carousel),
+    (
+        MjCarouselImage,
+        enter_mj_carousel_image,
+        leave_mj_carousel_image
+    ),
+    (MjColumn, enter_mj_column, leave_mj_column),
+    (MjDivider, enter_mj_divider, leave_mj_divider),
+    (MjGroup, enter_mj_group, leave_mj_group),
+    (MjHero, enter_mj_hero, leave_mj_hero),
+    (MjImage, enter_mj_image, leave_mj_image),
+    (MjIncludeBody, enter_mj_include_body, leave_mj_include_body),
+    (MjList, enter_mj_list, leave_mj_list),
+    (MjListItem, enter_mj_list_item, leave_mj_list_item),
+    (MjNavbar, enter_mj_navbar, leave_mj_navbar),
+    (MjNavbarLink, enter_mj_navbar_link, leave_mj_navbar_link),
+    (MjSection, enter_mj_section, leave_mj_section),
+    (MjSocial, enter_mj_social, leave_mj_social),
+    (
+        MjSocialElement,
+        enter_mj_social_element,
+        leave_mj_social_element
+    ),
+    (MjSpacer, enter_mj_spacer, leave_mj_spacer),
+    (MjTable, enter_mj_table, leave_mj_table),
+    (MjText, enter_mj_text, leave_mj_text),
+    (MjVideo, enter_mj_video, leave_mj_video),
+    (MjWrapper, enter_mj_wrapper, leave_mj_wrapper),
+);
+
+/// Element of the tree that can be walked through by a [`Visitor`].
+pub trait Visitable {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V);
+}
+
+/// Element of the tree that can be walked through by a [`VisitorMut`].
+pub trait VisitableMut {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+impl Visitable for () {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, _visitor: &mut V) {}
+}
+
+impl VisitableMut for () {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, _visitor: &mut V) {}
+}
+
+// the content of the comments, titles, styles...
+impl Visitable for String {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, _visitor: &mut V) {}
+}
+
+impl VisitableMut for String {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, _visitor: &mut V) {}
+}
+
+impl<T: Visitable> Visitable for Option<T> {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+        if let Some(inner) = self {
+            inner.accept(visitor);
+        }
+    }
+}
+
+impl<T: VisitableMut> VisitableMut for Option<T> {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(inner) = self {
+            inner.accept_mut(visitor);
+        }
+    }
+}
+
+impl<T: Visitable> Visitable for Vec<T> {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+        for item in self.iter() {
+            item.accept(visitor);
+        }
+    }
+}
+
+impl<T: VisitableMut> VisitableMut for Vec<T> {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        for item in self.iter_mut() {
+            item.accept_mut(visitor);
+        }
+    }
+}
+
+impl Visitable for MjmlChildren {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+        self.head.accept(visitor);
+        self.body.accept(visitor);
+    }
+}
+
+impl VisitableMut for MjmlChildren {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.head.accept_mut(visitor);
+        self.body.accept_mut(visitor);
+    }
+}
+
+impl Visitable for MjAccordionElementChildren {
+    fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+        self.title.accept(visitor);
+        self.text.accept(visitor);
+    }
+}
+
+impl VisitableMut for MjAccordionElementChildren {
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.title.accept_mut(visitor);
+        self.text.accept_mut(visitor);
+    }
+}
+
+macro_rules! visitable_element {
+    ($element:ty, $enter:ident, $leave:ident, |$value:ident| $children:expr) => {
+        impl Visitable for $element {
+            fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+                visitor.enter_element(self);
+                visitor.$enter(self);
+                let $value = self;
+                $children.accept(visitor);
+                visitor.$leave(self);
+                visitor.leave_element(self);
+            }
+        }
+
+        impl VisitableMut for $element {
+            fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                visitor.enter_element(self);
+                visitor.$enter(self);
+                let $value = &mut *self;
+                $children.accept_mut(visitor);
+                visitor.$leave(self);
+                visitor.leave_element(self);
+            }
+        }
+    };
+    ($element:ty, $enter:ident, $leave:ident) => {
+        visitable_element!($element, $enter, $leave, |element| element.children);
+    };
+}
+
+// the comments, placeholders and texts have no tag, so they are not elements
+macro_rules! visitable_content {
+    ($element:ty, $enter:ident, $leave:ident, |$value:ident| $children:expr) => {
+        impl Visitable for $element {
+            fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+                visitor.$enter(self);
+                let $value = self;
+                $children.accept(visitor);
+                visitor.$leave(self);
+            }
+        }
+
+        impl VisitableMut for $element {
+            fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                visitor.$enter(self);
+                let $value = &mut *self;
+                $children.accept_mut(visitor);
+                visitor.$leave(self);
+            }
+        }
+    };
+}
+
+visitable_element!(Mjml, enter_mjml, leave_mjml);
+visitable_element!(MjHead, enter_mj_head, leave_mj_head);
+visitable_element!(MjBody, enter_mj_body, leave_mj_body);
+visitable_content!(Comment, enter_comment, leave_comment, |element| element
+    .children);
+visitable_content!(
+    Placeholder,
+    enter_placeholder,
+    leave_placeholder,
+    |element| element.children
+);
+visitable_content!(Text, enter_text, leave_text, |_element| ());
+visitable_element!(Node<MjBodyChild>, enter_node, leave_node);
+visitable_element!(Node<MjRawChild>, enter_raw_node, leave_raw_node);
+visitable_element!(MjAttributes, enter_mj_attributes, leave_mj_attributes);
+visitable_element!(
+    MjAttributesAll,
+    enter_mj_attributes_all,
+    leave_mj_attributes_all
+);
+visitable_element!(
+    MjAttributesClass,
+    enter_mj_attributes_class,
+    leave_mj_attributes_class
+);
+visitable_element!(
+    MjAttributesElement,
+    enter_mj_attributes_element,
+    leave_mj_attributes_element,
+    |_element| ()
+);
+visitable_element!(MjBreakpoint, enter_mj_breakpoint, leave_mj_breakpoint);
+visitable_element!(MjFont, enter_mj_font, leave_mj_font);
+visitable_element!(
+    MjIncludeHead,
+    enter_mj_include_head,
+    leave_mj_include_head,
+    |element| element.0.children
+);
+visitable_element!(MjPreview, enter_mj_preview, leave_mj_preview);
+visitable_element!(MjRaw, enter_mj_raw, leave_mj_raw);
+visitable_element!(MjStyle, enter_mj_style, leave_mj_style);
+visitable_element!(MjTitle, enter_mj_title, leave_mj_title);
+visitable_element!(MjAccordion, enter_mj_accordion, leave_mj_accordion);
+visitable_element!(
+    MjAccordionElement,
+    enter_mj_accordion_element,
+    leave_mj_accordion_element
+);
+visitable_element!(
+    MjAccordionText,
+    enter_mj_accordion_text,
+    leave_mj_accordion_text
+);
+visitable_element!(
+    MjAccordionTitle,
+    enter_mj_accordion_title,
+    leave_mj_accordion_title
+);
+visitable_element!(MjButton, enter_mj_button, leave_mj_button);
+visitable_element!(MjCarousel, enter_mj_carousel, leave_mj_carousel);
+visitable_element!(
+    MjCarouselImage,
+    enter_mj_carousel_image,
+    leave_mj_carousel_image
+);
+visitable_element!(MjColumn, enter_mj_column, leave_mj_column);
+visitable_element!(MjDivider, enter_mj_divider, leave_mj_divider);
+visitable_element!(MjGroup, enter_mj_group, leave_mj_group);
+visitable_element!(MjHero, enter_mj_hero, leave_mj_hero);
+visitable_element!(MjImage, enter_mj_image, leave_mj_image);
+visitable_element!(
+    MjIncludeBody,
+    enter_mj_include_body,
+    leave_mj_include_body,
+    |element| element.0.children
+);
+visitable_element!(MjList, enter_mj_list, leave_mj_list);
+visitable_element!(MjListItem, enter_mj_list_item, leave_mj_list_item);
+visitable_element!(MjNavbar, enter_mj_navbar, leave_mj_navbar);
+visitable_element!(MjNavbarLink, enter_mj_navbar_link, leave_mj_navbar_link);
+visitable_element!(MjSection, enter_mj_section, leave_mj_section);
+visitable_element!(MjSocial, enter_mj_social, leave_mj_social);
+visitable_element!(
+    MjSocialElement,
+    enter_mj_social_element,
+    leave_mj_social_element
+);
+visitable_element!(MjSpacer, enter_mj_spacer, leave_mj_spacer);
+visitable_element!(MjTable, enter_mj_table, leave_mj_table);
+visitable_element!(MjText, enter_mj_text, leave_mj_text);
+visitable_element!(MjVideo, enter_mj_video, leave_mj_video);
+visitable_element!(MjWrapper, enter_mj_wrapper, leave_mj_wrapper);
+
+macro_rules! visitable_child {
+    ($child:ty, $($variant:ident),*) => {
+        impl Visitable for $child {
+            fn accept<'a, V: Visitor<'a> + ?Sized>(&'a self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.accept(visitor),)*
+                }
+            }
+        }
+
+        impl VisitableMut for $child {
+            fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(inner) => inner.accept_mut(visitor),)*
+                }
+            }
+        }
+    };
+}
+
+visitable_child!(
+    MjBodyChild,
+    Comment,
+    MjAccordion,
+    MjButton,
+    MjCarousel,
+    MjColumn,
+    MjDivider,
+    MjGroup,
+    MjHero,
+    MjInclude,
+    MjImage,
+    MjList,
+    MjNavbar,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjTable,
+    MjText,
+    MjVideo,
+    MjWrapper,
+    Node,
+    Placeholder,
+    Text
+);
+visitable_child!(
+    MjIncludeBodyChild,
+    Comment,
+    MjAccordion,
+    MjButton,
+    MjCarousel,
+    MjColumn,
+    MjDivider,
+    MjGroup,
+    MjHero,
+    MjImage,
+    MjList,
+    MjNavbar,
+    MjRaw,
+    MjSection,
+    MjSocial,
+    MjSpacer,
+    MjTable,
+    MjText,
+    MjVideo,
+    MjWrapper,
+    Node,
+    Placeholder,
+    Text
+);
+visitable_child!(
+    MjHeadChild,
+    Comment,
+    MjAttributes,
+    MjBreakpoint,
+    MjFont,
+    MjInclude,
+    MjPreview,
+    MjRaw,
+    MjStyle,
+    MjTitle,
+    Placeholder
+);
+visitable_child!(
+    MjIncludeHeadChild,
+    Comment,
+    MjAttributes,
+    MjBreakpoint,
+    MjFont,
+    MjPreview,
+    MjRaw,
+    MjStyle,
+    MjTitle,
+    Placeholder,
+    Text
+);
+visitable_child!(
+    MjAttributesChild,
+    MjAttributesAll,
+    MjAttributesClass,
+    MjAttributesElement
+);
+visitable_child!(MjRawChild, Comment, Node, Placeholder, Text);
+visitable_child!(MjAccordionChild, Comment, MjAccordionElement, Placeholder);
+visitable_child!(MjCarouselChild, Comment, MjCarouselImage, Placeholder);
+visitable_child!(MjListChild, Comment, MjListItem, Placeholder);
+visitable_child!(MjNavbarChild, Comment, MjNavbarLink, Placeholder);
+visitable_child!(MjSocialChild, Comment, MjSocialElement, Placeholder);
+
+#[cfg(all(test, feature = "parse"))]
+mod tests {
+    use super::{Visitable, Visitor};
+    use crate::mj_column::MjColumn;
+    use crate::mj_section::MjSection;
+    use crate::mj_title::MjTitle;
+    use crate::text::Text;
+
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl Visitor<'_> for Trace {
+        fn enter_mj_section(&mut self, _element: &MjSection) {
+            self.0.push("section".into());
+        }
+
+        fn leave_mj_section(&mut self, _element: &MjSection) {
+            self.0.push("/section".into());
+        }
+
+        fn enter_mj_column(&mut self, _element: &MjColumn) {
+            self.0.push("column".into());
+        }
+
+        fn leave_mj_column(&mut self, _element: &MjColumn) {
+            self.0.push("/column".into());
+        }
+
+        fn enter_mj_title(&mut self, element: &MjTitle) {
+            self.0.push(format!("title {}", element.children));
+        }
+
+        fn enter_text(&mut self, element: &Text) {
+            self.0.push(format!("text {}", element.inner_str()));
+        }
+    }
+
+    #[test]
+    fn should_visit_in_document_order() {
+        let template = r#"<mjml>
+<mj-head><mj-title>Hello</mj-title></mj-head>
+<mj-body><mj-section><mj-column><mj-text>World</mj-text></mj-column></mj-section></mj-body>
+</mjml>"#;
+        let root = crate::parse(template).unwrap();
+        let mut trace = Trace::default();
+        root.element.accept(&mut trace);
+        let texts = trace
+            .0
+            .iter()
+            .filter(|item| !item.trim().eq("text"))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            vec![
+                "title Hello",
+                "section",
+                "column",
+                "text World",
+                "/column",
+                "/section"
+            ]
+        );
+    }
+
+    #[cfg(feature = "print")]
+    #[test]
+    fn should_rewrite_in_place() {
+        use super::{VisitableMut, VisitorMut};
+        use crate::mj_button::MjButton;
+        use crate::mj_navbar_link::MjNavbarLink;
+
+        struct Rewrite;
+
+        impl VisitorMut for Rewrite {
+            fn enter_mj_button(&mut self, element: &mut MjButton) {
+                if let Some(href) = element.attributes.get_mut("href") {
+                    href.push_str("?utm_source=newsletter");
+                }
+            }
+
+            fn enter_mj_navbar_link(&mut self, element: &mut MjNavbarLink) {
+                if let Some(href) = element.attributes.get_mut("href") {
+                    href.push_str("?utm_source=newsletter");
+                }
+            }
+        }
+
+        let template = r#"<mjml><mj-body>
+<mj-navbar><mj-navbar-link href="/about">About</mj-navbar-link></mj-navbar>
+<mj-section><mj-column><mj-button href="https://example.com">Go</mj-button></mj-column></mj-section>
+</mj-body></mjml>"#;
+        let mut root = crate::parse(template).unwrap();
+        root.element.accept_mut(&mut Rewrite);
+        let output = crate::prelude::print::Printable::print_dense(&root.element).unwrap();
+        assert!(output.contains(r#"href="/about?utm_source=newsletter""#));
+        assert!(output.contains(r#"href="https://example.com?utm_source=newsletter""#));
+    }
+}
@@ -1,7 +1,8 @@
 use super::RootChild;
 use crate::comment::Comment;
 use crate::prelude::parser::{
-    Error, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput, ParserOptions,
+    Error, MaskedSource, MrmlCursor, MrmlParser, MrmlToken, ParseChildren, ParseOutput,
+    ParserOptions,
 };
 
 impl<'opts> crate::prelude::parser::ParseChildren<Vec<RootChild>> for MrmlParser<'opts> {
@@ -71,7 +72,8 @@ impl super::Root {
         opts: &ParserOptions,
     ) -> Result<ParseOutput<Self>, Error> {
         let parser = MrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        let source = MaskedSource::new(value.as_ref(), &parser.options.placeholders);
+        let mut cursor = MrmlCursor::from_masked(&source);
         let element = Self(parser.parse_children(&mut cursor)?);
         Ok(ParseOutput {
             element,
@@ -87,7 +89,8 @@ impl super::Root {
         use crate::prelude::parser::{AsyncMrmlParser, AsyncParseChildren};
 
         let parser = AsyncMrmlParser::new(opts);
-        let mut cursor = MrmlCursor::new(value.as_ref());
+        let source = MaskedSource::new(value.as_ref(), &parser.options.placeholders);
+        let mut cursor = MrmlCursor::from_masked(&source);
         let element = Self(parser.async_parse_children(&mut cursor).await?);
         Ok(ParseOutput {
             element,
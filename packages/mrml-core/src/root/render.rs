@@ -8,8 +8,12 @@ impl<'root> Render<'root> for Renderer<'root, super::Root, ()> {
     fn render(&self, cursor: &mut RenderCursor) -> Result<(), Error> {
         for element in self.element.as_ref().iter() {
             match element {
-                super::RootChild::Comment(inner) => inner.renderer(self.context).render(cursor)?,
-                super::RootChild::Mjml(inner) => inner.renderer(self.context).render(cursor)?,
+                super::RootChild::Comment(inner) => {
+                    inner.renderer(self.context).render_component(cursor)?
+                }
+                super::RootChild::Mjml(inner) => {
+                    inner.renderer(self.context).render_component(cursor)?
+                }
             };
         }
         Ok(())
@@ -3,9 +3,7 @@ use crate::prelude::print::{Printable, Printer};
 
 impl Printable for Text {
     fn print<P: Printer>(&self, printer: &mut P) -> std::fmt::Result {
-        printer.push_indent();
-        printer.push_str(self.0.as_str());
-        printer.push_new_line();
+        printer.push_text(self.0.as_str());
         Ok(())
     }
 }
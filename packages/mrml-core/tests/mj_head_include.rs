@@ -14,6 +14,7 @@ fn should_apply_head_includes() {
     let loader = MemoryIncludeLoader::from(vec![("mj-head-include-attributes.mjml", include)]);
     let parser_opts = ParserOptions {
         include_loader: Box::new(loader),
+        ..Default::default()
     };
 
     let render_opts = RenderOptions::default();
@@ -151,7 +151,10 @@ impl ParserOptions {
 impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         let include_loader = value.include_loader.build();
-        mrml::prelude::parser::ParserOptions { include_loader }
+        mrml::prelude::parser::ParserOptions {
+            include_loader,
+            ..Default::default()
+        }
     }
 }
 
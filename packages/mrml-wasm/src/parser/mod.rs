@@ -42,6 +42,7 @@ impl From<ParserOptions> for mrml::prelude::parser::ParserOptions {
     fn from(value: ParserOptions) -> Self {
         mrml::prelude::parser::ParserOptions {
             include_loader: value.include_loader.build(),
+            ..Default::default()
         }
     }
 }
@@ -92,6 +93,7 @@ impl From<AsyncParserOptions> for mrml::prelude::parser::AsyncParserOptions {
     fn from(value: AsyncParserOptions) -> Self {
         mrml::prelude::parser::AsyncParserOptions {
             include_loader: value.include_loader.build_async(),
+            ..Default::default()
         }
     }
 }
@@ -117,6 +119,12 @@ pub enum ParserError {
         origin: super::Origin,
         position: super::Span,
     },
+    InvalidAttributeValue {
+        name: String,
+        origin: super::Origin,
+        position: super::Span,
+        source: String,
+    },
     InvalidFormat {
         origin: super::Origin,
         position: super::Span,
@@ -140,6 +148,11 @@ pub enum ParserError {
         position: super::Span,
         source: String,
     },
+    InvalidCustomElement {
+        origin: super::Origin,
+        position: super::Span,
+        reason: String,
+    },
 }
 
 impl From<mrml::prelude::parser::Error> for ParserError {
@@ -164,6 +177,25 @@ impl From<mrml::prelude::parser::Error> for ParserError {
                 origin: origin.into(),
                 position: position.into(),
             },
+            Error::InvalidAttributeValue {
+                origin,
+                position,
+                source,
+            } => Self::InvalidAttributeValue {
+                name: source.name.clone(),
+                origin: origin.into(),
+                position: position.into(),
+                source: source.to_string(),
+            },
+            Error::InvalidCustomElement {
+                origin,
+                position,
+                reason,
+            } => Self::InvalidCustomElement {
+                origin: origin.into(),
+                position: position.into(),
+                reason,
+            },
             Error::InvalidFormat { origin, position } => Self::InvalidFormat {
                 origin: origin.into(),
                 position: position.into(),
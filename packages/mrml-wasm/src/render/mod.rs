@@ -27,6 +27,7 @@ impl From<RenderOptions> for mrml::prelude::render::RenderOptions {
                 .into_iter()
                 .map(|(key, value)| (key, Cow::Owned(value)))
                 .collect(),
+            ..Default::default()
         }
     }
 }